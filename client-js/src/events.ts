@@ -1,4 +1,4 @@
-import { CLBool, CLKey, CLList, CLMap, CLOption, CLString, CLU256, CLValue } from 'casper-js-sdk';
+import { CLBool, CLKey, CLList, CLMap, CLOption, CLString, CLU256, CLU64, CLU8, CLValue } from 'casper-js-sdk';
 
 export type Event<E extends Record<string, CLValue>> = {
   name: string;
@@ -28,6 +28,7 @@ export type CEP85Event = Event<
   | UriBatch
   | SetTotalSupply
   | ChangeSecurity
+  | ChangeSecurityRoles
   | SetModalities
   | Upgrade
 >;
@@ -44,6 +45,7 @@ export type EventsMap = {
   UriBatch: Event<UriBatch>;
   SetTotalSupply: Event<SetTotalSupply>;
   ChangeSecurity: Event<ChangeSecurity>;
+  ChangeSecurityRoles: Event<ChangeSecurityRoles>;
   SetModalities: Event<SetModalities>;
   Upgrade: Event<Upgrade>;
 };
@@ -76,6 +78,7 @@ export type ApprovalForAll = {
   owner: CLKey,
   operator: CLKey,
   approved: CLBool,
+  expires_at: CLOption<CLU64>,
 };
 
 export type Transfer = {
@@ -114,6 +117,11 @@ export type ChangeSecurity = {
   sec_change_map: CLMap<CLKey, CLValue>,
 };
 
+export type ChangeSecurityRoles = {
+  admin: CLKey,
+  sec_change_map: CLMap<CLKey, CLU8>,
+};
+
 export type SetModalities = Record<string, never>;
 
 export type Upgrade = Record<string, never>;
\ No newline at end of file
@@ -0,0 +1,30 @@
+//! Implementation of per-id allowances.
+
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::DICT_ALLOWANCES,
+    utils::{
+        get_dictionary_value_from_key, make_dictionary_item_key, set_dictionary_value_for_key,
+    },
+};
+
+/// Writes the amount of token `id` that `spender` may transfer on behalf of `owner`.
+pub fn write_allowance_to(owner: &Key, spender: &Key, id: &U256, amount: &U256) {
+    set_dictionary_value_for_key(
+        DICT_ALLOWANCES,
+        &make_dictionary_item_key(owner, &(*spender, *id)),
+        amount,
+    )
+}
+
+/// Reads the amount of token `id` that `spender` may transfer on behalf of `owner`.
+///
+/// If no allowance was ever approved, then a 0 is returned.
+pub fn read_allowance_of(owner: &Key, spender: &Key, id: &U256) -> U256 {
+    get_dictionary_value_from_key(
+        DICT_ALLOWANCES,
+        &make_dictionary_item_key(owner, &(*spender, *id)),
+    )
+    .unwrap_or_default()
+}
@@ -10,13 +10,31 @@ use casper_types::{Key, U256};
 use crate::{
     constants::{ARG_CONTRACT_HASH, DICT_BALANCES},
     error::Cep85Error,
+    split_ids::{read_type_balance_from, read_type_item, type_id_of, write_type_balance_to},
     utils::{
         get_dictionary_value_from_key, make_dictionary_item_key, set_dictionary_value_for_key,
     },
 };
 
 /// Writes token balance of a specified account into a dictionary.
+///
+/// The balance of the account in the type of an item of a split id follows the change.
 pub fn write_balance_to(account: &Key, id: &U256, amount: &U256) {
+    if read_type_item(id) {
+        let type_id = type_id_of(id);
+        let balance = read_balance_from(account, id);
+        let type_balance = read_type_balance_from(account, &type_id);
+        let new_type_balance = if *amount >= balance {
+            type_balance
+                .checked_add(*amount - balance)
+                .unwrap_or_revert_with(Cep85Error::Overflow)
+        } else {
+            type_balance
+                .checked_sub(balance - *amount)
+                .unwrap_or_revert_with(Cep85Error::InsufficientBalance)
+        };
+        write_type_balance_to(account, &type_id, &new_type_balance);
+    }
     set_dictionary_value_for_key(
         DICT_BALANCES,
         &make_dictionary_item_key(account, id),
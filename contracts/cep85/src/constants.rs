@@ -1,4 +1,5 @@
 //! Constants used by the CEP85 contract.
+use casper_types::Key;
 
 pub const PREFIX_ACCESS_KEY_NAME: &str = "cep85_contract_package_access";
 pub const PREFIX_CEP85: &str = "cep85";
@@ -6,69 +7,188 @@ pub const PREFIX_CONTRACT_NAME: &str = "cep85_contract_hash";
 pub const PREFIX_CONTRACT_VERSION: &str = "cep85_contract_version";
 pub const PREFIX_CONTRACT_PACKAGE_NAME: &str = "cep85_contract_package_hash";
 pub const DEFAULT_DICT_ITEM_KEY_NAME: &str = "cep85_dictionary_item_key";
+pub const PAUSED: &str = "paused";
+pub const INSTALLER: &str = "installer";
+pub const ADMIN_COUNT: &str = "admin_count";
+pub const PROPOSAL_COUNT: &str = "proposal_count";
+pub const OPERATION_COUNT: &str = "operation_count";
+pub const NEXT_ID: &str = "next_id";
+pub const TRANSFER_FILTERS: &str = "transfer_filters";
+/// Stands for the missing sender of a mint or recipient of a burn when calling transfer filters.
+pub const NULL_KEY: Key = Key::Hash([0u8; 32]);
 
+pub const ENTRY_POINT_ACCEPT_ADMIN: &str = "accept_admin";
+pub const ENTRY_POINT_ADD_TRANSFER_FILTER: &str = "add_transfer_filter";
+pub const ENTRY_POINT_ALLOWANCE: &str = "allowance";
+pub const ENTRY_POINT_APPROVAL_EXPIRES_AT: &str = "approval_expires_at";
+pub const ENTRY_POINT_APPROVE: &str = "approve";
+pub const ENTRY_POINT_APPROVE_PROPOSAL: &str = "approve_proposal";
 pub const ENTRY_POINT_BALANCE_OF: &str = "balance_of";
 pub const ENTRY_POINT_BALANCE_OF_BATCH: &str = "balance_of_batch";
+pub const ENTRY_POINT_BALANCE_OF_TYPE: &str = "balance_of_type";
 pub const ENTRY_POINT_BATCH_BURN: &str = "batch_burn";
+pub const ENTRY_POINT_BATCH_LOCK_IDS: &str = "batch_lock_ids";
 pub const ENTRY_POINT_BATCH_MINT: &str = "batch_mint";
+pub const ENTRY_POINT_BATCH_MINT_NEXT: &str = "batch_mint_next";
+pub const ENTRY_POINT_BATCH_UNLOCK_IDS: &str = "batch_unlock_ids";
 pub const ENTRY_POINT_BURN: &str = "burn";
+pub const ENTRY_POINT_CANCEL_OPERATION: &str = "cancel_operation";
+pub const ENTRY_POINT_CANCEL_PROPOSAL: &str = "cancel_proposal";
 pub const ENTRY_POINT_CHANGE_SECURITY: &str = "change_security";
+pub const ENTRY_POINT_CREATE_PROPOSAL: &str = "create_proposal";
+pub const ENTRY_POINT_CREATE_TOKEN: &str = "create_token";
+pub const ENTRY_POINT_CREATE_TOKEN_BATCH: &str = "create_token_batch";
+pub const ENTRY_POINT_CREATE_TYPE: &str = "create_type";
+pub const ENTRY_POINT_DECREASE_ALLOWANCE: &str = "decrease_allowance";
+pub const ENTRY_POINT_EXECUTE_OPERATION: &str = "execute_operation";
+pub const ENTRY_POINT_FORCE_BATCH_TRANSFER: &str = "force_batch_transfer";
+pub const ENTRY_POINT_FORCE_TRANSFER: &str = "force_transfer";
+pub const ENTRY_POINT_FREEZE_ACCOUNTS: &str = "freeze_accounts";
+pub const ENTRY_POINT_GET_SECURITY_BADGE: &str = "get_security_badge";
+pub const ENTRY_POINT_GRANT_SCOPED_ROLE: &str = "grant_scoped_role";
+pub const ENTRY_POINT_INCREASE_ALLOWANCE: &str = "increase_allowance";
 pub const ENTRY_POINT_INIT: &str = "init";
+pub const ENTRY_POINT_IS_PAUSED: &str = "is_paused";
 pub const ENTRY_POINT_IS_APPROVED_FOR_ALL: &str = "is_approved_for_all";
+pub const ENTRY_POINT_IS_FROZEN: &str = "is_frozen";
+pub const ENTRY_POINT_IS_ID_LOCKED: &str = "is_id_locked";
 pub const ENTRY_POINT_IS_NON_FUNGIBLE: &str = "is_non_fungible";
+pub const ENTRY_POINT_LOCK_ID: &str = "lock_id";
 pub const ENTRY_POINT_MAKE_DICTIONARY_ITEM_KEY: &str = "make_dictionary_item_key";
+pub const ENTRY_POINT_METADATA_OF: &str = "metadata_of";
+pub const ENTRY_POINT_METADATA_OF_BATCH: &str = "metadata_of_batch";
 pub const ENTRY_POINT_MINT: &str = "mint";
+pub const ENTRY_POINT_MINT_NEXT: &str = "mint_next";
+pub const ENTRY_POINT_ON_CEP85_BATCH_RECEIVED: &str = "on_cep85_batch_received";
+pub const ENTRY_POINT_ON_CEP85_RECEIVED: &str = "on_cep85_received";
+pub const ENTRY_POINT_PAUSE: &str = "pause";
+pub const ENTRY_POINT_PROPOSE_ADMIN: &str = "propose_admin";
+pub const ENTRY_POINT_QUEUE_OPERATION: &str = "queue_operation";
+pub const ENTRY_POINT_REMAINING_MINT_QUOTA: &str = "remaining_mint_quota";
+pub const ENTRY_POINT_REMOVE_TRANSFER_FILTER: &str = "remove_transfer_filter";
+pub const ENTRY_POINT_RENOUNCE_ROLE: &str = "renounce_role";
+pub const ENTRY_POINT_REVOKE_SCOPED_ROLE: &str = "revoke_scoped_role";
+pub const ENTRY_POINT_ROLE_MEMBER_COUNT: &str = "role_member_count";
+pub const ENTRY_POINT_ROLE_MEMBERS: &str = "role_members";
 pub const ENTRY_POINT_BATCH_TRANSFER_FROM: &str = "batch_transfer_from";
 pub const ENTRY_POINT_TRANSFER_FROM: &str = "transfer_from";
 pub const ENTRY_POINT_SET_APPROVAL_FOR_ALL: &str = "set_approval_for_all";
+pub const ENTRY_POINT_SET_ID_NAMESPACE: &str = "set_id_namespace";
+pub const ENTRY_POINT_SET_METADATA_OF: &str = "set_metadata_of";
+pub const ENTRY_POINT_SET_MINT_QUOTA: &str = "set_mint_quota";
 pub const ENTRY_POINT_SET_MODALITIES: &str = "set_modalities";
 pub const ENTRY_POINT_SET_TOTAL_SUPPLY_OF: &str = "set_total_supply_of";
 pub const ENTRY_POINT_SET_TOTAL_SUPPLY_OF_BATCH: &str = "set_total_supply_of_batch";
+pub const ENTRY_POINT_SET_TRANSFER_FILTER: &str = "set_transfer_filter";
+pub const ENTRY_POINT_SET_AFTER_TRANSFER_HOOK: &str = "set_after_transfer_hook";
 pub const ENTRY_POINT_SET_URI: &str = "set_uri";
 pub const ENTRY_POINT_SUPPLY_OF: &str = "supply_of";
 pub const ENTRY_POINT_SUPPLY_OF_BATCH: &str = "supply_of_batch";
 pub const ENTRY_POINT_TOTAL_FUNGIBLE_SUPPLY: &str = "total_fungible_supply";
 pub const ENTRY_POINT_TOTAL_SUPPLY_OF: &str = "total_supply_of";
 pub const ENTRY_POINT_TOTAL_SUPPLY_OF_BATCH: &str = "total_supply_of_batch";
+pub const ENTRY_POINT_UNFREEZE_ACCOUNTS: &str = "unfreeze_accounts";
+pub const ENTRY_POINT_UNLOCK_ID: &str = "unlock_id";
+pub const ENTRY_POINT_UNPAUSE: &str = "unpause";
 pub const ENTRY_POINT_UPGRADE: &str = "upgrade";
 pub const ENTRY_POINT_URI: &str = "uri";
 
 pub const ARG_ACCOUNT: &str = "account";
+pub const ARG_ACTION: &str = "action";
+pub const ARG_ACTION_ARGS: &str = "action_args";
+pub const ARG_ADMIN_THRESHOLD: &str = "admin_threshold";
 pub const ARG_ACCOUNTS: &str = "accounts";
 pub const ARG_AMOUNT: &str = "amount";
 pub const ARG_AMOUNTS: &str = "amounts";
+pub const ARG_BADGE: &str = "badge";
 pub const ARG_APPROVED: &str = "approved";
 pub const ARG_CONTRACT_HASH: &str = "contract_hash";
+pub const ARG_COUNT: &str = "count";
 pub const ARG_DATA: &str = "data";
+pub const ARG_DECIMALS: &str = "decimals";
+pub const ARG_DECIMALS_LIST: &str = "decimals_list";
+pub const ARG_END_ID: &str = "end_id";
 pub const ARG_ENABLE_BURN: &str = "enable_burn";
+pub const ARG_BURN_MODE: &str = "burn_mode";
+pub const ARG_ENABLE_FILTER_REASONS: &str = "enable_filter_reasons";
+pub const ARG_ENABLE_MINT_BURN_FILTER: &str = "enable_mint_burn_filter";
+pub const ARG_ENABLE_RECEIVER_CHECK: &str = "enable_receiver_check";
 pub const ARG_EVENTS_MODE: &str = "events_mode";
+pub const ARG_EXPIRES_AT: &str = "expires_at";
 pub const ARG_FROM: &str = "from";
 pub const ARG_ID: &str = "id";
+pub const ARG_LIFETIME_LIMIT: &str = "lifetime_limit";
+pub const ARG_LIMIT: &str = "limit";
 pub const ARG_IDS: &str = "ids";
+pub const ARG_INDEX: &str = "index";
 pub const ARG_NAME: &str = "name";
+pub const ARG_NAMES: &str = "names";
+pub const ARG_NAMESPACE: &str = "namespace";
+pub const ARG_OPERATION_ID: &str = "operation_id";
 pub const ARG_OPERATOR: &str = "operator";
 pub const ARG_OWNER: &str = "owner";
 pub const ARG_PACKAGE_HASH: &str = "package_hash";
+pub const ARG_PROPOSAL_ID: &str = "proposal_id";
+pub const ARG_REASON: &str = "reason";
 pub const ARG_RECIPIENT: &str = "recipient";
+pub const ARG_REGISTERED_IDS_ONLY: &str = "registered_ids_only";
+pub const ARG_REVOKE: &str = "revoke";
 pub const ARG_SESSION_NAMED_KEY_NAME: &str = "session_named_key_name";
+pub const ARG_SPENDER: &str = "spender";
+pub const ARG_START: &str = "start";
+pub const ARG_SYMBOL: &str = "symbol";
+pub const ARG_SYMBOLS: &str = "symbols";
+pub const ARG_TIMELOCK_DELAY: &str = "timelock_delay";
 pub const ARG_TOKEN_CONTRACT: &str = "token_contract";
+pub const ARG_TOKEN_KIND: &str = "token_kind";
+pub const ARG_TOKEN_KINDS: &str = "token_kinds";
 pub const ARG_TOTAL_SUPPLIES: &str = "total_supplies";
 pub const ARG_TOTAL_SUPPLY: &str = "total_supply";
 pub const ARG_TO: &str = "to";
 pub const ARG_TRANSFER_FILTER_CONTRACT: &str = "transfer_filter_contract";
 pub const ARG_TRANSFER_FILTER_METHOD: &str = "transfer_filter_method";
+pub const ARG_AFTER_TRANSFER_HOOK_CONTRACT: &str = "after_transfer_hook_contract";
+pub const ARG_AFTER_TRANSFER_HOOK_METHOD: &str = "after_transfer_hook_method";
 pub const ARG_UPGRADE_FLAG: &str = "upgrade";
 pub const ARG_URI: &str = "uri";
+pub const ARG_WINDOW: &str = "window";
+pub const ARG_WINDOW_LIMIT: &str = "window_limit";
 
 pub const ADMIN_LIST: &str = "admin_list";
 pub const MINTER_LIST: &str = "minter_list";
 pub const BURNER_LIST: &str = "burner_list";
 pub const META_LIST: &str = "meta_list";
+pub const PAUSER_LIST: &str = "pauser_list";
+pub const CONTROLLER_LIST: &str = "controller_list";
 pub const NONE_LIST: &str = "none_list";
 
+pub const DICT_ADMIN_PROPOSALS: &str = "admin_proposals";
+pub const DICT_ALLOWANCES: &str = "allowances";
 pub const DICT_BALANCES: &str = "balances";
+pub const DICT_FROZEN_ACCOUNTS: &str = "frozen_accounts";
+pub const DICT_ID_NAMESPACES: &str = "id_namespaces";
+pub const DICT_LOCKED_IDS: &str = "locked_ids";
+pub const DICT_MINT_QUOTAS: &str = "mint_quotas";
+pub const DICT_MINT_QUOTA_USAGES: &str = "mint_quota_usages";
+pub const DICT_OPERATIONS: &str = "timelock_operations";
+pub const DICT_OPERATION_STATES: &str = "timelock_operation_states";
 pub const DICT_OPERATORS: &str = "operators";
+pub const DICT_OPERATOR_EXPIRIES: &str = "operator_expiries";
+pub const DICT_PROPOSALS: &str = "proposals";
+pub const DICT_PROPOSAL_APPROVALS: &str = "proposal_approvals";
+pub const DICT_PROPOSAL_STATES: &str = "proposal_states";
+pub const DICT_ROLE_MEMBERS: &str = "role_members";
+pub const DICT_ROLE_MEMBER_COUNTS: &str = "role_member_counts";
+pub const DICT_ROLE_MEMBER_INDEXES: &str = "role_member_indexes";
+pub const DICT_SCOPED_ROLES: &str = "scoped_roles";
+pub const DICT_SCOPED_ROLE_COUNTS: &str = "scoped_role_counts";
 pub const DICT_SECURITY_BADGES: &str = "security_badges";
+pub const DICT_SECURITY_ROLES: &str = "security_roles";
 pub const DICT_SUPPLY: &str = "supply";
+pub const DICT_TOKEN_KINDS: &str = "token_kinds";
+pub const DICT_TOKEN_METADATA: &str = "token_metadata";
+pub const DICT_TOKEN_TYPES: &str = "token_types";
 pub const DICT_TOKEN_URI: &str = "token_uri";
 pub const DICT_TOTAL_SUPPLY: &str = "total_supply";
+pub const DICT_TYPE_BALANCES: &str = "type_balances";
+pub const DICT_TYPE_ITEMS: &str = "type_items";
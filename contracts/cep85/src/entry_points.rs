@@ -1,20 +1,49 @@
 //! Contains definition of the entry points.
-use crate::constants::{
-    ADMIN_LIST, ARG_ACCOUNT, ARG_ACCOUNTS, ARG_AMOUNT, ARG_AMOUNTS, ARG_APPROVED,
-    ARG_CONTRACT_HASH, ARG_DATA, ARG_ENABLE_BURN, ARG_EVENTS_MODE, ARG_FROM, ARG_ID, ARG_IDS,
-    ARG_NAME, ARG_OPERATOR, ARG_OWNER, ARG_PACKAGE_HASH, ARG_RECIPIENT, ARG_TO, ARG_TOTAL_SUPPLIES,
-    ARG_TOTAL_SUPPLY, ARG_TRANSFER_FILTER_CONTRACT, ARG_TRANSFER_FILTER_METHOD, ARG_URI,
-    BURNER_LIST, ENTRY_POINT_BALANCE_OF, ENTRY_POINT_BALANCE_OF_BATCH, ENTRY_POINT_BATCH_BURN,
-    ENTRY_POINT_BATCH_MINT, ENTRY_POINT_BATCH_TRANSFER_FROM, ENTRY_POINT_BURN,
-    ENTRY_POINT_CHANGE_SECURITY, ENTRY_POINT_INIT, ENTRY_POINT_IS_APPROVED_FOR_ALL,
-    ENTRY_POINT_IS_NON_FUNGIBLE, ENTRY_POINT_MAKE_DICTIONARY_ITEM_KEY, ENTRY_POINT_MINT,
-    ENTRY_POINT_SET_APPROVAL_FOR_ALL, ENTRY_POINT_SET_MODALITIES, ENTRY_POINT_SET_TOTAL_SUPPLY_OF,
-    ENTRY_POINT_SET_TOTAL_SUPPLY_OF_BATCH, ENTRY_POINT_SET_URI, ENTRY_POINT_SUPPLY_OF,
-    ENTRY_POINT_SUPPLY_OF_BATCH, ENTRY_POINT_TOTAL_FUNGIBLE_SUPPLY, ENTRY_POINT_TOTAL_SUPPLY_OF,
-    ENTRY_POINT_TOTAL_SUPPLY_OF_BATCH, ENTRY_POINT_TRANSFER_FROM, ENTRY_POINT_UPGRADE,
-    ENTRY_POINT_URI, META_LIST, MINTER_LIST, NONE_LIST,
+use crate::{
+    constants::{
+        ADMIN_LIST, ARG_ACCOUNT, ARG_ACCOUNTS, ARG_ACTION, ARG_ACTION_ARGS,
+        ARG_AFTER_TRANSFER_HOOK_CONTRACT, ARG_AFTER_TRANSFER_HOOK_METHOD, ARG_AMOUNT, ARG_AMOUNTS,
+        ARG_APPROVED, ARG_BADGE, ARG_BURN_MODE, ARG_CONTRACT_HASH, ARG_COUNT, ARG_DATA,
+        ARG_DECIMALS, ARG_DECIMALS_LIST, ARG_ENABLE_BURN, ARG_ENABLE_FILTER_REASONS,
+        ARG_ENABLE_MINT_BURN_FILTER, ARG_ENABLE_RECEIVER_CHECK, ARG_END_ID, ARG_EVENTS_MODE,
+        ARG_EXPIRES_AT, ARG_FROM, ARG_ID, ARG_IDS, ARG_INDEX, ARG_LIFETIME_LIMIT, ARG_LIMIT,
+        ARG_NAME, ARG_NAMES, ARG_NAMESPACE, ARG_OPERATION_ID, ARG_OPERATOR, ARG_OWNER,
+        ARG_PACKAGE_HASH, ARG_PROPOSAL_ID, ARG_REASON, ARG_RECIPIENT, ARG_REGISTERED_IDS_ONLY,
+        ARG_REVOKE, ARG_SPENDER, ARG_START, ARG_SYMBOL, ARG_SYMBOLS, ARG_TIMELOCK_DELAY, ARG_TO,
+        ARG_TOKEN_KIND, ARG_TOKEN_KINDS, ARG_TOTAL_SUPPLIES, ARG_TOTAL_SUPPLY,
+        ARG_TRANSFER_FILTER_CONTRACT, ARG_TRANSFER_FILTER_METHOD, ARG_URI, ARG_WINDOW,
+        ARG_WINDOW_LIMIT, BURNER_LIST, CONTROLLER_LIST, ENTRY_POINT_ACCEPT_ADMIN,
+        ENTRY_POINT_ADD_TRANSFER_FILTER, ENTRY_POINT_ALLOWANCE, ENTRY_POINT_APPROVAL_EXPIRES_AT,
+        ENTRY_POINT_APPROVE, ENTRY_POINT_APPROVE_PROPOSAL, ENTRY_POINT_BALANCE_OF,
+        ENTRY_POINT_BALANCE_OF_BATCH, ENTRY_POINT_BALANCE_OF_TYPE, ENTRY_POINT_BATCH_BURN,
+        ENTRY_POINT_BATCH_LOCK_IDS, ENTRY_POINT_BATCH_MINT, ENTRY_POINT_BATCH_MINT_NEXT,
+        ENTRY_POINT_BATCH_TRANSFER_FROM, ENTRY_POINT_BATCH_UNLOCK_IDS, ENTRY_POINT_BURN,
+        ENTRY_POINT_CANCEL_OPERATION, ENTRY_POINT_CANCEL_PROPOSAL, ENTRY_POINT_CHANGE_SECURITY,
+        ENTRY_POINT_CREATE_PROPOSAL, ENTRY_POINT_CREATE_TOKEN, ENTRY_POINT_CREATE_TOKEN_BATCH,
+        ENTRY_POINT_CREATE_TYPE, ENTRY_POINT_DECREASE_ALLOWANCE, ENTRY_POINT_EXECUTE_OPERATION,
+        ENTRY_POINT_FORCE_BATCH_TRANSFER, ENTRY_POINT_FORCE_TRANSFER, ENTRY_POINT_FREEZE_ACCOUNTS,
+        ENTRY_POINT_GET_SECURITY_BADGE, ENTRY_POINT_GRANT_SCOPED_ROLE,
+        ENTRY_POINT_INCREASE_ALLOWANCE, ENTRY_POINT_INIT, ENTRY_POINT_IS_APPROVED_FOR_ALL,
+        ENTRY_POINT_IS_FROZEN, ENTRY_POINT_IS_ID_LOCKED, ENTRY_POINT_IS_NON_FUNGIBLE,
+        ENTRY_POINT_IS_PAUSED, ENTRY_POINT_LOCK_ID, ENTRY_POINT_MAKE_DICTIONARY_ITEM_KEY,
+        ENTRY_POINT_METADATA_OF, ENTRY_POINT_METADATA_OF_BATCH, ENTRY_POINT_MINT,
+        ENTRY_POINT_MINT_NEXT, ENTRY_POINT_PAUSE, ENTRY_POINT_PROPOSE_ADMIN,
+        ENTRY_POINT_QUEUE_OPERATION, ENTRY_POINT_REMAINING_MINT_QUOTA,
+        ENTRY_POINT_REMOVE_TRANSFER_FILTER, ENTRY_POINT_RENOUNCE_ROLE,
+        ENTRY_POINT_REVOKE_SCOPED_ROLE, ENTRY_POINT_ROLE_MEMBERS, ENTRY_POINT_ROLE_MEMBER_COUNT,
+        ENTRY_POINT_SET_AFTER_TRANSFER_HOOK, ENTRY_POINT_SET_APPROVAL_FOR_ALL,
+        ENTRY_POINT_SET_ID_NAMESPACE, ENTRY_POINT_SET_METADATA_OF, ENTRY_POINT_SET_MINT_QUOTA,
+        ENTRY_POINT_SET_MODALITIES, ENTRY_POINT_SET_TOTAL_SUPPLY_OF,
+        ENTRY_POINT_SET_TOTAL_SUPPLY_OF_BATCH, ENTRY_POINT_SET_TRANSFER_FILTER,
+        ENTRY_POINT_SET_URI, ENTRY_POINT_SUPPLY_OF, ENTRY_POINT_SUPPLY_OF_BATCH,
+        ENTRY_POINT_TOTAL_FUNGIBLE_SUPPLY, ENTRY_POINT_TOTAL_SUPPLY_OF,
+        ENTRY_POINT_TOTAL_SUPPLY_OF_BATCH, ENTRY_POINT_TRANSFER_FROM,
+        ENTRY_POINT_UNFREEZE_ACCOUNTS, ENTRY_POINT_UNLOCK_ID, ENTRY_POINT_UNPAUSE,
+        ENTRY_POINT_UPGRADE, ENTRY_POINT_URI, META_LIST, MINTER_LIST, NONE_LIST, PAUSER_LIST,
+    },
+    security::SecurityBadge,
 };
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use casper_types::{
     bytesrepr::Bytes, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
     Parameter,
@@ -32,6 +61,8 @@ pub fn init() -> EntryPoint {
             Parameter::new(MINTER_LIST, CLType::List(Box::new(CLType::Key))),
             Parameter::new(BURNER_LIST, CLType::List(Box::new(CLType::Key))),
             Parameter::new(META_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(PAUSER_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(CONTROLLER_LIST, CLType::List(Box::new(CLType::Key))),
             Parameter::new(NONE_LIST, CLType::List(Box::new(CLType::Key))),
         ],
         CLType::Unit,
@@ -43,7 +74,15 @@ pub fn init() -> EntryPoint {
 pub fn upgrade() -> EntryPoint {
     EntryPoint::new(
         ENTRY_POINT_UPGRADE,
-        vec![Parameter::new(ARG_PACKAGE_HASH, CLType::Key)],
+        vec![
+            Parameter::new(ARG_PACKAGE_HASH, CLType::Key),
+            Parameter::new(ADMIN_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(MINTER_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(BURNER_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(META_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(PAUSER_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(CONTROLLER_LIST, CLType::List(Box::new(CLType::Key))),
+        ],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -58,6 +97,9 @@ pub fn mint() -> EntryPoint {
             Parameter::new(ARG_ID, CLType::U256),
             Parameter::new(ARG_AMOUNT, CLType::U256),
             Parameter::new(ARG_URI, CLType::String),
+            Parameter::new(ARG_NAME, CLType::String),
+            Parameter::new(ARG_SYMBOL, CLType::String),
+            Parameter::new(ARG_DECIMALS, CLType::U8),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -73,6 +115,9 @@ pub fn batch_mint() -> EntryPoint {
             Parameter::new(ARG_IDS, CLType::List(Box::new(CLType::U256))),
             Parameter::new(ARG_AMOUNTS, CLType::List(Box::new(CLType::U256))),
             Parameter::new(ARG_URI, CLType::String),
+            Parameter::new(ARG_NAMES, CLType::List(Box::new(CLType::String))),
+            Parameter::new(ARG_SYMBOLS, CLType::List(Box::new(CLType::String))),
+            Parameter::new(ARG_DECIMALS_LIST, CLType::List(Box::new(CLType::U8))),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -80,6 +125,55 @@ pub fn batch_mint() -> EntryPoint {
     )
 }
 
+pub fn set_id_namespace() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_SET_ID_NAMESPACE,
+        vec![
+            Parameter::new(ARG_NAMESPACE, CLType::String),
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_END_ID, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn mint_next() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_MINT_NEXT,
+        vec![
+            Parameter::new(ARG_RECIPIENT, CLType::Key),
+            Parameter::new(ARG_NAMESPACE, CLType::String),
+            Parameter::new(ARG_URI, CLType::String),
+            Parameter::new(ARG_NAME, CLType::String),
+            Parameter::new(ARG_SYMBOL, CLType::String),
+            Parameter::new(ARG_DECIMALS, CLType::U8),
+        ],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn batch_mint_next() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_BATCH_MINT_NEXT,
+        vec![
+            Parameter::new(ARG_RECIPIENT, CLType::Key),
+            Parameter::new(ARG_COUNT, CLType::U32),
+            Parameter::new(ARG_NAMESPACE, CLType::String),
+            Parameter::new(ARG_URI, CLType::String),
+            Parameter::new(ARG_NAMES, CLType::List(Box::new(CLType::String))),
+            Parameter::new(ARG_SYMBOLS, CLType::List(Box::new(CLType::String))),
+            Parameter::new(ARG_DECIMALS_LIST, CLType::List(Box::new(CLType::U8))),
+        ],
+        CLType::List(Box::new(CLType::U256)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 pub fn burn() -> EntryPoint {
     EntryPoint::new(
         ENTRY_POINT_BURN,
@@ -121,6 +215,19 @@ pub fn balance_of() -> EntryPoint {
     )
 }
 
+pub fn balance_of_type() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_BALANCE_OF_TYPE,
+        vec![
+            Parameter::new(ARG_ACCOUNT, CLType::Key),
+            Parameter::new(ARG_ID, CLType::U256),
+        ],
+        CLType::Option(Box::new(CLType::U256)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 pub fn balance_of_batch() -> EntryPoint {
     EntryPoint::new(
         ENTRY_POINT_BALANCE_OF_BATCH,
@@ -140,6 +247,7 @@ pub fn set_approval_for_all() -> EntryPoint {
         vec![
             Parameter::new(ARG_OPERATOR, CLType::Key),
             Parameter::new(ARG_APPROVED, CLType::Bool),
+            Parameter::new(ARG_EXPIRES_AT, CLType::U64),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -160,6 +268,75 @@ pub fn is_approved_for_all() -> EntryPoint {
     )
 }
 
+pub fn approval_expires_at() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_APPROVAL_EXPIRES_AT,
+        vec![
+            Parameter::new(ARG_ACCOUNT, CLType::Key),
+            Parameter::new(ARG_OPERATOR, CLType::Key),
+        ],
+        CLType::Option(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn approve() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_APPROVE,
+        vec![
+            Parameter::new(ARG_SPENDER, CLType::Key),
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_AMOUNT, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn allowance() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_ALLOWANCE,
+        vec![
+            Parameter::new(ARG_OWNER, CLType::Key),
+            Parameter::new(ARG_SPENDER, CLType::Key),
+            Parameter::new(ARG_ID, CLType::U256),
+        ],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn increase_allowance() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_INCREASE_ALLOWANCE,
+        vec![
+            Parameter::new(ARG_SPENDER, CLType::Key),
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_AMOUNT, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn decrease_allowance() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_DECREASE_ALLOWANCE,
+        vec![
+            Parameter::new(ARG_SPENDER, CLType::Key),
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_AMOUNT, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 pub fn transfer_from() -> EntryPoint {
     EntryPoint::new(
         ENTRY_POINT_TRANSFER_FROM,
@@ -192,6 +369,40 @@ pub fn batch_transfer_from() -> EntryPoint {
     )
 }
 
+pub fn force_transfer() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_FORCE_TRANSFER,
+        vec![
+            Parameter::new(ARG_FROM, CLType::Key),
+            Parameter::new(ARG_TO, CLType::Key),
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_AMOUNT, CLType::U256),
+            Parameter::new(ARG_REASON, CLType::String),
+            Parameter::new(ARG_DATA, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn force_batch_transfer() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_FORCE_BATCH_TRANSFER,
+        vec![
+            Parameter::new(ARG_FROM, CLType::Key),
+            Parameter::new(ARG_TO, CLType::Key),
+            Parameter::new(ARG_IDS, CLType::List(Box::new(CLType::U256))),
+            Parameter::new(ARG_AMOUNTS, CLType::List(Box::new(CLType::U256))),
+            Parameter::new(ARG_REASON, CLType::String),
+            Parameter::new(ARG_DATA, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 pub fn supply_of() -> EntryPoint {
     EntryPoint::new(
         ENTRY_POINT_SUPPLY_OF,
@@ -264,6 +475,55 @@ pub fn set_total_supply_of_batch() -> EntryPoint {
     )
 }
 
+pub fn create_token() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_CREATE_TOKEN,
+        vec![
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_TOKEN_KIND, CLType::U8),
+            Parameter::new(ARG_TOTAL_SUPPLY, CLType::U256),
+            Parameter::new(ARG_URI, CLType::String),
+            Parameter::new(ARG_NAME, CLType::String),
+            Parameter::new(ARG_SYMBOL, CLType::String),
+            Parameter::new(ARG_DECIMALS, CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn create_token_batch() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_CREATE_TOKEN_BATCH,
+        vec![
+            Parameter::new(ARG_IDS, CLType::List(Box::new(CLType::U256))),
+            Parameter::new(ARG_TOKEN_KINDS, CLType::List(Box::new(CLType::U8))),
+            Parameter::new(ARG_TOTAL_SUPPLIES, CLType::List(Box::new(CLType::U256))),
+            Parameter::new(ARG_URI, CLType::String),
+            Parameter::new(ARG_NAMES, CLType::List(Box::new(CLType::String))),
+            Parameter::new(ARG_SYMBOLS, CLType::List(Box::new(CLType::String))),
+            Parameter::new(ARG_DECIMALS_LIST, CLType::List(Box::new(CLType::U8))),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn create_type() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_CREATE_TYPE,
+        vec![
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_URI, CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 pub fn uri() -> EntryPoint {
     EntryPoint::new(
         ENTRY_POINT_URI,
@@ -287,6 +547,52 @@ pub fn set_uri() -> EntryPoint {
     )
 }
 
+pub fn set_metadata_of() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_SET_METADATA_OF,
+        vec![
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_NAME, CLType::String),
+            Parameter::new(ARG_SYMBOL, CLType::String),
+            Parameter::new(ARG_DECIMALS, CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn metadata_of() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_METADATA_OF,
+        vec![Parameter::new(ARG_ID, CLType::U256)],
+        CLType::Option(Box::new(CLType::Tuple3([
+            Box::new(CLType::String),
+            Box::new(CLType::String),
+            Box::new(CLType::U8),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn metadata_of_batch() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_METADATA_OF_BATCH,
+        vec![Parameter::new(
+            ARG_IDS,
+            CLType::List(Box::new(CLType::U256)),
+        )],
+        CLType::List(Box::new(CLType::Option(Box::new(CLType::Tuple3([
+            Box::new(CLType::String),
+            Box::new(CLType::String),
+            Box::new(CLType::U8),
+        ]))))),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 pub fn is_non_fungible() -> EntryPoint {
     EntryPoint::new(
         ENTRY_POINT_IS_NON_FUNGIBLE,
@@ -315,7 +621,10 @@ pub fn change_security() -> EntryPoint {
             Parameter::new(MINTER_LIST, CLType::List(Box::new(CLType::Key))),
             Parameter::new(BURNER_LIST, CLType::List(Box::new(CLType::Key))),
             Parameter::new(META_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(PAUSER_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(CONTROLLER_LIST, CLType::List(Box::new(CLType::Key))),
             Parameter::new(NONE_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(ARG_REVOKE, CLType::Bool),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -328,7 +637,13 @@ pub fn set_modalities() -> EntryPoint {
         ENTRY_POINT_SET_MODALITIES,
         vec![
             Parameter::new(ARG_ENABLE_BURN, CLType::Bool),
+            Parameter::new(ARG_BURN_MODE, CLType::U8),
             Parameter::new(ARG_EVENTS_MODE, CLType::U8),
+            Parameter::new(ARG_ENABLE_RECEIVER_CHECK, CLType::Bool),
+            Parameter::new(ARG_ENABLE_FILTER_REASONS, CLType::Bool),
+            Parameter::new(ARG_ENABLE_MINT_BURN_FILTER, CLType::Bool),
+            Parameter::new(ARG_REGISTERED_IDS_ONLY, CLType::Bool),
+            Parameter::new(ARG_TIMELOCK_DELAY, CLType::U64),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -336,6 +651,369 @@ pub fn set_modalities() -> EntryPoint {
     )
 }
 
+pub fn set_transfer_filter() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_SET_TRANSFER_FILTER,
+        vec![
+            Parameter::new(ARG_TRANSFER_FILTER_CONTRACT, CLType::Key),
+            Parameter::new(ARG_TRANSFER_FILTER_METHOD, CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn set_after_transfer_hook() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_SET_AFTER_TRANSFER_HOOK,
+        vec![
+            Parameter::new(ARG_AFTER_TRANSFER_HOOK_CONTRACT, CLType::Key),
+            Parameter::new(ARG_AFTER_TRANSFER_HOOK_METHOD, CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn add_transfer_filter() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_ADD_TRANSFER_FILTER,
+        vec![
+            Parameter::new(ARG_TRANSFER_FILTER_CONTRACT, CLType::Key),
+            Parameter::new(ARG_TRANSFER_FILTER_METHOD, CLType::String),
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_END_ID, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn remove_transfer_filter() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_REMOVE_TRANSFER_FILTER,
+        vec![Parameter::new(ARG_INDEX, CLType::U32)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn propose_admin() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_PROPOSE_ADMIN,
+        vec![Parameter::new(ARG_ACCOUNT, CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn accept_admin() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_ACCEPT_ADMIN,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn renounce_role() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_RENOUNCE_ROLE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_security_badge() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_GET_SECURITY_BADGE,
+        vec![Parameter::new(ARG_ACCOUNT, CLType::Key)],
+        Vec::<SecurityBadge>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn role_member_count() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_ROLE_MEMBER_COUNT,
+        vec![Parameter::new(ARG_BADGE, SecurityBadge::cl_type())],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn role_members() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_ROLE_MEMBERS,
+        vec![
+            Parameter::new(ARG_BADGE, SecurityBadge::cl_type()),
+            Parameter::new(ARG_START, CLType::U32),
+            Parameter::new(ARG_LIMIT, CLType::U32),
+        ],
+        CLType::List(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn grant_scoped_role() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_GRANT_SCOPED_ROLE,
+        vec![
+            Parameter::new(ARG_ACCOUNT, CLType::Key),
+            Parameter::new(ARG_BADGE, SecurityBadge::cl_type()),
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_END_ID, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn revoke_scoped_role() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_REVOKE_SCOPED_ROLE,
+        vec![
+            Parameter::new(ARG_ACCOUNT, CLType::Key),
+            Parameter::new(ARG_BADGE, SecurityBadge::cl_type()),
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_END_ID, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn set_mint_quota() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_SET_MINT_QUOTA,
+        vec![
+            Parameter::new(ARG_ACCOUNT, CLType::Key),
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_LIFETIME_LIMIT, CLType::U256),
+            Parameter::new(ARG_WINDOW_LIMIT, CLType::U256),
+            Parameter::new(ARG_WINDOW, CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn remaining_mint_quota() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_REMAINING_MINT_QUOTA,
+        vec![
+            Parameter::new(ARG_ACCOUNT, CLType::Key),
+            Parameter::new(ARG_ID, CLType::U256),
+        ],
+        CLType::Option(Box::new(CLType::U256)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn create_proposal() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_CREATE_PROPOSAL,
+        vec![
+            Parameter::new(ARG_ACTION, CLType::String),
+            Parameter::new(ARG_ACTION_ARGS, Bytes::cl_type()),
+            Parameter::new(ARG_EXPIRES_AT, CLType::U64),
+        ],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn approve_proposal() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_APPROVE_PROPOSAL,
+        vec![Parameter::new(ARG_PROPOSAL_ID, CLType::U32)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn cancel_proposal() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_CANCEL_PROPOSAL,
+        vec![Parameter::new(ARG_PROPOSAL_ID, CLType::U32)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn queue_operation() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_QUEUE_OPERATION,
+        vec![
+            Parameter::new(ARG_ACTION, CLType::String),
+            Parameter::new(ARG_ACTION_ARGS, Bytes::cl_type()),
+        ],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn execute_operation() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_EXECUTE_OPERATION,
+        vec![Parameter::new(ARG_OPERATION_ID, CLType::U32)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn cancel_operation() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_CANCEL_OPERATION,
+        vec![Parameter::new(ARG_OPERATION_ID, CLType::U32)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn freeze_accounts() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_FREEZE_ACCOUNTS,
+        vec![Parameter::new(
+            ARG_ACCOUNTS,
+            CLType::List(Box::new(CLType::Key)),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn unfreeze_accounts() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_UNFREEZE_ACCOUNTS,
+        vec![Parameter::new(
+            ARG_ACCOUNTS,
+            CLType::List(Box::new(CLType::Key)),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn is_frozen() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_IS_FROZEN,
+        vec![Parameter::new(ARG_ACCOUNT, CLType::Key)],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn lock_id() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_LOCK_ID,
+        vec![Parameter::new(ARG_ID, CLType::U256)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn batch_lock_ids() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_BATCH_LOCK_IDS,
+        vec![Parameter::new(
+            ARG_IDS,
+            CLType::List(Box::new(CLType::U256)),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn unlock_id() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_UNLOCK_ID,
+        vec![Parameter::new(ARG_ID, CLType::U256)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn batch_unlock_ids() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_BATCH_UNLOCK_IDS,
+        vec![Parameter::new(
+            ARG_IDS,
+            CLType::List(Box::new(CLType::U256)),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn is_id_locked() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_IS_ID_LOCKED,
+        vec![Parameter::new(ARG_ID, CLType::U256)],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn pause() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_PAUSE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn unpause() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_UNPAUSE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn is_paused() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_IS_PAUSED,
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 pub fn make_dictionary_item_key() -> EntryPoint {
     EntryPoint::new(
         ENTRY_POINT_MAKE_DICTIONARY_ITEM_KEY,
@@ -343,6 +1021,7 @@ pub fn make_dictionary_item_key() -> EntryPoint {
             Parameter::new(ARG_OWNER, CLType::Key),
             Parameter::new(ARG_ID, CLType::U256),
             Parameter::new(ARG_OPERATOR, CLType::Key),
+            Parameter::new(ARG_SPENDER, CLType::Key),
             Parameter::new(ARG_NAME, CLType::String),
         ],
         CLType::Unit,
@@ -358,26 +1037,74 @@ pub fn generate_entry_points() -> EntryPoints {
     entry_points.add_entry_point(upgrade());
     entry_points.add_entry_point(balance_of());
     entry_points.add_entry_point(balance_of_batch());
+    entry_points.add_entry_point(balance_of_type());
     entry_points.add_entry_point(mint());
     entry_points.add_entry_point(batch_mint());
+    entry_points.add_entry_point(set_id_namespace());
+    entry_points.add_entry_point(mint_next());
+    entry_points.add_entry_point(batch_mint_next());
     entry_points.add_entry_point(burn());
     entry_points.add_entry_point(batch_burn());
     entry_points.add_entry_point(set_approval_for_all());
     entry_points.add_entry_point(is_approved_for_all());
+    entry_points.add_entry_point(approval_expires_at());
+    entry_points.add_entry_point(approve());
+    entry_points.add_entry_point(allowance());
+    entry_points.add_entry_point(increase_allowance());
+    entry_points.add_entry_point(decrease_allowance());
     entry_points.add_entry_point(transfer_from());
     entry_points.add_entry_point(batch_transfer_from());
+    entry_points.add_entry_point(force_transfer());
+    entry_points.add_entry_point(force_batch_transfer());
     entry_points.add_entry_point(supply_of());
     entry_points.add_entry_point(supply_of_batch());
     entry_points.add_entry_point(total_supply_of());
     entry_points.add_entry_point(total_supply_of_batch());
     entry_points.add_entry_point(set_total_supply_of());
     entry_points.add_entry_point(set_total_supply_of_batch());
+    entry_points.add_entry_point(create_token());
+    entry_points.add_entry_point(create_token_batch());
+    entry_points.add_entry_point(create_type());
     entry_points.add_entry_point(uri());
     entry_points.add_entry_point(set_uri());
+    entry_points.add_entry_point(set_metadata_of());
+    entry_points.add_entry_point(metadata_of());
+    entry_points.add_entry_point(metadata_of_batch());
     entry_points.add_entry_point(is_non_fungible());
     entry_points.add_entry_point(total_fungible_supply());
     entry_points.add_entry_point(change_security());
     entry_points.add_entry_point(set_modalities());
+    entry_points.add_entry_point(set_transfer_filter());
+    entry_points.add_entry_point(add_transfer_filter());
+    entry_points.add_entry_point(remove_transfer_filter());
+    entry_points.add_entry_point(set_after_transfer_hook());
+    entry_points.add_entry_point(propose_admin());
+    entry_points.add_entry_point(accept_admin());
+    entry_points.add_entry_point(renounce_role());
+    entry_points.add_entry_point(get_security_badge());
+    entry_points.add_entry_point(role_member_count());
+    entry_points.add_entry_point(role_members());
+    entry_points.add_entry_point(grant_scoped_role());
+    entry_points.add_entry_point(revoke_scoped_role());
+    entry_points.add_entry_point(set_mint_quota());
+    entry_points.add_entry_point(remaining_mint_quota());
+    entry_points.add_entry_point(create_proposal());
+    entry_points.add_entry_point(approve_proposal());
+    entry_points.add_entry_point(cancel_proposal());
+    entry_points.add_entry_point(queue_operation());
+    entry_points.add_entry_point(execute_operation());
+    entry_points.add_entry_point(cancel_operation());
+    entry_points.add_entry_point(freeze_accounts());
+    entry_points.add_entry_point(unfreeze_accounts());
+    entry_points.add_entry_point(is_frozen());
+    entry_points.add_entry_point(lock_id());
+    entry_points.add_entry_point(batch_lock_ids());
+    entry_points.add_entry_point(unlock_id());
+    entry_points.add_entry_point(batch_unlock_ids());
+    entry_points.add_entry_point(is_id_locked());
+    entry_points.add_entry_point(pause());
+    entry_points.add_entry_point(unpause());
+    entry_points.add_entry_point(is_paused());
     entry_points.add_entry_point(make_dictionary_item_key());
     entry_points
 }
@@ -92,8 +92,121 @@ pub enum Cep85Error {
     InvalidValue = 79,
     MissingValue = 80,
     NonSuppliedTokenId = 81,
+    TransferRejectedByReceiver = 82,
+    InvalidEnableReceiverCheckFlag = 83,
+    MissingEnableReceiverCheckFlag = 84,
+    InsufficientAllowance = 85,
+    InvalidSpender = 86,
+    MissingSpender = 87,
+    SelfSpenderApproval = 88,
+    InvalidExpiresAt = 89,
+    ExpiresAtInPast = 90,
+    ContractPaused = 91,
+    ContractNotPaused = 92,
+    InvalidPauserList = 93,
+    InvalidPausedFlag = 94,
+    MissingPausedFlag = 95,
+    TokenIdLocked = 96,
+    AccountFrozen = 97,
+    InvalidControllerList = 98,
+    MissingReason = 99,
+    InvalidReason = 100,
+    MissingInstaller = 101,
+    InvalidInstaller = 102,
+    LastAdmin = 103,
+    MissingAdminCount = 104,
+    InvalidAdminCount = 105,
+    NoPendingAdminProposal = 106,
+    SelfAdminProposal = 107,
+    MissingBadge = 108,
+    InvalidBadge = 109,
+    InvalidStart = 110,
+    InvalidLimit = 111,
+    InvalidRevokeFlag = 112,
+    InvalidEndId = 113,
+    InvalidIdRange = 114,
+    InvalidScopedBadge = 115,
+    MissingScopedRole = 116,
+    MintQuotaExceeded = 117,
+    InvalidLifetimeLimit = 118,
+    InvalidWindowLimit = 119,
+    InvalidWindow = 120,
+    MissingAdminThreshold = 121,
+    InvalidAdminThreshold = 122,
+    AdminCountBelowThreshold = 123,
+    MissingAction = 124,
+    InvalidAction = 125,
+    MissingActionArgs = 126,
+    InvalidActionArgs = 127,
+    MissingProposalId = 128,
+    InvalidProposalId = 129,
+    MissingProposalCount = 130,
+    InvalidProposalCount = 131,
+    ProposalNotFound = 132,
+    ProposalNotPending = 133,
+    ProposalExpired = 134,
+    ProposalAlreadyApproved = 135,
+    MissingTimelockDelay = 136,
+    InvalidTimelockDelay = 137,
+    TimelockedAction = 138,
+    MissingOperationId = 139,
+    InvalidOperationId = 140,
+    MissingOperationCount = 141,
+    InvalidOperationCount = 142,
+    OperationNotFound = 143,
+    OperationNotQueued = 144,
+    OperationNotReady = 145,
+    MissingTransferFilters = 146,
+    InvalidTransferFilters = 147,
+    MissingTransferFilterIndex = 148,
+    InvalidTransferFilterIndex = 149,
+    InvalidEnableMintBurnFilterFlag = 150,
+    MissingEnableMintBurnFilterFlag = 151,
+    InvalidAfterTransferHookContract = 152,
+    InvalidAfterTransferHookMethod = 153,
+    MissingAfterTransferHookContract = 154,
+    MissingAfterTransferHookMethod = 155,
+    InvalidBurnMode = 156,
+    MissingBurnMode = 157,
+    MissingTokenKind = 158,
+    InvalidTokenKind = 159,
+    MissingTokenKinds = 160,
+    InvalidTokenKinds = 161,
+    TokenAlreadyCreated = 162,
+    TokenNotCreated = 163,
+    InvalidRegisteredIdsOnlyFlag = 164,
+    MissingRegisteredIdsOnlyFlag = 165,
+    MissingNamespace = 166,
+    InvalidNamespace = 167,
+    IdNamespaceNotFound = 168,
+    IdNamespaceExhausted = 169,
+    MissingCount = 170,
+    InvalidCount = 171,
+    MissingNextId = 172,
+    InvalidNextId = 173,
+    InvalidTypeId = 174,
+    TypeAlreadyCreated = 175,
+    MissingTokenName = 176,
+    InvalidTokenName = 177,
+    MissingTokenSymbol = 178,
+    InvalidTokenSymbol = 179,
+    MissingTokenDecimals = 180,
+    InvalidTokenDecimals = 181,
+    InvalidEnableFilterReasonsFlag = 182,
+    MissingEnableFilterReasonsFlag = 183,
+    InvalidTokenNames = 184,
+    MissingTokenSymbols = 185,
+    InvalidTokenSymbols = 186,
+    MissingTokenDecimalsList = 187,
+    InvalidTokenDecimalsList = 188,
+    MissingAdminList = 189,
+    ListedKeyWithoutBadge = 190,
 }
 
+/// Offset of the user errors reverted with when a transfer filter denies a transfer with a reason
+/// code, the reverted error being this offset plus the reason code.
+pub const TRANSFER_FILTER_REASON_ERROR_OFFSET: u16 = 1000;
+
 impl From<Cep85Error> for ApiError {
     fn from(error: Cep85Error) -> Self {
         ApiError::User(error as u16)
@@ -1,4 +1,4 @@
-use crate::security::SecurityBadge;
+use crate::security::{SecurityBadge, SecurityRoles};
 #[cfg(feature = "contract-support")]
 use crate::{constants::ARG_EVENTS_MODE, modalities::EventsMode, utils::get_stored_value};
 use alloc::{collections::BTreeMap, string::String, vec::Vec};
@@ -7,7 +7,7 @@ use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 use casper_event_standard::Event;
 #[cfg(feature = "contract-support")]
 use casper_event_standard::{emit, Schemas};
-use casper_types::{bytesrepr::Bytes, Key, U256};
+use casper_types::{bytesrepr::Bytes, ContractHash, Key, U256};
 #[cfg(feature = "contract-support")]
 use core::convert::TryFrom;
 
@@ -18,12 +18,44 @@ pub enum Event {
     Burn(Burn),
     BurnBatch(BurnBatch),
     ApprovalForAll(ApprovalForAll),
+    SetAllowance(SetAllowance),
+    IncreaseAllowance(IncreaseAllowance),
+    DecreaseAllowance(DecreaseAllowance),
     Transfer(Transfer),
     TransferBatch(TransferBatch),
+    ForcedTransfer(ForcedTransfer),
     Uri(Uri),
     UriBatch(UriBatch),
+    MetadataUpdate(MetadataUpdate),
     SetTotalSupply(SetTotalSupply),
-    ChangeSecurity(ChangeSecurity),
+    CreateToken(CreateToken),
+    CreateTokenBatch(CreateTokenBatch),
+    CreateType(CreateType),
+    ChangeSecurityRoles(ChangeSecurityRoles),
+    ProposeAdmin(ProposeAdmin),
+    GrantScopedRole(GrantScopedRole),
+    RevokeScopedRole(RevokeScopedRole),
+    SetMintQuota(SetMintQuota),
+    SetIdNamespace(SetIdNamespace),
+    CreateProposal(CreateProposal),
+    ApproveProposal(ApproveProposal),
+    ExecuteProposal(ExecuteProposal),
+    CancelProposal(CancelProposal),
+    QueueOperation(QueueOperation),
+    ExecuteOperation(ExecuteOperation),
+    CancelOperation(CancelOperation),
+    FreezeAccounts(FreezeAccounts),
+    UnfreezeAccounts(UnfreezeAccounts),
+    LockId(LockId),
+    LockIdBatch(LockIdBatch),
+    UnlockId(UnlockId),
+    UnlockIdBatch(UnlockIdBatch),
+    Paused(Paused),
+    Unpaused(Unpaused),
+    SetTransferFilter(SetTransferFilter),
+    AddTransferFilter(AddTransferFilter),
+    RemoveTransferFilter(RemoveTransferFilter),
+    SetAfterTransferHook(SetAfterTransferHook),
     SetModalities(SetModalities),
     Upgrade(Upgrade),
 }
@@ -108,14 +140,77 @@ pub struct ApprovalForAll {
     pub owner: Key,
     pub operator: Key,
     pub approved: bool,
+    pub expires_at: Option<u64>,
 }
 
 impl ApprovalForAll {
-    pub fn new(owner: Key, operator: Key, approved: bool) -> Self {
+    pub fn new(owner: Key, operator: Key, approved: bool, expires_at: Option<u64>) -> Self {
         Self {
             owner,
             operator,
             approved,
+            expires_at,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SetAllowance {
+    pub owner: Key,
+    pub spender: Key,
+    pub id: U256,
+    pub allowance: U256,
+}
+
+impl SetAllowance {
+    pub fn new(owner: Key, spender: Key, id: U256, allowance: U256) -> Self {
+        Self {
+            owner,
+            spender,
+            id,
+            allowance,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct IncreaseAllowance {
+    pub owner: Key,
+    pub spender: Key,
+    pub id: U256,
+    pub allowance: U256,
+    pub inc_by: U256,
+}
+
+impl IncreaseAllowance {
+    pub fn new(owner: Key, spender: Key, id: U256, allowance: U256, inc_by: U256) -> Self {
+        Self {
+            owner,
+            spender,
+            id,
+            allowance,
+            inc_by,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct DecreaseAllowance {
+    pub owner: Key,
+    pub spender: Key,
+    pub id: U256,
+    pub allowance: U256,
+    pub decr_by: U256,
+}
+
+impl DecreaseAllowance {
+    pub fn new(owner: Key, spender: Key, id: U256, allowance: U256, decr_by: U256) -> Self {
+        Self {
+            owner,
+            spender,
+            id,
+            allowance,
+            decr_by,
         }
     }
 }
@@ -180,6 +275,39 @@ impl TransferBatch {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ForcedTransfer {
+    pub operator: Key,
+    pub from: Key,
+    pub to: Key,
+    pub ids: Vec<U256>,
+    pub values: Vec<U256>,
+    pub reason: String,
+    pub data: Option<Bytes>,
+}
+
+impl ForcedTransfer {
+    pub fn new(
+        operator: Key,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        reason: String,
+        data: Option<Bytes>,
+    ) -> Self {
+        Self {
+            operator,
+            from,
+            to,
+            ids,
+            values,
+            reason,
+            data,
+        }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq)]
 pub struct Uri {
     pub value: String,
@@ -204,6 +332,25 @@ impl UriBatch {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MetadataUpdate {
+    pub id: U256,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl MetadataUpdate {
+    pub fn new(id: U256, name: String, symbol: String, decimals: u8) -> Self {
+        Self {
+            id,
+            name,
+            symbol,
+            decimals,
+        }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq)]
 pub struct SetTotalSupply {
     pub id: U256,
@@ -217,13 +364,58 @@ impl SetTotalSupply {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
-pub struct ChangeSecurity {
+pub struct CreateToken {
+    pub id: U256,
+    pub token_kind: u8,
+    pub total_supply: U256,
+}
+
+impl CreateToken {
+    pub fn new(id: U256, token_kind: u8, total_supply: U256) -> Self {
+        Self {
+            id,
+            token_kind,
+            total_supply,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct CreateTokenBatch {
+    pub ids: Vec<U256>,
+    pub token_kinds: Vec<u8>,
+    pub total_supplies: Vec<U256>,
+}
+
+impl CreateTokenBatch {
+    pub fn new(ids: Vec<U256>, token_kinds: Vec<u8>, total_supplies: Vec<U256>) -> Self {
+        Self {
+            ids,
+            token_kinds,
+            total_supplies,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct CreateType {
+    pub id: U256,
+}
+
+impl CreateType {
+    pub fn new(id: U256) -> Self {
+        Self { id }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeSecurityRoles {
     pub admin: Key,
-    pub sec_change_map: BTreeMap<Key, SecurityBadge>,
+    pub sec_change_map: BTreeMap<Key, SecurityRoles>,
 }
 
-impl ChangeSecurity {
-    pub fn new(admin: Key, sec_change_map: BTreeMap<Key, SecurityBadge>) -> Self {
+impl ChangeSecurityRoles {
+    pub fn new(admin: Key, sec_change_map: BTreeMap<Key, SecurityRoles>) -> Self {
         Self {
             admin,
             sec_change_map,
@@ -231,6 +423,411 @@ impl ChangeSecurity {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposeAdmin {
+    pub proposer: Key,
+    pub candidate: Key,
+}
+
+impl ProposeAdmin {
+    pub fn new(proposer: Key, candidate: Key) -> Self {
+        Self {
+            proposer,
+            candidate,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct GrantScopedRole {
+    pub admin: Key,
+    pub account: Key,
+    pub badge: SecurityBadge,
+    pub start_id: U256,
+    pub end_id: U256,
+}
+
+impl GrantScopedRole {
+    pub fn new(
+        admin: Key,
+        account: Key,
+        badge: SecurityBadge,
+        start_id: U256,
+        end_id: U256,
+    ) -> Self {
+        Self {
+            admin,
+            account,
+            badge,
+            start_id,
+            end_id,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RevokeScopedRole {
+    pub admin: Key,
+    pub account: Key,
+    pub badge: SecurityBadge,
+    pub start_id: U256,
+    pub end_id: U256,
+}
+
+impl RevokeScopedRole {
+    pub fn new(
+        admin: Key,
+        account: Key,
+        badge: SecurityBadge,
+        start_id: U256,
+        end_id: U256,
+    ) -> Self {
+        Self {
+            admin,
+            account,
+            badge,
+            start_id,
+            end_id,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SetMintQuota {
+    pub admin: Key,
+    pub account: Key,
+    pub id: Option<U256>,
+    pub lifetime_limit: Option<U256>,
+    pub window_limit: Option<U256>,
+    pub window: u64,
+}
+
+impl SetMintQuota {
+    pub fn new(
+        admin: Key,
+        account: Key,
+        id: Option<U256>,
+        lifetime_limit: Option<U256>,
+        window_limit: Option<U256>,
+        window: u64,
+    ) -> Self {
+        Self {
+            admin,
+            account,
+            id,
+            lifetime_limit,
+            window_limit,
+            window,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SetIdNamespace {
+    pub namespace: String,
+    pub start_id: U256,
+    pub end_id: U256,
+}
+
+impl SetIdNamespace {
+    pub fn new(namespace: String, start_id: U256, end_id: U256) -> Self {
+        Self {
+            namespace,
+            start_id,
+            end_id,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct CreateProposal {
+    pub proposal_id: u32,
+    pub proposer: Key,
+    pub action: String,
+    pub action_args: Bytes,
+    pub expires_at: Option<u64>,
+}
+
+impl CreateProposal {
+    pub fn new(
+        proposal_id: u32,
+        proposer: Key,
+        action: String,
+        action_args: Bytes,
+        expires_at: Option<u64>,
+    ) -> Self {
+        Self {
+            proposal_id,
+            proposer,
+            action,
+            action_args,
+            expires_at,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ApproveProposal {
+    pub proposal_id: u32,
+    pub admin: Key,
+    pub approvals: u32,
+}
+
+impl ApproveProposal {
+    pub fn new(proposal_id: u32, admin: Key, approvals: u32) -> Self {
+        Self {
+            proposal_id,
+            admin,
+            approvals,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ExecuteProposal {
+    pub proposal_id: u32,
+}
+
+impl ExecuteProposal {
+    pub fn new(proposal_id: u32) -> Self {
+        Self { proposal_id }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct CancelProposal {
+    pub proposal_id: u32,
+    pub admin: Key,
+}
+
+impl CancelProposal {
+    pub fn new(proposal_id: u32, admin: Key) -> Self {
+        Self { proposal_id, admin }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct QueueOperation {
+    pub operation_id: u32,
+    pub proposer: Key,
+    pub action: String,
+    pub action_args: Bytes,
+    pub executable_at: u64,
+}
+
+impl QueueOperation {
+    pub fn new(
+        operation_id: u32,
+        proposer: Key,
+        action: String,
+        action_args: Bytes,
+        executable_at: u64,
+    ) -> Self {
+        Self {
+            operation_id,
+            proposer,
+            action,
+            action_args,
+            executable_at,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ExecuteOperation {
+    pub operation_id: u32,
+}
+
+impl ExecuteOperation {
+    pub fn new(operation_id: u32) -> Self {
+        Self { operation_id }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct CancelOperation {
+    pub operation_id: u32,
+    pub admin: Key,
+}
+
+impl CancelOperation {
+    pub fn new(operation_id: u32, admin: Key) -> Self {
+        Self {
+            operation_id,
+            admin,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FreezeAccounts {
+    pub accounts: Vec<Key>,
+}
+
+impl FreezeAccounts {
+    pub fn new(accounts: Vec<Key>) -> Self {
+        Self { accounts }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct UnfreezeAccounts {
+    pub accounts: Vec<Key>,
+}
+
+impl UnfreezeAccounts {
+    pub fn new(accounts: Vec<Key>) -> Self {
+        Self { accounts }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct LockId {
+    pub id: U256,
+}
+
+impl LockId {
+    pub fn new(id: U256) -> Self {
+        Self { id }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct LockIdBatch {
+    pub ids: Vec<U256>,
+}
+
+impl LockIdBatch {
+    pub fn new(ids: Vec<U256>) -> Self {
+        Self { ids }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct UnlockId {
+    pub id: U256,
+}
+
+impl UnlockId {
+    pub fn new(id: U256) -> Self {
+        Self { id }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct UnlockIdBatch {
+    pub ids: Vec<U256>,
+}
+
+impl UnlockIdBatch {
+    pub fn new(ids: Vec<U256>) -> Self {
+        Self { ids }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Paused {
+    pub account: Key,
+}
+
+impl Paused {
+    pub fn new(account: Key) -> Self {
+        Self { account }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Unpaused {
+    pub account: Key,
+}
+
+impl Unpaused {
+    pub fn new(account: Key) -> Self {
+        Self { account }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SetTransferFilter {
+    pub transfer_filter_contract: Option<ContractHash>,
+    pub transfer_filter_method: Option<String>,
+}
+
+impl SetTransferFilter {
+    pub fn new(
+        transfer_filter_contract: Option<ContractHash>,
+        transfer_filter_method: Option<String>,
+    ) -> Self {
+        Self {
+            transfer_filter_contract,
+            transfer_filter_method,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AddTransferFilter {
+    pub transfer_filter_contract: ContractHash,
+    pub transfer_filter_method: String,
+    pub start_id: Option<U256>,
+    pub end_id: Option<U256>,
+}
+
+impl AddTransferFilter {
+    pub fn new(
+        transfer_filter_contract: ContractHash,
+        transfer_filter_method: String,
+        start_id: Option<U256>,
+        end_id: Option<U256>,
+    ) -> Self {
+        Self {
+            transfer_filter_contract,
+            transfer_filter_method,
+            start_id,
+            end_id,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RemoveTransferFilter {
+    pub index: u32,
+    pub transfer_filter_contract: ContractHash,
+    pub transfer_filter_method: String,
+}
+
+impl RemoveTransferFilter {
+    pub fn new(
+        index: u32,
+        transfer_filter_contract: ContractHash,
+        transfer_filter_method: String,
+    ) -> Self {
+        Self {
+            index,
+            transfer_filter_contract,
+            transfer_filter_method,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SetAfterTransferHook {
+    pub after_transfer_hook_contract: Option<ContractHash>,
+    pub after_transfer_hook_method: Option<String>,
+}
+
+impl SetAfterTransferHook {
+    pub fn new(
+        after_transfer_hook_contract: Option<ContractHash>,
+        after_transfer_hook_method: Option<String>,
+    ) -> Self {
+        Self {
+            after_transfer_hook_contract,
+            after_transfer_hook_method,
+        }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq, Default)]
 pub struct SetModalities {}
 
@@ -257,12 +854,44 @@ fn ces(event: Event) {
         Event::Burn(ev) => emit(ev),
         Event::BurnBatch(ev) => emit(ev),
         Event::ApprovalForAll(ev) => emit(ev),
+        Event::SetAllowance(ev) => emit(ev),
+        Event::IncreaseAllowance(ev) => emit(ev),
+        Event::DecreaseAllowance(ev) => emit(ev),
         Event::Transfer(ev) => emit(ev),
         Event::TransferBatch(ev) => emit(ev),
+        Event::ForcedTransfer(ev) => emit(ev),
         Event::Uri(ev) => emit(ev),
         Event::UriBatch(ev) => emit(ev),
+        Event::MetadataUpdate(ev) => emit(ev),
         Event::SetTotalSupply(ev) => emit(ev),
-        Event::ChangeSecurity(ev) => emit(ev),
+        Event::CreateToken(ev) => emit(ev),
+        Event::CreateTokenBatch(ev) => emit(ev),
+        Event::CreateType(ev) => emit(ev),
+        Event::ChangeSecurityRoles(ev) => emit(ev),
+        Event::ProposeAdmin(ev) => emit(ev),
+        Event::GrantScopedRole(ev) => emit(ev),
+        Event::RevokeScopedRole(ev) => emit(ev),
+        Event::SetMintQuota(ev) => emit(ev),
+        Event::SetIdNamespace(ev) => emit(ev),
+        Event::CreateProposal(ev) => emit(ev),
+        Event::ApproveProposal(ev) => emit(ev),
+        Event::ExecuteProposal(ev) => emit(ev),
+        Event::CancelProposal(ev) => emit(ev),
+        Event::QueueOperation(ev) => emit(ev),
+        Event::ExecuteOperation(ev) => emit(ev),
+        Event::CancelOperation(ev) => emit(ev),
+        Event::FreezeAccounts(ev) => emit(ev),
+        Event::UnfreezeAccounts(ev) => emit(ev),
+        Event::LockId(ev) => emit(ev),
+        Event::LockIdBatch(ev) => emit(ev),
+        Event::UnlockId(ev) => emit(ev),
+        Event::UnlockIdBatch(ev) => emit(ev),
+        Event::Paused(ev) => emit(ev),
+        Event::Unpaused(ev) => emit(ev),
+        Event::SetTransferFilter(ev) => emit(ev),
+        Event::AddTransferFilter(ev) => emit(ev),
+        Event::RemoveTransferFilter(ev) => emit(ev),
+        Event::SetAfterTransferHook(ev) => emit(ev),
         Event::SetModalities(ev) => emit(ev),
         Event::Upgrade(ev) => emit(ev),
     }
@@ -280,12 +909,44 @@ pub fn init_events() {
             .with::<Burn>()
             .with::<BurnBatch>()
             .with::<ApprovalForAll>()
+            .with::<SetAllowance>()
+            .with::<IncreaseAllowance>()
+            .with::<DecreaseAllowance>()
             .with::<Transfer>()
             .with::<TransferBatch>()
+            .with::<ForcedTransfer>()
             .with::<Uri>()
             .with::<UriBatch>()
+            .with::<MetadataUpdate>()
             .with::<SetTotalSupply>()
-            .with::<ChangeSecurity>()
+            .with::<CreateToken>()
+            .with::<CreateTokenBatch>()
+            .with::<CreateType>()
+            .with::<ChangeSecurityRoles>()
+            .with::<ProposeAdmin>()
+            .with::<GrantScopedRole>()
+            .with::<RevokeScopedRole>()
+            .with::<SetMintQuota>()
+            .with::<SetIdNamespace>()
+            .with::<CreateProposal>()
+            .with::<ApproveProposal>()
+            .with::<ExecuteProposal>()
+            .with::<CancelProposal>()
+            .with::<QueueOperation>()
+            .with::<ExecuteOperation>()
+            .with::<CancelOperation>()
+            .with::<FreezeAccounts>()
+            .with::<UnfreezeAccounts>()
+            .with::<LockId>()
+            .with::<LockIdBatch>()
+            .with::<UnlockId>()
+            .with::<UnlockIdBatch>()
+            .with::<Paused>()
+            .with::<Unpaused>()
+            .with::<SetTransferFilter>()
+            .with::<AddTransferFilter>()
+            .with::<RemoveTransferFilter>()
+            .with::<SetAfterTransferHook>()
             .with::<SetModalities>()
             .with::<Upgrade>();
         casper_event_standard::init(schemas);
@@ -0,0 +1,24 @@
+//! Implementation of frozen accounts.
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{bytesrepr::ToBytes, Key};
+
+use crate::{
+    constants::DICT_FROZEN_ACCOUNTS,
+    utils::{get_dictionary_value_from_key, set_dictionary_value_for_key},
+};
+
+pub fn write_frozen(account: &Key, frozen: bool) {
+    set_dictionary_value_for_key(
+        DICT_FROZEN_ACCOUNTS,
+        &hex::encode(account.to_bytes().unwrap_or_revert()),
+        &frozen,
+    )
+}
+
+pub fn read_frozen(account: &Key) -> bool {
+    get_dictionary_value_from_key(
+        DICT_FROZEN_ACCOUNTS,
+        &hex::encode(account.to_bytes().unwrap_or_revert()),
+    )
+    .unwrap_or_default()
+}
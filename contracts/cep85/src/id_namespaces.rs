@@ -0,0 +1,97 @@
+//! Implementation of id allocation for `mint_next`, optionally within id namespaces.
+use alloc::vec::Vec;
+use casper_contract::{
+    contract_api::{
+        runtime::{put_key, revert},
+        storage,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::U256;
+
+use crate::{
+    constants::{DICT_ID_NAMESPACES, NEXT_ID},
+    error::Cep85Error,
+    supply::read_total_supply_of,
+    token_kinds::read_token_kind,
+    utils::{
+        get_dictionary_value_from_key, get_stored_value_with_user_errors,
+        set_dictionary_value_for_key,
+    },
+};
+
+/// Inclusive id range of a namespace and the next id to allocate in it.
+pub type IdNamespace = (U256, U256, U256);
+
+pub fn write_id_namespace(namespace: &str, start_id: &U256, end_id: &U256, next_id: &U256) {
+    set_dictionary_value_for_key(
+        DICT_ID_NAMESPACES,
+        namespace,
+        &(*start_id, *end_id, *next_id),
+    )
+}
+
+pub fn read_id_namespace(namespace: &str) -> IdNamespace {
+    get_dictionary_value_from_key(DICT_ID_NAMESPACES, namespace)
+        .unwrap_or_revert_with(Cep85Error::IdNamespaceNotFound)
+}
+
+fn is_free_id(id: &U256) -> bool {
+    read_total_supply_of(id).unwrap_or_default().is_zero() && read_token_kind(id).is_none()
+}
+
+/// Returns the first free id after `id`, or `None` when there is none up to `end_id`.
+fn next_free_id_after(id: &U256, end_id: &U256) -> Option<U256> {
+    let mut next_id = *id;
+    while next_id < *end_id {
+        next_id += U256::one();
+        if is_free_id(&next_id) {
+            return Some(next_id);
+        }
+    }
+    None
+}
+
+/// Allocates `count` ids that were neither minted nor created, taken from the counter of
+/// `namespace` or from the collection counter.
+///
+/// The counter is stored on the next free id, skipping the ids taken after the allocated ones.
+/// Once the last id of the range is allocated, the counter stays on it and later allocations
+/// revert with `IdNamespaceExhausted`.
+pub fn allocate_next_ids(namespace: Option<&str>, count: u32) -> Vec<U256> {
+    let (start_id, end_id, stored_next_id) = match namespace {
+        Some(namespace) => read_id_namespace(namespace),
+        None => (
+            U256::zero(),
+            U256::MAX,
+            get_stored_value_with_user_errors(
+                NEXT_ID,
+                Cep85Error::MissingNextId,
+                Cep85Error::InvalidNextId,
+            ),
+        ),
+    };
+
+    // The id under the counter may have been minted or created directly since it was stored.
+    let mut next_id = if stored_next_id > end_id {
+        None
+    } else if is_free_id(&stored_next_id) {
+        Some(stored_next_id)
+    } else {
+        next_free_id_after(&stored_next_id, &end_id)
+    };
+
+    let mut ids: Vec<U256> = Vec::new();
+    for _ in 0..count {
+        let id = next_id.unwrap_or_revert_with(Cep85Error::IdNamespaceExhausted);
+        ids.push(id);
+        next_id = next_free_id_after(&id, &end_id);
+    }
+
+    let next_id = next_id.unwrap_or(end_id);
+    match namespace {
+        Some(namespace) => write_id_namespace(namespace, &start_id, &end_id, &next_id),
+        None => put_key(NEXT_ID, storage::new_uref(next_id).into()),
+    }
+    ids
+}
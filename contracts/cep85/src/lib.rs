@@ -10,11 +10,35 @@ pub mod modalities;
 pub mod security;
 pub mod utils;
 
+#[cfg(feature = "contract-support")]
+pub mod allowances;
 #[cfg(feature = "contract-support")]
 pub mod balances;
 #[cfg(feature = "contract-support")]
+pub mod frozen;
+#[cfg(feature = "contract-support")]
+pub mod id_namespaces;
+#[cfg(feature = "contract-support")]
+pub mod locks;
+#[cfg(feature = "contract-support")]
+pub mod metadata;
+#[cfg(feature = "contract-support")]
 pub mod operators;
 #[cfg(feature = "contract-support")]
+pub mod proposals;
+#[cfg(feature = "contract-support")]
+pub mod quotas;
+#[cfg(feature = "contract-support")]
+pub mod scoped_roles;
+#[cfg(feature = "contract-support")]
+pub mod split_ids;
+#[cfg(feature = "contract-support")]
 pub mod supply;
 #[cfg(feature = "contract-support")]
+pub mod timelock;
+#[cfg(feature = "contract-support")]
+pub mod token_kinds;
+#[cfg(feature = "contract-support")]
+pub mod transfer_filters;
+#[cfg(feature = "contract-support")]
 pub mod uri;
@@ -0,0 +1,16 @@
+//! Implementation of token id locks.
+use alloc::string::ToString;
+use casper_types::U256;
+
+use crate::{
+    constants::DICT_LOCKED_IDS,
+    utils::{get_dictionary_value_from_key, set_dictionary_value_for_key},
+};
+
+pub fn write_id_lock(id: &U256, locked: bool) {
+    set_dictionary_value_for_key(DICT_LOCKED_IDS, &id.to_string(), &locked)
+}
+
+pub fn read_id_lock(id: &U256) -> bool {
+    get_dictionary_value_from_key(DICT_LOCKED_IDS, &id.to_string()).unwrap_or_default()
+}
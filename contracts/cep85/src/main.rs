@@ -26,38 +26,89 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    bytesrepr::Bytes, contracts::NamedKeys, runtime_args, CLValue, ContractHash, Key, RuntimeArgs,
-    U256,
+    bytesrepr::{Bytes, FromBytes},
+    contracts::NamedKeys,
+    runtime_args, ApiError, CLValue, ContractHash, Key, RuntimeArgs, U256,
 };
 use cep85::{
+    allowances::{read_allowance_of, write_allowance_to},
     balances::{batch_transfer_balance, read_balance_from, transfer_balance, write_balance_to},
     constants::{
-        ADMIN_LIST, ARG_ACCOUNT, ARG_ACCOUNTS, ARG_AMOUNT, ARG_AMOUNTS, ARG_APPROVED,
-        ARG_CONTRACT_HASH, ARG_DATA, ARG_ENABLE_BURN, ARG_EVENTS_MODE, ARG_FROM, ARG_ID, ARG_IDS,
-        ARG_NAME, ARG_OPERATOR, ARG_OWNER, ARG_PACKAGE_HASH, ARG_RECIPIENT,
-        ARG_SESSION_NAMED_KEY_NAME, ARG_TO, ARG_TOTAL_SUPPLIES, ARG_TOTAL_SUPPLY,
-        ARG_TRANSFER_FILTER_CONTRACT, ARG_TRANSFER_FILTER_METHOD, ARG_UPGRADE_FLAG, ARG_URI,
-        BURNER_LIST, DEFAULT_DICT_ITEM_KEY_NAME, DICT_BALANCES, DICT_OPERATORS,
-        DICT_SECURITY_BADGES, DICT_SUPPLY, DICT_TOKEN_URI, DICT_TOTAL_SUPPLY, ENTRY_POINT_INIT,
-        ENTRY_POINT_UPGRADE, META_LIST, MINTER_LIST, NONE_LIST, PREFIX_ACCESS_KEY_NAME,
+        ADMIN_COUNT, ADMIN_LIST, ARG_ACCOUNT, ARG_ACCOUNTS, ARG_ACTION, ARG_ACTION_ARGS,
+        ARG_ADMIN_THRESHOLD, ARG_AFTER_TRANSFER_HOOK_CONTRACT, ARG_AFTER_TRANSFER_HOOK_METHOD,
+        ARG_AMOUNT, ARG_AMOUNTS, ARG_APPROVED, ARG_BADGE, ARG_BURN_MODE, ARG_CONTRACT_HASH,
+        ARG_COUNT, ARG_DATA, ARG_DECIMALS, ARG_DECIMALS_LIST, ARG_ENABLE_BURN,
+        ARG_ENABLE_FILTER_REASONS, ARG_ENABLE_MINT_BURN_FILTER, ARG_ENABLE_RECEIVER_CHECK,
+        ARG_END_ID, ARG_EVENTS_MODE, ARG_EXPIRES_AT, ARG_FROM, ARG_ID, ARG_IDS, ARG_INDEX,
+        ARG_LIFETIME_LIMIT, ARG_LIMIT, ARG_NAME, ARG_NAMES, ARG_NAMESPACE, ARG_OPERATION_ID,
+        ARG_OPERATOR, ARG_OWNER, ARG_PACKAGE_HASH, ARG_PROPOSAL_ID, ARG_REASON, ARG_RECIPIENT,
+        ARG_REGISTERED_IDS_ONLY, ARG_REVOKE, ARG_SESSION_NAMED_KEY_NAME, ARG_SPENDER, ARG_START,
+        ARG_SYMBOL, ARG_SYMBOLS, ARG_TIMELOCK_DELAY, ARG_TO, ARG_TOKEN_KIND, ARG_TOKEN_KINDS,
+        ARG_TOTAL_SUPPLIES, ARG_TOTAL_SUPPLY, ARG_TRANSFER_FILTER_CONTRACT,
+        ARG_TRANSFER_FILTER_METHOD, ARG_UPGRADE_FLAG, ARG_URI, ARG_WINDOW, ARG_WINDOW_LIMIT,
+        BURNER_LIST, CONTROLLER_LIST, DEFAULT_DICT_ITEM_KEY_NAME, DICT_ADMIN_PROPOSALS,
+        DICT_ALLOWANCES, DICT_BALANCES, DICT_FROZEN_ACCOUNTS, DICT_ID_NAMESPACES, DICT_LOCKED_IDS,
+        DICT_MINT_QUOTAS, DICT_MINT_QUOTA_USAGES, DICT_OPERATIONS, DICT_OPERATION_STATES,
+        DICT_OPERATORS, DICT_OPERATOR_EXPIRIES, DICT_PROPOSALS, DICT_PROPOSAL_APPROVALS,
+        DICT_PROPOSAL_STATES, DICT_ROLE_MEMBERS, DICT_ROLE_MEMBER_COUNTS, DICT_ROLE_MEMBER_INDEXES,
+        DICT_SCOPED_ROLES, DICT_SCOPED_ROLE_COUNTS, DICT_SECURITY_ROLES, DICT_SUPPLY,
+        DICT_TOKEN_KINDS, DICT_TOKEN_METADATA, DICT_TOKEN_TYPES, DICT_TOKEN_URI, DICT_TOTAL_SUPPLY,
+        DICT_TYPE_BALANCES, DICT_TYPE_ITEMS, ENTRY_POINT_INIT, ENTRY_POINT_ON_CEP85_BATCH_RECEIVED,
+        ENTRY_POINT_ON_CEP85_RECEIVED, ENTRY_POINT_UPGRADE, INSTALLER, META_LIST, MINTER_LIST,
+        NEXT_ID, NONE_LIST, NULL_KEY, OPERATION_COUNT, PAUSED, PAUSER_LIST, PREFIX_ACCESS_KEY_NAME,
         PREFIX_CONTRACT_NAME, PREFIX_CONTRACT_PACKAGE_NAME, PREFIX_CONTRACT_VERSION,
+        PROPOSAL_COUNT, TRANSFER_FILTERS,
     },
     entry_points::generate_entry_points,
-    error::Cep85Error,
+    error::{Cep85Error, TRANSFER_FILTER_REASON_ERROR_OFFSET},
     events::{
-        init_events, record_event_dictionary, ApprovalForAll, Burn, BurnBatch, ChangeSecurity,
-        Event, Mint, MintBatch, SetModalities, SetTotalSupply, Transfer, TransferBatch, Upgrade,
-        Uri, UriBatch,
+        init_events, record_event_dictionary, AddTransferFilter, ApprovalForAll, ApproveProposal,
+        Burn, BurnBatch, CancelOperation, CancelProposal, ChangeSecurityRoles, CreateProposal,
+        CreateToken, CreateTokenBatch, CreateType, DecreaseAllowance, Event, ExecuteOperation,
+        ExecuteProposal, ForcedTransfer, FreezeAccounts, GrantScopedRole, IncreaseAllowance,
+        LockId, LockIdBatch, MetadataUpdate, Mint, MintBatch, Paused, ProposeAdmin, QueueOperation,
+        RemoveTransferFilter, RevokeScopedRole, SetAfterTransferHook, SetAllowance, SetIdNamespace,
+        SetMintQuota, SetModalities, SetTotalSupply, SetTransferFilter, Transfer, TransferBatch,
+        UnfreezeAccounts, UnlockId, UnlockIdBatch, Unpaused, Upgrade, Uri, UriBatch,
+    },
+    frozen::{read_frozen, write_frozen},
+    id_namespaces::{allocate_next_ids, write_id_namespace},
+    locks::{read_id_lock, write_id_lock},
+    metadata::{read_metadata_of, write_metadata_of, TokenMetadata},
+    modalities::{
+        BurnMode, EventsMode, TokenKind, TokenReceiverResult, TransferFilterContractResult,
+    },
+    operators::{read_operator, read_operator_expiry, write_operator},
+    proposals::{
+        read_pending_proposal_state, read_proposal_action, write_proposal_action,
+        write_proposal_approval, write_proposal_state, ProposalStatus, PROPOSAL_ACTIONS,
+    },
+    quotas::{read_remaining_mint_quota, spend_mint_quota, write_mint_quota},
+    scoped_roles::{add_scoped_role, remove_scoped_role},
+    security::{
+        backfill_role_members, change_sec_badge, grant_badge, read_admin_proposal,
+        read_admin_threshold, read_role_member_count, read_role_members, read_security_roles,
+        revoke_badge, sec_check, sec_check_admin_action, sec_check_ids,
+        sec_check_timelocked_action, write_admin_proposal, SecurityBadge, SecurityRoles,
+    },
+    split_ids::{
+        is_type_item_id, read_token_type, read_type_balance_from, type_id_of, write_token_type,
+        write_type_item,
     },
-    modalities::{EventsMode, TransferFilterContractResult},
-    operators::{read_operator, write_operator},
-    security::{change_sec_badge, sec_check, SecurityBadge},
     supply::{read_supply_of, read_total_supply_of, write_supply_of, write_total_supply_of},
+    timelock::{
+        read_operation_action, read_queued_operation_state, read_ready_operation_state,
+        read_timelock_delay, write_operation_action, write_operation_state, OperationStatus,
+        TIMELOCKED_ACTIONS,
+    },
+    token_kinds::{read_token_kind, write_token_kind},
+    transfer_filters::{filter_ids_in_scope, read_transfer_filters, write_transfer_filters},
     uri::{read_uri_of, write_uri_of},
     utils::{
-        get_named_arg_with_user_errors, get_optional_named_arg_with_user_errors,
-        get_stored_value_with_user_errors, get_transfer_filter_contract,
-        get_transfer_filter_method, get_verified_caller,
+        get_after_transfer_hook_contract, get_after_transfer_hook_method,
+        get_current_contract_hash, get_named_arg_with_user_errors,
+        get_optional_named_arg_with_user_errors, get_stored_value_with_user_errors,
+        get_transfer_filter_contract, get_transfer_filter_method, get_verified_caller,
         make_dictionary_item_key as utils_make_dictionary_item_key,
     },
 };
@@ -119,22 +170,70 @@ pub extern "C" fn init() {
         storage::new_uref(transfer_filter_method).into(),
     );
 
+    write_transfer_filters(Vec::new());
+
     storage::new_dictionary(DICT_BALANCES)
         .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_LOCKED_IDS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_FROZEN_ACCOUNTS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_ADMIN_PROPOSALS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_ALLOWANCES)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
     storage::new_dictionary(DICT_OPERATORS)
         .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_OPERATOR_EXPIRIES)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
     storage::new_dictionary(DICT_SUPPLY)
         .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
     storage::new_dictionary(DICT_TOTAL_SUPPLY)
         .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
     storage::new_dictionary(DICT_TOKEN_URI)
         .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_TOKEN_KINDS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_ID_NAMESPACES)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_TOKEN_TYPES)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_TYPE_ITEMS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_TYPE_BALANCES)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_TOKEN_METADATA)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
 
     init_events();
 
-    storage::new_dictionary(DICT_SECURITY_BADGES).unwrap_or_revert();
+    storage::new_dictionary(DICT_SECURITY_ROLES).unwrap_or_revert();
+    storage::new_dictionary(DICT_ROLE_MEMBERS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_ROLE_MEMBER_INDEXES)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_ROLE_MEMBER_COUNTS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_SCOPED_ROLES)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_SCOPED_ROLE_COUNTS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_MINT_QUOTAS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_MINT_QUOTA_USAGES)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_PROPOSALS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_PROPOSAL_STATES)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_PROPOSAL_APPROVALS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_OPERATIONS)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_OPERATION_STATES)
+        .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
 
-    let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
+    let mut roles_map: BTreeMap<Key, SecurityRoles> = BTreeMap::new();
 
     let admin_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep85Error::InvalidAdminList);
@@ -144,23 +243,27 @@ pub extern "C" fn init() {
         get_optional_named_arg_with_user_errors(BURNER_LIST, Cep85Error::InvalidBurnerList);
     let meta_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(META_LIST, Cep85Error::InvalidMetaList);
+    let pauser_list: Option<Vec<Key>> =
+        get_optional_named_arg_with_user_errors(PAUSER_LIST, Cep85Error::InvalidPauserList);
+    let controller_list: Option<Vec<Key>> =
+        get_optional_named_arg_with_user_errors(CONTROLLER_LIST, Cep85Error::InvalidControllerList);
     let none_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(NONE_LIST, Cep85Error::InvalidNoneList);
 
     if let Some(minter_list) = minter_list {
-        for account_key in minter_list {
-            badge_map.insert(account_key, SecurityBadge::Minter);
-        }
+        grant_badge(&mut roles_map, minter_list, SecurityBadge::Minter);
     }
     if let Some(burner_list) = burner_list {
-        for account_key in burner_list {
-            badge_map.insert(account_key, SecurityBadge::Burner);
-        }
+        grant_badge(&mut roles_map, burner_list, SecurityBadge::Burner);
     }
     if let Some(meta_list) = meta_list {
-        for account_key in meta_list {
-            badge_map.insert(account_key, SecurityBadge::Meta);
-        }
+        grant_badge(&mut roles_map, meta_list, SecurityBadge::Meta);
+    }
+    if let Some(pauser_list) = pauser_list {
+        grant_badge(&mut roles_map, pauser_list, SecurityBadge::Pauser);
+    }
+    if let Some(controller_list) = controller_list {
+        grant_badge(&mut roles_map, controller_list, SecurityBadge::Controller);
     }
 
     if admin_list.is_none()
@@ -169,19 +272,23 @@ pub extern "C" fn init() {
             .unwrap_or_revert_with(Cep85Error::InvalidAdminList)
             .is_empty()
     {
-        badge_map.insert(get_verified_caller().0, SecurityBadge::Admin);
+        grant_badge(
+            &mut roles_map,
+            vec![get_verified_caller().0],
+            SecurityBadge::Admin,
+        );
     } else if let Some(admin_list) = admin_list {
-        for account_key in admin_list {
-            badge_map.insert(account_key, SecurityBadge::Admin);
-        }
+        grant_badge(&mut roles_map, admin_list, SecurityBadge::Admin);
     }
     if let Some(none_list) = none_list {
-        for account_key in none_list {
-            badge_map.insert(account_key, SecurityBadge::None);
-        }
+        grant_badge(&mut roles_map, none_list, SecurityBadge::None);
     }
 
-    change_sec_badge(&badge_map);
+    put_key(ADMIN_COUNT, storage::new_uref(0u32).into());
+    put_key(PROPOSAL_COUNT, storage::new_uref(0u32).into());
+    put_key(OPERATION_COUNT, storage::new_uref(0u32).into());
+    put_key(NEXT_ID, storage::new_uref(U256::one()).into());
+    change_sec_badge(&roles_map);
 }
 
 #[no_mangle]
@@ -230,6 +337,27 @@ pub extern "C" fn balance_of_batch() {
     runtime::ret(CLValue::from_t(batch_balances).unwrap_or_revert());
 }
 
+/// Returns the number of items an account holds in the type of the split `id`, which can be the
+/// base type id or the id of any item of the type, or `None` when the type was not created.
+#[no_mangle]
+pub extern "C" fn balance_of_type() {
+    let account: Key = get_named_arg_with_user_errors(
+        ARG_ACCOUNT,
+        Cep85Error::MissingAccount,
+        Cep85Error::InvalidAccount,
+    )
+    .unwrap_or_revert();
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+    let type_id = type_id_of(&id);
+    if read_token_type(&type_id).is_none() {
+        runtime::ret(CLValue::from_t::<Option<U256>>(None).unwrap_or_revert());
+    }
+    let balance: U256 = read_type_balance_from(&account, &type_id);
+    runtime::ret(CLValue::from_t(Some(balance)).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn is_approved_for_all() {
     let owner: Key = get_named_arg_with_user_errors(
@@ -251,6 +379,29 @@ pub extern "C" fn is_approved_for_all() {
     runtime::ret(CLValue::from_t(is_approved_for_all).unwrap_or_revert());
 }
 
+/// Returns the block time at which the approval of `operator` expires, `None` if the approval
+/// does not expire.
+#[no_mangle]
+pub extern "C" fn approval_expires_at() {
+    let owner: Key = get_named_arg_with_user_errors(
+        ARG_ACCOUNT,
+        Cep85Error::MissingAccount,
+        Cep85Error::InvalidAccount,
+    )
+    .unwrap_or_revert();
+
+    let operator: Key = get_named_arg_with_user_errors(
+        ARG_OPERATOR,
+        Cep85Error::MissingOperator,
+        Cep85Error::InvalidOperator,
+    )
+    .unwrap_or_revert();
+
+    let expires_at: Option<u64> = read_operator_expiry(&owner, &operator);
+
+    runtime::ret(CLValue::from_t(expires_at).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn make_dictionary_item_key() {
     let owner: Key =
@@ -261,7 +412,14 @@ pub extern "C" fn make_dictionary_item_key() {
         get_optional_named_arg_with_user_errors(ARG_ID, Cep85Error::InvalidValue);
 
     let dictionary_item_key: String = match id {
-        Some(id) => utils_make_dictionary_item_key(&owner, &id),
+        Some(id) => {
+            let spender: Option<Key> =
+                get_optional_named_arg_with_user_errors(ARG_SPENDER, Cep85Error::InvalidSpender);
+            match spender {
+                Some(spender) => utils_make_dictionary_item_key(&owner, &(spender, id)),
+                None => utils_make_dictionary_item_key(&owner, &id),
+            }
+        }
         None => {
             let operator: Option<Key> =
                 get_optional_named_arg_with_user_errors(ARG_OPERATOR, Cep85Error::InvalidOperator);
@@ -288,6 +446,8 @@ pub extern "C" fn make_dictionary_item_key() {
 
 #[no_mangle]
 pub extern "C" fn set_approval_for_all() {
+    check_not_paused();
+
     let operator: Key = get_named_arg_with_user_errors(
         ARG_OPERATOR,
         Cep85Error::MissingOperator,
@@ -314,23 +474,126 @@ pub extern "C" fn set_approval_for_all() {
     )
     .unwrap_or_revert();
 
-    write_operator(&caller, &operator, approved);
+    let expires_at: Option<u64> =
+        get_optional_named_arg_with_user_errors(ARG_EXPIRES_AT, Cep85Error::InvalidExpiresAt);
+
+    if let Some(expires_at) = expires_at {
+        if expires_at <= u64::from(runtime::get_blocktime()) {
+            runtime::revert(Cep85Error::ExpiresAtInPast);
+        }
+    }
+
+    write_operator(&caller, &operator, approved, expires_at);
     record_event_dictionary(Event::ApprovalForAll(ApprovalForAll {
         owner: caller,
         operator,
         approved,
+        expires_at,
+    }));
+}
+
+/// Sets the amount of token `id` that `spender` may transfer on behalf of the caller.
+#[no_mangle]
+pub extern "C" fn approve() {
+    check_not_paused();
+
+    let (owner, spender, id, amount) = get_allowance_args();
+
+    write_allowance_to(&owner, &spender, &id, &amount);
+    record_event_dictionary(Event::SetAllowance(SetAllowance {
+        owner,
+        spender,
+        id,
+        allowance: amount,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    let owner: Key = get_named_arg_with_user_errors(
+        ARG_OWNER,
+        Cep85Error::MissingOwner,
+        Cep85Error::InvalidOwner,
+    )
+    .unwrap_or_revert();
+
+    let spender: Key = get_named_arg_with_user_errors(
+        ARG_SPENDER,
+        Cep85Error::MissingSpender,
+        Cep85Error::InvalidSpender,
+    )
+    .unwrap_or_revert();
+
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+
+    let allowance: U256 = read_allowance_of(&owner, &spender, &id);
+    runtime::ret(CLValue::from_t(allowance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn increase_allowance() {
+    check_not_paused();
+
+    let (owner, spender, id, amount) = get_allowance_args();
+
+    let new_allowance = read_allowance_of(&owner, &spender, &id)
+        .checked_add(amount)
+        .unwrap_or_revert_with(Cep85Error::Overflow);
+
+    write_allowance_to(&owner, &spender, &id, &new_allowance);
+    record_event_dictionary(Event::IncreaseAllowance(IncreaseAllowance {
+        owner,
+        spender,
+        id,
+        allowance: new_allowance,
+        inc_by: amount,
+    }));
+}
+
+/// Decreases the allowance of `spender` on token `id`, down to 0 at most.
+#[no_mangle]
+pub extern "C" fn decrease_allowance() {
+    check_not_paused();
+
+    let (owner, spender, id, amount) = get_allowance_args();
+
+    let new_allowance = read_allowance_of(&owner, &spender, &id).saturating_sub(amount);
+
+    write_allowance_to(&owner, &spender, &id, &new_allowance);
+    record_event_dictionary(Event::DecreaseAllowance(DecreaseAllowance {
+        owner,
+        spender,
+        id,
+        allowance: new_allowance,
+        decr_by: amount,
     }));
 }
 
 /// Transfer a specified amount of tokens from the `sender` to the `recipient`.
 ///
-/// This function should only be called by an approved operator or by the sender themselves.
+/// This function should only be called by an approved operator, by a spender with enough
+/// allowance or by the sender themselves.
 #[no_mangle]
 pub extern "C" fn transfer_from() {
+    check_not_paused();
+
     let from: Key =
         get_named_arg_with_user_errors(ARG_FROM, Cep85Error::MissingFrom, Cep85Error::InvalidFrom)
             .unwrap_or_revert();
 
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+
+    let amount: U256 = get_named_arg_with_user_errors(
+        ARG_AMOUNT,
+        Cep85Error::MissingAmount,
+        Cep85Error::InvalidAmount,
+    )
+    .unwrap_or_revert();
+
     let (caller, caller_package) = get_verified_caller();
 
     // Check if the caller is the spender or an operator
@@ -345,34 +608,26 @@ pub extern "C" fn transfer_from() {
     };
 
     if !is_approved {
-        runtime::revert(Cep85Error::NotApproved);
+        spend_allowance(&from, &caller, caller_package, &id, &amount);
     }
 
     let to: Key =
         get_named_arg_with_user_errors(ARG_TO, Cep85Error::MissingTo, Cep85Error::InvalidTo)
             .unwrap_or_revert();
 
-    let id: U256 =
-        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
-            .unwrap_or_revert();
-
     if read_total_supply_of(&id).unwrap_or_default().is_zero() {
         revert(Cep85Error::NonSuppliedTokenId);
     }
 
-    let amount: U256 = get_named_arg_with_user_errors(
-        ARG_AMOUNT,
-        Cep85Error::MissingAmount,
-        Cep85Error::InvalidAmount,
-    )
-    .unwrap_or_revert();
-
     let data: Option<Bytes> =
         get_optional_named_arg_with_user_errors(ARG_DATA, Cep85Error::InvalidData);
 
     before_token_transfer(&caller, &from, &to, &[id], &[amount], data.clone());
 
     transfer_balance(&from, &to, &id, &amount);
+    after_token_transfer(&caller, &from, &to, &[id], &[amount], data.clone());
+    do_safe_transfer_acceptance_check(&caller, Some(from), &to, &id, &amount, data.clone());
+
     record_event_dictionary(Event::Transfer(Transfer {
         operator: caller,
         from,
@@ -385,9 +640,12 @@ pub extern "C" fn transfer_from() {
 
 /// Batch transfer specified amounts of multiple tokens from the `sender` to the `recipient`.
 ///
-/// This function should only be called by an approved operator or by the sender themselves.
+/// This function should only be called by an approved operator, by a spender with enough
+/// allowance for every token or by the sender themselves.
 #[no_mangle]
 pub extern "C" fn batch_transfer_from() {
+    check_not_paused();
+
     let ids: Vec<U256> =
         get_named_arg_with_user_errors(ARG_IDS, Cep85Error::MissingIds, Cep85Error::InvalidIds)
             .unwrap_or_revert();
@@ -421,7 +679,9 @@ pub extern "C" fn batch_transfer_from() {
     };
 
     if !is_approved {
-        runtime::revert(Cep85Error::NotApproved);
+        for (id, amount) in ids.iter().zip(amounts.iter()) {
+            spend_allowance(&from, &caller, caller_package, id, amount);
+        }
     }
 
     for id in ids.iter() {
@@ -440,6 +700,8 @@ pub extern "C" fn batch_transfer_from() {
     before_token_transfer(&caller, &from, &to, &ids, &amounts, data.clone());
 
     batch_transfer_balance(&from, &to, &ids, &amounts);
+    after_token_transfer(&caller, &from, &to, &ids, &amounts, data.clone());
+    do_safe_batch_transfer_acceptance_check(&caller, Some(from), &to, &ids, &amounts, data.clone());
 
     record_event_dictionary(Event::TransferBatch(TransferBatch {
         operator: caller,
@@ -451,16 +713,23 @@ pub extern "C" fn batch_transfer_from() {
     }));
 }
 
+/// Moves tokens out of `from` without its approval, e.g. under a court order or after key loss.
+///
+/// This function can only be called by a controller. Operator, allowance, freeze and lock checks
+/// are bypassed, balances are still validated.
 #[no_mangle]
-pub extern "C" fn mint() {
-    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Minter]);
+pub extern "C" fn force_transfer() {
+    check_not_paused();
 
-    let recipient: Key = get_named_arg_with_user_errors(
-        ARG_RECIPIENT,
-        Cep85Error::MissingRecipient,
-        Cep85Error::InvalidRecipient,
-    )
-    .unwrap_or_revert();
+    sec_check(vec![SecurityBadge::Controller]);
+
+    let from: Key =
+        get_named_arg_with_user_errors(ARG_FROM, Cep85Error::MissingFrom, Cep85Error::InvalidFrom)
+            .unwrap_or_revert();
+
+    let to: Key =
+        get_named_arg_with_user_errors(ARG_TO, Cep85Error::MissingTo, Cep85Error::InvalidTo)
+            .unwrap_or_revert();
 
     let id: U256 =
         get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
@@ -473,55 +742,53 @@ pub extern "C" fn mint() {
     )
     .unwrap_or_revert();
 
-    let recipient_balance = read_balance_from(&recipient, &id);
-    let new_recipient_balance = recipient_balance.checked_add(amount).unwrap_or_default();
-
-    let supply = read_supply_of(&id);
-    let new_supply = supply
-        .checked_add(amount)
-        .unwrap_or_revert_with(Cep85Error::OverflowMint);
-    let total_max_supply = read_total_supply_of(&id).unwrap_or_default();
+    let reason: String = get_named_arg_with_user_errors(
+        ARG_REASON,
+        Cep85Error::MissingReason,
+        Cep85Error::InvalidReason,
+    )
+    .unwrap_or_revert();
 
-    if total_max_supply != U256::zero() {
-        if new_supply > total_max_supply {
-            revert(Cep85Error::ExceededMaxTotalSupply);
-        }
-    } else {
-        write_total_supply_of(&id, &new_supply);
+    if read_total_supply_of(&id).unwrap_or_default().is_zero() {
+        revert(Cep85Error::NonSuppliedTokenId);
     }
 
-    write_supply_of(&id, &new_supply);
-    write_balance_to(&recipient, &id, &new_recipient_balance);
+    check_not_frozen(&to);
 
-    let uri: String = get_optional_named_arg_with_user_errors(ARG_URI, Cep85Error::InvalidUri)
-        .unwrap_or_default();
+    let data: Option<Bytes> =
+        get_optional_named_arg_with_user_errors(ARG_DATA, Cep85Error::InvalidData);
 
-    record_event_dictionary(Event::Mint(Mint {
-        id,
-        recipient,
-        amount,
-    }));
+    let (caller, _) = get_verified_caller();
 
-    if !uri.is_empty() {
-        write_uri_of(&id, &uri);
-        record_event_dictionary(Event::Uri(Uri {
-            id: Some(id),
-            value: uri,
-        }));
-    };
+    transfer_balance(&from, &to, &id, &amount);
+    after_token_transfer(&caller, &from, &to, &[id], &[amount], data.clone());
+    do_safe_transfer_acceptance_check(&caller, Some(from), &to, &id, &amount, data.clone());
+
+    record_event_dictionary(Event::ForcedTransfer(ForcedTransfer {
+        operator: caller,
+        from,
+        to,
+        ids: vec![id],
+        values: vec![amount],
+        reason,
+        data,
+    }));
 }
 
-/// Batch mint specified amounts of multiple tokens to one `recipient`.
+/// Batch version of `force_transfer`.
 #[no_mangle]
-pub extern "C" fn batch_mint() {
-    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Minter]);
+pub extern "C" fn force_batch_transfer() {
+    check_not_paused();
 
-    let recipient: Key = get_named_arg_with_user_errors(
-        ARG_RECIPIENT,
-        Cep85Error::MissingRecipient,
-        Cep85Error::InvalidRecipient,
-    )
-    .unwrap_or_revert();
+    sec_check(vec![SecurityBadge::Controller]);
+
+    let from: Key =
+        get_named_arg_with_user_errors(ARG_FROM, Cep85Error::MissingFrom, Cep85Error::InvalidFrom)
+            .unwrap_or_revert();
+
+    let to: Key =
+        get_named_arg_with_user_errors(ARG_TO, Cep85Error::MissingTo, Cep85Error::InvalidTo)
+            .unwrap_or_revert();
 
     let ids: Vec<U256> =
         get_named_arg_with_user_errors(ARG_IDS, Cep85Error::MissingIds, Cep85Error::InvalidIds)
@@ -535,39 +802,315 @@ pub extern "C" fn batch_mint() {
     .unwrap_or_revert();
 
     if ids.len() != amounts.len() {
-        revert(Cep85Error::MismatchParamsLength);
+        runtime::revert(Cep85Error::MismatchParamsLength);
     }
 
-    let uri: String = get_optional_named_arg_with_user_errors(ARG_URI, Cep85Error::InvalidUri)
-        .unwrap_or_default();
+    let reason: String = get_named_arg_with_user_errors(
+        ARG_REASON,
+        Cep85Error::MissingReason,
+        Cep85Error::InvalidReason,
+    )
+    .unwrap_or_revert();
 
-    for (i, &id) in ids.iter().enumerate() {
-        let amount = amounts[i];
+    for id in ids.iter() {
+        if read_total_supply_of(id).unwrap_or_default().is_zero() {
+            revert(Cep85Error::NonSuppliedTokenId);
+        }
+    }
 
-        let recipient_balance = read_balance_from(&recipient, &id);
-        let new_recipient_balance = recipient_balance.checked_add(amount).unwrap_or_default();
+    check_not_frozen(&to);
 
-        let supply = read_supply_of(&id);
-        let total_max_supply = read_total_supply_of(&id).unwrap_or_default();
-        let new_supply = supply
-            .checked_add(amount)
-            .unwrap_or_revert_with(Cep85Error::OverflowBatchMint);
+    let data: Option<Bytes> =
+        get_optional_named_arg_with_user_errors(ARG_DATA, Cep85Error::InvalidData);
 
-        if total_max_supply != U256::zero() {
-            if new_supply > total_max_supply {
-                revert(Cep85Error::ExceededMaxTotalSupply);
-            }
-        } else {
-            write_total_supply_of(&id, &new_supply);
-        }
+    let (caller, _) = get_verified_caller();
 
-        write_supply_of(&id, &new_supply);
-        write_balance_to(&recipient, &id, &new_recipient_balance);
+    batch_transfer_balance(&from, &to, &ids, &amounts);
+    after_token_transfer(&caller, &from, &to, &ids, &amounts, data.clone());
+    do_safe_batch_transfer_acceptance_check(&caller, Some(from), &to, &ids, &amounts, data.clone());
+
+    record_event_dictionary(Event::ForcedTransfer(ForcedTransfer {
+        operator: caller,
+        from,
+        to,
+        ids,
+        values: amounts,
+        reason,
+        data,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn mint() {
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+
+    sec_check_ids(vec![SecurityBadge::Admin, SecurityBadge::Minter], &[id]);
+
+    check_not_paused();
+    create_type_item(&id);
+    check_registered_id(&id);
+
+    let recipient: Key = get_named_arg_with_user_errors(
+        ARG_RECIPIENT,
+        Cep85Error::MissingRecipient,
+        Cep85Error::InvalidRecipient,
+    )
+    .unwrap_or_revert();
+
+    let amount: U256 = get_named_arg_with_user_errors(
+        ARG_AMOUNT,
+        Cep85Error::MissingAmount,
+        Cep85Error::InvalidAmount,
+    )
+    .unwrap_or_revert();
+
+    let (caller, _) = get_verified_caller();
+    check_mint_burn_filters(&caller, &NULL_KEY, &recipient, &[id], &[amount]);
+
+    let recipient_balance = read_balance_from(&recipient, &id);
+    let new_recipient_balance = recipient_balance.checked_add(amount).unwrap_or_default();
+
+    let supply = read_supply_of(&id);
+    let new_supply = supply
+        .checked_add(amount)
+        .unwrap_or_revert_with(Cep85Error::OverflowMint);
+    let total_max_supply = read_total_supply_of(&id).unwrap_or_default();
+
+    if total_max_supply != U256::zero() {
+        if new_supply > total_max_supply {
+            revert(Cep85Error::ExceededMaxTotalSupply);
+        }
+    } else {
+        write_total_supply_of(&id, &new_supply);
+    }
+
+    spend_caller_mint_quota(&id, &amount);
+    write_supply_of(&id, &new_supply);
+    write_balance_to(&recipient, &id, &new_recipient_balance);
+
+    after_token_transfer(&caller, &NULL_KEY, &recipient, &[id], &[amount], None);
+    do_safe_transfer_acceptance_check(&caller, None, &recipient, &id, &amount, None);
+
+    let uri: String = get_optional_named_arg_with_user_errors(ARG_URI, Cep85Error::InvalidUri)
+        .unwrap_or_default();
+
+    record_event_dictionary(Event::Mint(Mint {
+        id,
+        recipient,
+        amount,
+    }));
+
+    if !uri.is_empty() {
+        write_uri_of(&id, &uri);
+        record_event_dictionary(Event::Uri(Uri {
+            id: Some(id),
+            value: uri,
+        }));
+    };
+
+    if let Some(metadata) = get_optional_metadata_args() {
+        set_metadata(id, metadata);
+    }
+}
+
+/// Batch mint specified amounts of multiple tokens to one `recipient`.
+#[no_mangle]
+pub extern "C" fn batch_mint() {
+    let ids: Vec<U256> =
+        get_named_arg_with_user_errors(ARG_IDS, Cep85Error::MissingIds, Cep85Error::InvalidIds)
+            .unwrap_or_revert();
+
+    sec_check_ids(vec![SecurityBadge::Admin, SecurityBadge::Minter], &ids);
+
+    check_not_paused();
+
+    let recipient: Key = get_named_arg_with_user_errors(
+        ARG_RECIPIENT,
+        Cep85Error::MissingRecipient,
+        Cep85Error::InvalidRecipient,
+    )
+    .unwrap_or_revert();
+
+    let amounts: Vec<U256> = get_named_arg_with_user_errors(
+        ARG_AMOUNTS,
+        Cep85Error::MissingAmounts,
+        Cep85Error::InvalidAmounts,
+    )
+    .unwrap_or_revert();
+
+    if ids.len() != amounts.len() {
+        revert(Cep85Error::MismatchParamsLength);
+    }
+
+    let (caller, _) = get_verified_caller();
+    check_mint_burn_filters(&caller, &NULL_KEY, &recipient, &ids, &amounts);
+
+    let uri: String = get_optional_named_arg_with_user_errors(ARG_URI, Cep85Error::InvalidUri)
+        .unwrap_or_default();
+
+    for (i, &id) in ids.iter().enumerate() {
+        create_type_item(&id);
+        check_registered_id(&id);
+        let amount = amounts[i];
+
+        let recipient_balance = read_balance_from(&recipient, &id);
+        let new_recipient_balance = recipient_balance.checked_add(amount).unwrap_or_default();
+
+        let supply = read_supply_of(&id);
+        let total_max_supply = read_total_supply_of(&id).unwrap_or_default();
+        let new_supply = supply
+            .checked_add(amount)
+            .unwrap_or_revert_with(Cep85Error::OverflowBatchMint);
+
+        if total_max_supply != U256::zero() {
+            if new_supply > total_max_supply {
+                revert(Cep85Error::ExceededMaxTotalSupply);
+            }
+        } else {
+            write_total_supply_of(&id, &new_supply);
+        }
+
+        spend_caller_mint_quota(&id, &amount);
+        write_supply_of(&id, &new_supply);
+        write_balance_to(&recipient, &id, &new_recipient_balance);
         if !uri.is_empty() {
             write_uri_of(&id, &uri);
         }
     }
 
+    after_token_transfer(&caller, &NULL_KEY, &recipient, &ids, &amounts, None);
+    do_safe_batch_transfer_acceptance_check(&caller, None, &recipient, &ids, &amounts, None);
+
+    record_event_dictionary(Event::MintBatch(MintBatch {
+        ids: ids.clone(),
+        recipient,
+        amounts,
+    }));
+
+    if !uri.is_empty() {
+        record_event_dictionary(Event::UriBatch(UriBatch {
+            value: uri,
+            ids: ids.clone(),
+        }));
+    }
+
+    set_optional_batch_metadata(&ids);
+}
+
+/// Admin EntryPoint to set the inclusive id range `mint_next` and `batch_mint_next` allocate ids
+/// from for a `namespace`, restarting its counter at the start of the range. The range is
+/// unbounded when `end_id` is omitted.
+#[no_mangle]
+pub extern "C" fn set_id_namespace() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    check_not_paused();
+
+    let namespace: String = get_named_arg_with_user_errors(
+        ARG_NAMESPACE,
+        Cep85Error::MissingNamespace,
+        Cep85Error::InvalidNamespace,
+    )
+    .unwrap_or_revert();
+    let start_id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+    let end_id: U256 =
+        get_optional_named_arg_with_user_errors(ARG_END_ID, Cep85Error::InvalidEndId)
+            .unwrap_or(U256::MAX);
+
+    if end_id < start_id {
+        revert(Cep85Error::InvalidIdRange);
+    }
+
+    write_id_namespace(&namespace, &start_id, &end_id, &start_id);
+    record_event_dictionary(Event::SetIdNamespace(SetIdNamespace {
+        namespace,
+        start_id,
+        end_id,
+    }));
+}
+
+/// Mints one non-fungible token with the next free id, taken from the counter of the optional
+/// `namespace` or from the collection counter, and returns that id.
+#[no_mangle]
+pub extern "C" fn mint_next() {
+    let recipient: Key = get_named_arg_with_user_errors(
+        ARG_RECIPIENT,
+        Cep85Error::MissingRecipient,
+        Cep85Error::InvalidRecipient,
+    )
+    .unwrap_or_revert();
+
+    let namespace: Option<String> =
+        get_optional_named_arg_with_user_errors(ARG_NAMESPACE, Cep85Error::InvalidNamespace);
+
+    let ids = allocate_next_ids(namespace.as_deref(), 1);
+    let caller = mint_allocated_ids(&recipient, &ids);
+    let id = ids[0];
+    let amount = U256::one();
+
+    do_safe_transfer_acceptance_check(&caller, None, &recipient, &id, &amount, None);
+
+    let uri: String = get_optional_named_arg_with_user_errors(ARG_URI, Cep85Error::InvalidUri)
+        .unwrap_or_default();
+
+    record_event_dictionary(Event::Mint(Mint {
+        id,
+        recipient,
+        amount,
+    }));
+
+    if !uri.is_empty() {
+        write_uri_of(&id, &uri);
+        record_event_dictionary(Event::Uri(Uri {
+            id: Some(id),
+            value: uri,
+        }));
+    }
+
+    if let Some(metadata) = get_optional_metadata_args() {
+        set_metadata(id, metadata);
+    }
+
+    runtime::ret(CLValue::from_t(id).unwrap_or_revert());
+}
+
+/// Batch version of `mint_next`, minting `count` non-fungible tokens with consecutive free ids
+/// and returning them.
+#[no_mangle]
+pub extern "C" fn batch_mint_next() {
+    let recipient: Key = get_named_arg_with_user_errors(
+        ARG_RECIPIENT,
+        Cep85Error::MissingRecipient,
+        Cep85Error::InvalidRecipient,
+    )
+    .unwrap_or_revert();
+
+    let count: u32 = get_named_arg_with_user_errors(
+        ARG_COUNT,
+        Cep85Error::MissingCount,
+        Cep85Error::InvalidCount,
+    )
+    .unwrap_or_revert();
+    if count == 0 {
+        revert(Cep85Error::InvalidCount);
+    }
+
+    let namespace: Option<String> =
+        get_optional_named_arg_with_user_errors(ARG_NAMESPACE, Cep85Error::InvalidNamespace);
+
+    let ids = allocate_next_ids(namespace.as_deref(), count);
+    let caller = mint_allocated_ids(&recipient, &ids);
+    let amounts = vec![U256::one(); ids.len()];
+
+    do_safe_batch_transfer_acceptance_check(&caller, None, &recipient, &ids, &amounts, None);
+
+    let uri: String = get_optional_named_arg_with_user_errors(ARG_URI, Cep85Error::InvalidUri)
+        .unwrap_or_default();
+
     record_event_dictionary(Event::MintBatch(MintBatch {
         ids: ids.clone(),
         recipient,
@@ -575,12 +1118,24 @@ pub extern "C" fn batch_mint() {
     }));
 
     if !uri.is_empty() {
-        record_event_dictionary(Event::UriBatch(UriBatch { value: uri, ids }));
+        for id in ids.iter() {
+            write_uri_of(id, &uri);
+        }
+        record_event_dictionary(Event::UriBatch(UriBatch {
+            value: uri,
+            ids: ids.clone(),
+        }));
     }
+
+    set_optional_batch_metadata(&ids);
+
+    runtime::ret(CLValue::from_t(ids).unwrap_or_revert());
 }
 
 #[no_mangle]
 pub extern "C" fn burn() {
+    check_not_paused();
+
     if !get_stored_value_with_user_errors::<bool>(
         ARG_ENABLE_BURN,
         Cep85Error::MissingEnableMBFlag,
@@ -589,7 +1144,9 @@ pub extern "C" fn burn() {
         revert(Cep85Error::BurnDisabled);
     };
 
-    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Burner]);
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
 
     let owner: Key = get_named_arg_with_user_errors(
         ARG_OWNER,
@@ -598,31 +1155,19 @@ pub extern "C" fn burn() {
     )
     .unwrap_or_revert();
 
-    let (caller, caller_package) = get_verified_caller();
-
-    // Check if the caller is the owner or operator
-    let is_approved: bool = match caller_package {
-        Some(caller_package) => {
-            owner == caller_package
-                || owner == caller
-                || read_operator(&owner, &caller_package)
-                || read_operator(&owner, &caller)
-        }
-        None => owner == caller || read_operator(&owner, &caller),
-    };
-
-    if !is_approved {
-        revert(Cep85Error::InvalidBurnTarget);
-    }
+    let caller = check_burn_rights(&owner, &[id]);
 
-    let id: U256 =
-        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
-            .unwrap_or_revert();
+    check_not_frozen(&caller);
+    check_not_frozen(&owner);
 
     if read_total_supply_of(&id).unwrap_or_default().is_zero() {
         revert(Cep85Error::NonSuppliedTokenId);
     }
 
+    if read_id_lock(&id) {
+        revert(Cep85Error::TokenIdLocked);
+    }
+
     let amount: U256 = get_named_arg_with_user_errors(
         ARG_AMOUNT,
         Cep85Error::MissingAmount,
@@ -630,6 +1175,8 @@ pub extern "C" fn burn() {
     )
     .unwrap_or_revert();
 
+    check_mint_burn_filters(&caller, &owner, &NULL_KEY, &[id], &[amount]);
+
     let owner_balance = read_balance_from(&owner, &id);
     let new_owner_balance = owner_balance
         .checked_sub(amount)
@@ -644,11 +1191,14 @@ pub extern "C" fn burn() {
 
     write_supply_of(&id, &new_supply);
     write_balance_to(&owner, &id, &new_owner_balance);
+    after_token_transfer(&caller, &owner, &NULL_KEY, &[id], &[amount], None);
     record_event_dictionary(Event::Burn(Burn { id, owner, amount }));
 }
 
 #[no_mangle]
 pub extern "C" fn batch_burn() {
+    check_not_paused();
+
     if !get_stored_value_with_user_errors::<bool>(
         ARG_ENABLE_BURN,
         Cep85Error::MissingEnableMBFlag,
@@ -657,7 +1207,9 @@ pub extern "C" fn batch_burn() {
         revert(Cep85Error::BurnDisabled);
     };
 
-    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Burner]);
+    let ids: Vec<U256> =
+        get_named_arg_with_user_errors(ARG_IDS, Cep85Error::MissingIds, Cep85Error::InvalidIds)
+            .unwrap_or_revert();
 
     let owner: Key = get_named_arg_with_user_errors(
         ARG_OWNER,
@@ -666,26 +1218,10 @@ pub extern "C" fn batch_burn() {
     )
     .unwrap_or_revert();
 
-    let (caller, caller_package) = get_verified_caller();
-
-    // Check if the caller is the owner or operator
-    let is_approved: bool = match caller_package {
-        Some(caller_package) => {
-            owner == caller_package
-                || owner == caller
-                || read_operator(&owner, &caller_package)
-                || read_operator(&owner, &caller)
-        }
-        None => owner == caller || read_operator(&owner, &caller),
-    };
-
-    if !is_approved {
-        revert(Cep85Error::InvalidBurnTarget);
-    }
+    let caller = check_burn_rights(&owner, &ids);
 
-    let ids: Vec<U256> =
-        get_named_arg_with_user_errors(ARG_IDS, Cep85Error::MissingIds, Cep85Error::InvalidIds)
-            .unwrap_or_revert();
+    check_not_frozen(&caller);
+    check_not_frozen(&owner);
 
     let amounts: Vec<U256> = get_named_arg_with_user_errors(
         ARG_AMOUNTS,
@@ -698,10 +1234,15 @@ pub extern "C" fn batch_burn() {
         revert(Cep85Error::MismatchParamsLength);
     }
 
+    check_mint_burn_filters(&caller, &owner, &NULL_KEY, &ids, &amounts);
+
     for (i, &id) in ids.iter().enumerate() {
         if read_total_supply_of(&id).unwrap_or_default().is_zero() {
             revert(Cep85Error::NonSuppliedTokenId);
         }
+        if read_id_lock(&id) {
+            revert(Cep85Error::TokenIdLocked);
+        }
         let amount = amounts[i];
         let owner_balance = read_balance_from(&owner, &id);
         let new_owner_balance = owner_balance
@@ -718,6 +1259,7 @@ pub extern "C" fn batch_burn() {
         write_supply_of(&id, &new_supply);
         write_balance_to(&owner, &id, &new_owner_balance);
     }
+    after_token_transfer(&caller, &owner, &NULL_KEY, &ids, &amounts, None);
     record_event_dictionary(Event::BurnBatch(BurnBatch {
         ids,
         owner,
@@ -752,7 +1294,9 @@ pub extern "C" fn total_supply_of() {
 
 #[no_mangle]
 pub extern "C" fn set_total_supply_of() {
-    sec_check(vec![SecurityBadge::Admin]);
+    sec_check_timelocked_action();
+
+    check_not_paused();
 
     let id: U256 =
         get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
@@ -770,6 +1314,7 @@ pub extern "C" fn set_total_supply_of() {
     if total_supply < current_supply {
         runtime::revert(Cep85Error::InvalidTotalSupply);
     }
+    check_token_kind_total_supply(read_token_kind(&id), &total_supply);
 
     write_total_supply_of(&id, &total_supply);
     record_event_dictionary(Event::SetTotalSupply(SetTotalSupply { id, total_supply }));
@@ -816,7 +1361,9 @@ pub extern "C" fn total_supply_of_batch() {
 
 #[no_mangle]
 pub extern "C" fn set_total_supply_of_batch() {
-    sec_check(vec![SecurityBadge::Admin]);
+    sec_check_timelocked_action();
+
+    check_not_paused();
 
     let ids: Vec<U256> =
         get_named_arg_with_user_errors(ARG_IDS, Cep85Error::MissingIds, Cep85Error::InvalidIds)
@@ -839,16 +1386,156 @@ pub extern "C" fn set_total_supply_of_batch() {
         if total_supply < current_supply {
             runtime::revert(Cep85Error::InvalidTotalSupply);
         }
+        check_token_kind_total_supply(read_token_kind(&id), &total_supply);
 
         write_total_supply_of(&id, &total_supply);
         record_event_dictionary(Event::SetTotalSupply(SetTotalSupply { id, total_supply }));
     }
 }
 
+/// Admin EntryPoint to register a token id with an explicit kind and total supply, and
+/// optionally its uri and metadata, before any of it is minted.
 #[no_mangle]
-pub extern "C" fn uri() {
-    let id: Option<U256> = get_optional_named_arg_with_user_errors(ARG_ID, Cep85Error::InvalidId);
-    if let Some(id) = id {
+pub extern "C" fn create_token() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    check_not_paused();
+
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+
+    let token_kind: u8 = get_named_arg_with_user_errors(
+        ARG_TOKEN_KIND,
+        Cep85Error::MissingTokenKind,
+        Cep85Error::InvalidTokenKind,
+    )
+    .unwrap_or_revert();
+
+    let total_supply: U256 = get_named_arg_with_user_errors(
+        ARG_TOTAL_SUPPLY,
+        Cep85Error::MissingTotalSupply,
+        Cep85Error::InvalidTotalSupply,
+    )
+    .unwrap_or_revert();
+
+    let uri: String = get_optional_named_arg_with_user_errors(ARG_URI, Cep85Error::InvalidUri)
+        .unwrap_or_default();
+
+    register_token(&id, token_kind, &total_supply);
+
+    record_event_dictionary(Event::CreateToken(CreateToken {
+        id,
+        token_kind,
+        total_supply,
+    }));
+
+    if !uri.is_empty() {
+        write_uri_of(&id, &uri);
+        record_event_dictionary(Event::Uri(Uri {
+            id: Some(id),
+            value: uri,
+        }));
+    }
+
+    if let Some(metadata) = get_optional_metadata_args() {
+        set_metadata(id, metadata);
+    }
+}
+
+/// Batch version of `create_token`, the optional uri being shared by all the ids.
+#[no_mangle]
+pub extern "C" fn create_token_batch() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    check_not_paused();
+
+    let ids: Vec<U256> =
+        get_named_arg_with_user_errors(ARG_IDS, Cep85Error::MissingIds, Cep85Error::InvalidIds)
+            .unwrap_or_revert();
+
+    let token_kinds: Vec<u8> = get_named_arg_with_user_errors(
+        ARG_TOKEN_KINDS,
+        Cep85Error::MissingTokenKinds,
+        Cep85Error::InvalidTokenKinds,
+    )
+    .unwrap_or_revert();
+
+    let total_supplies: Vec<U256> = get_named_arg_with_user_errors(
+        ARG_TOTAL_SUPPLIES,
+        Cep85Error::MissingTotalSupplies,
+        Cep85Error::InvalidTotalSupplies,
+    )
+    .unwrap_or_revert();
+
+    if ids.len() != token_kinds.len() || ids.len() != total_supplies.len() {
+        revert(Cep85Error::MismatchParamsLength);
+    }
+
+    let uri: String = get_optional_named_arg_with_user_errors(ARG_URI, Cep85Error::InvalidUri)
+        .unwrap_or_default();
+
+    for (i, id) in ids.iter().enumerate() {
+        register_token(id, token_kinds[i], &total_supplies[i]);
+        if !uri.is_empty() {
+            write_uri_of(id, &uri);
+        }
+    }
+
+    record_event_dictionary(Event::CreateTokenBatch(CreateTokenBatch {
+        ids: ids.clone(),
+        token_kinds,
+        total_supplies,
+    }));
+
+    if !uri.is_empty() {
+        record_event_dictionary(Event::UriBatch(UriBatch {
+            value: uri,
+            ids: ids.clone(),
+        }));
+    }
+
+    set_optional_batch_metadata(&ids);
+}
+
+/// Admin EntryPoint to create a split id type, the base `id` carrying the type in its high 128
+/// bits and zero in its low 128 bits. Ids of the type with a non-zero index are then minted as
+/// non-fungible items, and resolve their uri from the optional type uri.
+#[no_mangle]
+pub extern "C" fn create_type() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    check_not_paused();
+
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+
+    if id.is_zero() || type_id_of(&id) != id {
+        revert(Cep85Error::InvalidTypeId);
+    }
+    if read_token_type(&id).is_some() {
+        revert(Cep85Error::TypeAlreadyCreated);
+    }
+
+    let uri: String = get_optional_named_arg_with_user_errors(ARG_URI, Cep85Error::InvalidUri)
+        .unwrap_or_default();
+
+    write_token_type(&id, &uri);
+    record_event_dictionary(Event::CreateType(CreateType { id }));
+
+    if !uri.is_empty() {
+        record_event_dictionary(Event::Uri(Uri {
+            id: Some(id),
+            value: uri,
+        }));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn uri() {
+    let id: Option<U256> = get_optional_named_arg_with_user_errors(ARG_ID, Cep85Error::InvalidId);
+    if let Some(id) = id {
         let total_supply = read_total_supply_of(&id).unwrap_or_default();
         if total_supply.is_zero() {
             runtime::ret(CLValue::from_t::<Option<String>>(None).unwrap_or_revert());
@@ -860,10 +1547,17 @@ pub extern "C" fn uri() {
 
 #[no_mangle]
 pub extern "C" fn set_uri() {
-    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Meta]);
-
     let id: Option<U256> = get_optional_named_arg_with_user_errors(ARG_ID, Cep85Error::InvalidId);
 
+    match id {
+        Some(id) => {
+            sec_check_admin_action(vec![SecurityBadge::Admin, SecurityBadge::Meta], Some(&[id]))
+        }
+        None => sec_check_admin_action(vec![SecurityBadge::Admin, SecurityBadge::Meta], None),
+    }
+
+    check_not_paused();
+
     let uri: String =
         get_named_arg_with_user_errors(ARG_URI, Cep85Error::MissingUri, Cep85Error::InvalidUri)
             .unwrap_or_revert();
@@ -895,6 +1589,57 @@ pub extern "C" fn set_uri() {
     };
 }
 
+/// EntryPoint to set the name, symbol and decimals of a supplied token id, callable by admins
+/// and meta accounts.
+#[no_mangle]
+pub extern "C" fn set_metadata_of() {
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+
+    sec_check_admin_action(vec![SecurityBadge::Admin, SecurityBadge::Meta], Some(&[id]));
+
+    check_not_paused();
+
+    if read_total_supply_of(&id).unwrap_or_default().is_zero() {
+        revert(Cep85Error::NonSuppliedTokenId)
+    }
+
+    let name: String = get_named_arg_with_user_errors(
+        ARG_NAME,
+        Cep85Error::MissingTokenName,
+        Cep85Error::InvalidTokenName,
+    )
+    .unwrap_or_revert();
+
+    set_metadata(id, get_metadata_args(name));
+}
+
+/// Returns the name, symbol and decimals of a token id, or `None` when they were never set.
+#[no_mangle]
+pub extern "C" fn metadata_of() {
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+    let metadata: Option<TokenMetadata> = read_metadata_of(&id);
+    runtime::ret(CLValue::from_t(metadata).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn metadata_of_batch() {
+    let ids: Vec<U256> =
+        get_named_arg_with_user_errors(ARG_IDS, Cep85Error::MissingIds, Cep85Error::InvalidIds)
+            .unwrap_or_revert();
+
+    let mut batch_metadata: Vec<Option<TokenMetadata>> = Vec::new();
+
+    for id in ids {
+        batch_metadata.push(read_metadata_of(&id));
+    }
+
+    runtime::ret(CLValue::from_t(batch_metadata).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn is_non_fungible() {
     let id: U256 =
@@ -904,7 +1649,11 @@ pub extern "C" fn is_non_fungible() {
     if total_supply.is_zero() {
         runtime::ret(CLValue::from_t::<Option<bool>>(None).unwrap_or_revert());
     }
-    let is_non_fungible: bool = U256::from(1_u32) == total_supply;
+    // Ids created with an explicit kind do not rely on their total supply.
+    let is_non_fungible: bool = match read_token_kind(&id) {
+        Some(token_kind) => token_kind == TokenKind::NonFungible,
+        None => U256::from(1_u32) == total_supply,
+    };
     runtime::ret(CLValue::from_t(Some(is_non_fungible)).unwrap_or_revert());
 }
 
@@ -933,14 +1682,14 @@ pub extern "C" fn total_fungible_supply() {
 }
 
 /// Admin EntryPoint to manipulate the security access granted to users.
-/// One user can only possess one access group badge.
-/// Change strength: None > Admin > Minter
-/// Change strength meaning by example: If user is added to both Minter and Admin they will be an
-/// Admin, also if a user is added to Admin and None then they will be removed from having rights.
-/// Beware: do not remove the last Admin because that will lock out all admin functionality.
+/// One user can hold several badges: each list grants its badge on top of the user's current
+/// ones, or removes it when the `revoke` argument is `true`.
+/// The `none_list` takes precedence and removes every badge of the listed users.
+/// Any change that would leave the contract without an Admin reverts with `LastAdmin`.
+/// The caller's own badge is never changed here, use `propose_admin` or `renounce_role` instead.
 #[no_mangle]
 pub extern "C" fn change_security() {
-    sec_check(vec![SecurityBadge::Admin]);
+    sec_check_timelocked_action();
 
     let admin_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep85Error::InvalidAdminList);
@@ -948,10 +1697,20 @@ pub extern "C" fn change_security() {
         get_optional_named_arg_with_user_errors(MINTER_LIST, Cep85Error::InvalidMinterList);
     let meta_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(META_LIST, Cep85Error::InvalidMetaList);
+    let pauser_list: Option<Vec<Key>> =
+        get_optional_named_arg_with_user_errors(PAUSER_LIST, Cep85Error::InvalidPauserList);
+    let controller_list: Option<Vec<Key>> =
+        get_optional_named_arg_with_user_errors(CONTROLLER_LIST, Cep85Error::InvalidControllerList);
     let none_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(NONE_LIST, Cep85Error::InvalidNoneList);
+    let revoke: bool =
+        get_optional_named_arg_with_user_errors(ARG_REVOKE, Cep85Error::InvalidRevokeFlag)
+            .unwrap_or_default();
+
+    // Listed badges are granted on top of the existing ones, or removed when `revoke` is set.
+    let apply_badge = if revoke { revoke_badge } else { grant_badge };
 
-    let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
+    let mut roles_map: BTreeMap<Key, SecurityRoles> = BTreeMap::new();
     if get_stored_value_with_user_errors::<bool>(
         ARG_ENABLE_BURN,
         Cep85Error::MissingEnableMBFlag,
@@ -960,152 +1719,1266 @@ pub extern "C" fn change_security() {
         let burner_list: Option<Vec<Key>> =
             get_optional_named_arg_with_user_errors(BURNER_LIST, Cep85Error::InvalidBurnerList);
         if let Some(burner_list) = burner_list {
-            for account_key in burner_list {
-                badge_map.insert(account_key, SecurityBadge::Burner);
-            }
+            apply_badge(&mut roles_map, burner_list, SecurityBadge::Burner);
         }
     };
 
     if let Some(minter_list) = minter_list {
-        for account_key in minter_list {
-            badge_map.insert(account_key, SecurityBadge::Minter);
-        }
+        apply_badge(&mut roles_map, minter_list, SecurityBadge::Minter);
     }
 
     if let Some(meta_list) = meta_list {
-        for account_key in meta_list {
-            badge_map.insert(account_key, SecurityBadge::Meta);
-        }
+        apply_badge(&mut roles_map, meta_list, SecurityBadge::Meta);
+    }
+    if let Some(pauser_list) = pauser_list {
+        apply_badge(&mut roles_map, pauser_list, SecurityBadge::Pauser);
+    }
+    if let Some(controller_list) = controller_list {
+        apply_badge(&mut roles_map, controller_list, SecurityBadge::Controller);
     }
     if let Some(admin_list) = admin_list {
-        for account_key in admin_list {
-            badge_map.insert(account_key, SecurityBadge::Admin);
-        }
+        apply_badge(&mut roles_map, admin_list, SecurityBadge::Admin);
     }
     if let Some(none_list) = none_list {
-        for account_key in none_list {
-            badge_map.insert(account_key, SecurityBadge::None);
-        }
+        grant_badge(&mut roles_map, none_list, SecurityBadge::None);
     }
 
     let (caller, _) = get_verified_caller();
-    badge_map.remove(&caller);
+    roles_map.remove(&caller);
 
-    change_sec_badge(&badge_map);
-    record_event_dictionary(Event::ChangeSecurity(ChangeSecurity {
+    change_sec_badge(&roles_map);
+    record_event_dictionary(Event::ChangeSecurityRoles(ChangeSecurityRoles {
         admin: caller,
-        sec_change_map: badge_map,
+        sec_change_map: roles_map,
     }));
 }
 
-// set_modalities allows the user to set any variable or any combination of variables
-// simultaneously.
+/// Proposes `account` as the new admin replacing the caller, who stays admin until the
+/// candidate calls `accept_admin`. As the number of admins is unchanged, it does not need a
+/// proposal when the admin threshold is above one.
 #[no_mangle]
-pub extern "C" fn set_modalities() {
-    // Only the installing account can change the mutable variables.
+pub extern "C" fn propose_admin() {
     sec_check(vec![SecurityBadge::Admin]);
 
-    if let Some(enable_burn) = get_optional_named_arg_with_user_errors::<bool>(
-        ARG_ENABLE_BURN,
-        Cep85Error::InvalidEventsMode,
-    ) {
-        runtime::put_key(ARG_ENABLE_BURN, storage::new_uref(enable_burn).into());
+    let candidate: Key = get_named_arg_with_user_errors(
+        ARG_ACCOUNT,
+        Cep85Error::MissingAccount,
+        Cep85Error::InvalidAccount,
+    )
+    .unwrap_or_revert();
+
+    let (caller, _) = get_verified_caller();
+    if candidate == caller {
+        revert(Cep85Error::SelfAdminProposal);
     }
 
-    if let Some(optional_events_mode) = get_optional_named_arg_with_user_errors::<u8>(
-        ARG_EVENTS_MODE,
-        Cep85Error::InvalidEventsMode,
-    ) {
-        let old_events_mode: EventsMode = get_stored_value_with_user_errors::<u8>(
-            ARG_EVENTS_MODE,
-            Cep85Error::MissingEventsMode,
-            Cep85Error::InvalidEventsMode,
-        )
-        .try_into()
-        .unwrap_or_revert();
+    write_admin_proposal(&candidate, Some(caller));
+    record_event_dictionary(Event::ProposeAdmin(ProposeAdmin {
+        proposer: caller,
+        candidate,
+    }));
+}
 
-        runtime::put_key(
-            ARG_EVENTS_MODE,
-            storage::new_uref(optional_events_mode).into(),
-        );
+/// Completes a handover started with `propose_admin`: the caller becomes admin and the proposer
+/// loses its admin badge.
+#[no_mangle]
+pub extern "C" fn accept_admin() {
+    let (caller, _) = get_verified_caller();
 
-        let new_events_mode: EventsMode = optional_events_mode
-            .try_into()
-            .unwrap_or_revert_with(Cep85Error::InvalidEventsMode);
+    let proposer =
+        read_admin_proposal(&caller).unwrap_or_revert_with(Cep85Error::NoPendingAdminProposal);
 
-        // Check if current_events_mode and requested_events_mode are both CES
-        if old_events_mode != EventsMode::CES && new_events_mode == EventsMode::CES {
-            // Initialize events structures
-            init_events();
-        }
+    // A proposal made by an account that has since lost its admin badge is void.
+    if !read_security_roles(&proposer)
+        .unwrap_or_default()
+        .contains(SecurityBadge::Admin)
+    {
+        revert(Cep85Error::NoPendingAdminProposal);
     }
 
-    record_event_dictionary(Event::SetModalities(SetModalities {}));
+    write_admin_proposal(&caller, None);
+
+    let mut roles_map: BTreeMap<Key, SecurityRoles> = BTreeMap::new();
+    revoke_badge(&mut roles_map, vec![proposer], SecurityBadge::Admin);
+    grant_badge(&mut roles_map, vec![caller], SecurityBadge::Admin);
+
+    change_sec_badge(&roles_map);
+    record_event_dictionary(Event::ChangeSecurityRoles(ChangeSecurityRoles {
+        admin: proposer,
+        sec_change_map: roles_map,
+    }));
 }
 
+/// Drops all of the caller's own badges. The last admin cannot renounce.
 #[no_mangle]
-pub extern "C" fn upgrade() {
-    put_key(
-        ARG_CONTRACT_HASH,
-        get_named_arg_with_user_errors::<Key>(
-            ARG_CONTRACT_HASH,
-            Cep85Error::MissingContractHash,
-            Cep85Error::InvalidContractHash,
-        )
-        .unwrap_or_revert(),
-    );
-    record_event_dictionary(Event::Upgrade(Upgrade {}))
+pub extern "C" fn renounce_role() {
+    let (caller, _) = get_verified_caller();
+
+    let mut roles_map: BTreeMap<Key, SecurityRoles> = BTreeMap::new();
+    roles_map.insert(caller, SecurityRoles::default());
+
+    change_sec_badge(&roles_map);
+    record_event_dictionary(Event::ChangeSecurityRoles(ChangeSecurityRoles {
+        admin: caller,
+        sec_change_map: roles_map,
+    }));
 }
 
-fn install_contract() {
-    let name: String = get_named_arg_with_user_errors(
-        ARG_NAME,
-        Cep85Error::MissingCollectionName,
-        Cep85Error::InvalidCollectionName,
+#[no_mangle]
+pub extern "C" fn get_security_badge() {
+    let account: Key = get_named_arg_with_user_errors(
+        ARG_ACCOUNT,
+        Cep85Error::MissingAccount,
+        Cep85Error::InvalidAccount,
     )
     .unwrap_or_revert();
 
-    let uri: String = get_named_arg_with_user_errors(
-        ARG_URI,
-        Cep85Error::MissingUri,
-        Cep85Error::InvalidCollectionName,
+    let badges = read_security_roles(&account).unwrap_or_default().badges();
+    runtime::ret(CLValue::from_t(badges).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn role_member_count() {
+    let badge: SecurityBadge = get_named_arg_with_user_errors(
+        ARG_BADGE,
+        Cep85Error::MissingBadge,
+        Cep85Error::InvalidBadge,
     )
     .unwrap_or_revert();
 
-    let events_mode: u8 =
-        get_optional_named_arg_with_user_errors(ARG_EVENTS_MODE, Cep85Error::InvalidEventsMode)
-            .unwrap_or_default();
+    runtime::ret(CLValue::from_t(read_role_member_count(badge)).unwrap_or_revert());
+}
 
-    let enable_burn: bool =
-        get_optional_named_arg_with_user_errors(ARG_ENABLE_BURN, Cep85Error::InvalidEnableBurnFlag)
-            .unwrap_or_default();
+/// Returns the keys holding `badge`, paged with the optional `start` and `limit` arguments.
+/// Badges granted before an upgrade that introduced member tracking are not listed until they
+/// are changed again.
+#[no_mangle]
+pub extern "C" fn role_members() {
+    let badge: SecurityBadge = get_named_arg_with_user_errors(
+        ARG_BADGE,
+        Cep85Error::MissingBadge,
+        Cep85Error::InvalidBadge,
+    )
+    .unwrap_or_revert();
+    let start: u32 =
+        get_optional_named_arg_with_user_errors(ARG_START, Cep85Error::InvalidStart).unwrap_or(0);
+    let limit: u32 = get_optional_named_arg_with_user_errors(ARG_LIMIT, Cep85Error::InvalidLimit)
+        .unwrap_or(u32::MAX);
 
-    let transfer_filter_contract_key: Option<Key> = get_optional_named_arg_with_user_errors(
-        ARG_TRANSFER_FILTER_CONTRACT,
-        Cep85Error::InvalidTransferFilterContract,
-    );
+    runtime::ret(CLValue::from_t(read_role_members(badge, start, limit)).unwrap_or_revert());
+}
 
-    let transfer_filter_method: Option<String> = get_optional_named_arg_with_user_errors(
-        ARG_TRANSFER_FILTER_METHOD,
-        Cep85Error::InvalidTransferFilterMethod,
-    );
+/// Grants `badge` to `account` for token `id` only, or for every id from `id` to `end_id`
+/// inclusive. Only the minter, burner and meta badges can be scoped.
+#[no_mangle]
+pub extern "C" fn grant_scoped_role() {
+    sec_check_timelocked_action();
 
-    if let Some(_contract_key) = transfer_filter_contract_key {
-        if transfer_filter_method.is_none() || transfer_filter_method.as_ref().unwrap().is_empty() {
-            revert(Cep85Error::InvalidTransferFilterMethod);
-        }
-    }
+    check_not_paused();
 
-    let mut named_keys = NamedKeys::new();
-    named_keys.insert(ARG_NAME.to_string(), storage::new_uref(name.clone()).into());
-    named_keys.insert(ARG_URI.to_string(), storage::new_uref(uri).into());
-    named_keys.insert(
+    let (account, badge, start_id, end_id) = get_scoped_role_args();
+    add_scoped_role(&account, badge, &start_id, &end_id);
+
+    record_event_dictionary(Event::GrantScopedRole(GrantScopedRole {
+        admin: get_verified_caller().0,
+        account,
+        badge,
+        start_id,
+        end_id,
+    }));
+}
+
+/// Revokes a grant made with `grant_scoped_role`, matching its exact id range.
+#[no_mangle]
+pub extern "C" fn revoke_scoped_role() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    check_not_paused();
+
+    let (account, badge, start_id, end_id) = get_scoped_role_args();
+    if !remove_scoped_role(&account, badge, &start_id, &end_id) {
+        revert(Cep85Error::MissingScopedRole);
+    }
+
+    record_event_dictionary(Event::RevokeScopedRole(RevokeScopedRole {
+        admin: get_verified_caller().0,
+        account,
+        badge,
+        start_id,
+        end_id,
+    }));
+}
+
+/// Sets the mint quota of `account` for token `id`, or for the whole collection when no `id` is
+/// given. `lifetime_limit` caps the total amount minted under the quota and `window_limit` the
+/// amount minted per `window` milliseconds. Usage is kept when a quota is changed, and omitting
+/// both limits lifts the quota.
+#[no_mangle]
+pub extern "C" fn set_mint_quota() {
+    sec_check_timelocked_action();
+
+    check_not_paused();
+
+    let account: Key = get_named_arg_with_user_errors(
+        ARG_ACCOUNT,
+        Cep85Error::MissingAccount,
+        Cep85Error::InvalidAccount,
+    )
+    .unwrap_or_revert();
+    let id: Option<U256> = get_optional_named_arg_with_user_errors(ARG_ID, Cep85Error::InvalidId);
+    let lifetime_limit: Option<U256> = get_optional_named_arg_with_user_errors(
+        ARG_LIFETIME_LIMIT,
+        Cep85Error::InvalidLifetimeLimit,
+    );
+    let window_limit: Option<U256> =
+        get_optional_named_arg_with_user_errors(ARG_WINDOW_LIMIT, Cep85Error::InvalidWindowLimit);
+    let window: u64 =
+        get_optional_named_arg_with_user_errors(ARG_WINDOW, Cep85Error::InvalidWindow)
+            .unwrap_or_default();
+
+    if window_limit.is_some() && window == 0 {
+        revert(Cep85Error::InvalidWindow);
+    }
+
+    write_mint_quota(
+        &account,
+        id.as_ref(),
+        &(lifetime_limit, window_limit, window),
+    );
+
+    record_event_dictionary(Event::SetMintQuota(SetMintQuota {
+        admin: get_verified_caller().0,
+        account,
+        id,
+        lifetime_limit,
+        window_limit,
+        window,
+    }));
+}
+
+/// Returns the amount `account` can still mint of token `id`, or of any token when no `id` is
+/// given, or `None` if no quota limits it.
+#[no_mangle]
+pub extern "C" fn remaining_mint_quota() {
+    let account: Key = get_named_arg_with_user_errors(
+        ARG_ACCOUNT,
+        Cep85Error::MissingAccount,
+        Cep85Error::InvalidAccount,
+    )
+    .unwrap_or_revert();
+    let id: Option<U256> = get_optional_named_arg_with_user_errors(ARG_ID, Cep85Error::InvalidId);
+
+    let remaining = read_remaining_mint_quota(&account, id.as_ref());
+    runtime::ret(CLValue::from_t(remaining).unwrap_or_revert());
+}
+
+/// Proposes to call the admin `action` entry point with the serialized `action_args`, optionally
+/// until the `expires_at` block time, and returns the id of the proposal. The proposer approves it
+/// right away, so it is executed immediately when the admin threshold is one.
+#[no_mangle]
+pub extern "C" fn create_proposal() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let action: String = get_named_arg_with_user_errors(
+        ARG_ACTION,
+        Cep85Error::MissingAction,
+        Cep85Error::InvalidAction,
+    )
+    .unwrap_or_revert();
+    if !PROPOSAL_ACTIONS.contains(&action.as_str()) {
+        revert(Cep85Error::InvalidAction);
+    }
+
+    let action_args: Bytes = get_named_arg_with_user_errors(
+        ARG_ACTION_ARGS,
+        Cep85Error::MissingActionArgs,
+        Cep85Error::InvalidActionArgs,
+    )
+    .unwrap_or_revert();
+    RuntimeArgs::from_bytes(&action_args).unwrap_or_revert_with(Cep85Error::InvalidActionArgs);
+
+    let expires_at: Option<u64> =
+        get_optional_named_arg_with_user_errors(ARG_EXPIRES_AT, Cep85Error::InvalidExpiresAt);
+    if let Some(expires_at) = expires_at {
+        if expires_at <= u64::from(runtime::get_blocktime()) {
+            revert(Cep85Error::ExpiresAtInPast);
+        }
+    }
+
+    let proposal_id: u32 = get_stored_value_with_user_errors(
+        PROPOSAL_COUNT,
+        Cep85Error::MissingProposalCount,
+        Cep85Error::InvalidProposalCount,
+    );
+    let proposal_count = proposal_id
+        .checked_add(1)
+        .unwrap_or_revert_with(Cep85Error::Overflow);
+    put_key(PROPOSAL_COUNT, storage::new_uref(proposal_count).into());
+
+    write_proposal_action(proposal_id, &action, &action_args);
+    write_proposal_state(proposal_id, ProposalStatus::Pending, 0, expires_at);
+
+    let proposer = get_verified_caller().0;
+    record_event_dictionary(Event::CreateProposal(CreateProposal {
+        proposal_id,
+        proposer,
+        action,
+        action_args,
+        expires_at,
+    }));
+
+    add_proposal_approval(proposal_id, proposer);
+
+    runtime::ret(CLValue::from_t(proposal_id).unwrap_or_revert());
+}
+
+/// Approves a pending proposal, executing it once the admin threshold is reached. A proposal
+/// whose action reverts stays pending.
+#[no_mangle]
+pub extern "C" fn approve_proposal() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let proposal_id: u32 = get_named_arg_with_user_errors(
+        ARG_PROPOSAL_ID,
+        Cep85Error::MissingProposalId,
+        Cep85Error::InvalidProposalId,
+    )
+    .unwrap_or_revert();
+
+    add_proposal_approval(proposal_id, get_verified_caller().0);
+}
+
+/// Cancels a pending proposal, which can then no longer be approved.
+#[no_mangle]
+pub extern "C" fn cancel_proposal() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let proposal_id: u32 = get_named_arg_with_user_errors(
+        ARG_PROPOSAL_ID,
+        Cep85Error::MissingProposalId,
+        Cep85Error::InvalidProposalId,
+    )
+    .unwrap_or_revert();
+
+    let (approvals, expires_at) = read_pending_proposal_state(proposal_id);
+    write_proposal_state(
+        proposal_id,
+        ProposalStatus::Cancelled,
+        approvals,
+        expires_at,
+    );
+
+    record_event_dictionary(Event::CancelProposal(CancelProposal {
+        proposal_id,
+        admin: get_verified_caller().0,
+    }));
+}
+
+/// Queues a call to the timelocked admin `action` entry point with the serialized `action_args`
+/// and returns the id of the operation, which can be executed once the timelock delay has
+/// elapsed.
+#[no_mangle]
+pub extern "C" fn queue_operation() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    let action: String = get_named_arg_with_user_errors(
+        ARG_ACTION,
+        Cep85Error::MissingAction,
+        Cep85Error::InvalidAction,
+    )
+    .unwrap_or_revert();
+    if !TIMELOCKED_ACTIONS.contains(&action.as_str()) {
+        revert(Cep85Error::InvalidAction);
+    }
+
+    let action_args: Bytes = get_named_arg_with_user_errors(
+        ARG_ACTION_ARGS,
+        Cep85Error::MissingActionArgs,
+        Cep85Error::InvalidActionArgs,
+    )
+    .unwrap_or_revert();
+    RuntimeArgs::from_bytes(&action_args).unwrap_or_revert_with(Cep85Error::InvalidActionArgs);
+
+    let operation_id = add_operation(action, action_args, get_verified_caller().0);
+
+    runtime::ret(CLValue::from_t(operation_id).unwrap_or_revert());
+}
+
+/// Executes a queued operation whose timelock delay has elapsed.
+#[no_mangle]
+pub extern "C" fn execute_operation() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let operation_id: u32 = get_named_arg_with_user_errors(
+        ARG_OPERATION_ID,
+        Cep85Error::MissingOperationId,
+        Cep85Error::InvalidOperationId,
+    )
+    .unwrap_or_revert();
+
+    let executable_at = read_ready_operation_state(operation_id);
+
+    // Mark the operation as executed first so that the action can not execute it again.
+    write_operation_state(operation_id, OperationStatus::Executed, executable_at);
+
+    let (action, action_args) = read_operation_action(operation_id);
+    call_own_action(&action, &action_args);
+
+    record_event_dictionary(Event::ExecuteOperation(ExecuteOperation { operation_id }));
+}
+
+/// Cancels a queued operation, which can then no longer be executed.
+#[no_mangle]
+pub extern "C" fn cancel_operation() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let operation_id: u32 = get_named_arg_with_user_errors(
+        ARG_OPERATION_ID,
+        Cep85Error::MissingOperationId,
+        Cep85Error::InvalidOperationId,
+    )
+    .unwrap_or_revert();
+
+    let executable_at = read_queued_operation_state(operation_id);
+    write_operation_state(operation_id, OperationStatus::Cancelled, executable_at);
+
+    record_event_dictionary(Event::CancelOperation(CancelOperation {
+        operation_id,
+        admin: get_verified_caller().0,
+    }));
+}
+
+/// Freezes every account in `accounts`, preventing them from sending, receiving, operating on
+/// or burning tokens until unfrozen.
+#[no_mangle]
+pub extern "C" fn freeze_accounts() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    let accounts: Vec<Key> = get_named_arg_with_user_errors(
+        ARG_ACCOUNTS,
+        Cep85Error::MissingAccounts,
+        Cep85Error::InvalidAccounts,
+    )
+    .unwrap_or_revert();
+
+    for account in accounts.iter() {
+        write_frozen(account, true);
+    }
+    record_event_dictionary(Event::FreezeAccounts(FreezeAccounts { accounts }));
+}
+
+#[no_mangle]
+pub extern "C" fn unfreeze_accounts() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    let accounts: Vec<Key> = get_named_arg_with_user_errors(
+        ARG_ACCOUNTS,
+        Cep85Error::MissingAccounts,
+        Cep85Error::InvalidAccounts,
+    )
+    .unwrap_or_revert();
+
+    for account in accounts.iter() {
+        write_frozen(account, false);
+    }
+    record_event_dictionary(Event::UnfreezeAccounts(UnfreezeAccounts { accounts }));
+}
+
+#[no_mangle]
+pub extern "C" fn is_frozen() {
+    let account: Key = get_named_arg_with_user_errors(
+        ARG_ACCOUNT,
+        Cep85Error::MissingAccount,
+        Cep85Error::InvalidAccount,
+    )
+    .unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(read_frozen(&account)).unwrap_or_revert());
+}
+
+/// Locks token `id`, preventing any transfer or burn of it until unlocked.
+#[no_mangle]
+pub extern "C" fn lock_id() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+
+    write_id_lock(&id, true);
+    record_event_dictionary(Event::LockId(LockId { id }));
+}
+
+#[no_mangle]
+pub extern "C" fn batch_lock_ids() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    let ids: Vec<U256> =
+        get_named_arg_with_user_errors(ARG_IDS, Cep85Error::MissingIds, Cep85Error::InvalidIds)
+            .unwrap_or_revert();
+
+    for id in ids.iter() {
+        write_id_lock(id, true);
+    }
+    record_event_dictionary(Event::LockIdBatch(LockIdBatch { ids }));
+}
+
+#[no_mangle]
+pub extern "C" fn unlock_id() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+
+    write_id_lock(&id, false);
+    record_event_dictionary(Event::UnlockId(UnlockId { id }));
+}
+
+#[no_mangle]
+pub extern "C" fn batch_unlock_ids() {
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
+
+    let ids: Vec<U256> =
+        get_named_arg_with_user_errors(ARG_IDS, Cep85Error::MissingIds, Cep85Error::InvalidIds)
+            .unwrap_or_revert();
+
+    for id in ids.iter() {
+        write_id_lock(id, false);
+    }
+    record_event_dictionary(Event::UnlockIdBatch(UnlockIdBatch { ids }));
+}
+
+#[no_mangle]
+pub extern "C" fn is_id_locked() {
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(read_id_lock(&id)).unwrap_or_revert());
+}
+
+/// Halts every state-changing entry point until `unpause` is called. Only the security and
+/// incident response entry points stay available while paused: `change_security`,
+/// `propose_admin`, `accept_admin`, `renounce_role`, `freeze_accounts`, `unfreeze_accounts`,
+/// `lock_id`, `batch_lock_ids`, `unlock_id`, `batch_unlock_ids`, `unpause` and `upgrade`, along
+/// with the proposal and operation entry points, whose executed actions are checked themselves.
+#[no_mangle]
+pub extern "C" fn pause() {
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Pauser]);
+
+    check_not_paused();
+
+    runtime::put_key(PAUSED, storage::new_uref(true).into());
+    record_event_dictionary(Event::Paused(Paused {
+        account: get_verified_caller().0,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Pauser]);
+
+    if !is_contract_paused() {
+        revert(Cep85Error::ContractNotPaused);
+    }
+
+    runtime::put_key(PAUSED, storage::new_uref(false).into());
+    record_event_dictionary(Event::Unpaused(Unpaused {
+        account: get_verified_caller().0,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn is_paused() {
+    runtime::ret(CLValue::from_t(is_contract_paused()).unwrap_or_revert());
+}
+
+// set_modalities allows the user to set any variable or any combination of variables
+// simultaneously.
+#[no_mangle]
+pub extern "C" fn set_modalities() {
+    // Only the installing account can change the mutable variables.
+    sec_check_timelocked_action();
+
+    check_not_paused();
+
+    if let Some(enable_burn) = get_optional_named_arg_with_user_errors::<bool>(
+        ARG_ENABLE_BURN,
+        Cep85Error::InvalidEventsMode,
+    ) {
+        runtime::put_key(ARG_ENABLE_BURN, storage::new_uref(enable_burn).into());
+    }
+
+    if let Some(burn_mode) =
+        get_optional_named_arg_with_user_errors::<u8>(ARG_BURN_MODE, Cep85Error::InvalidBurnMode)
+    {
+        BurnMode::try_from(burn_mode).unwrap_or_revert();
+        runtime::put_key(ARG_BURN_MODE, storage::new_uref(burn_mode).into());
+    }
+
+    if let Some(enable_receiver_check) = get_optional_named_arg_with_user_errors::<bool>(
+        ARG_ENABLE_RECEIVER_CHECK,
+        Cep85Error::InvalidEnableReceiverCheckFlag,
+    ) {
+        runtime::put_key(
+            ARG_ENABLE_RECEIVER_CHECK,
+            storage::new_uref(enable_receiver_check).into(),
+        );
+    }
+
+    if let Some(enable_filter_reasons) = get_optional_named_arg_with_user_errors::<bool>(
+        ARG_ENABLE_FILTER_REASONS,
+        Cep85Error::InvalidEnableFilterReasonsFlag,
+    ) {
+        runtime::put_key(
+            ARG_ENABLE_FILTER_REASONS,
+            storage::new_uref(enable_filter_reasons).into(),
+        );
+    }
+
+    if let Some(enable_mint_burn_filter) = get_optional_named_arg_with_user_errors::<bool>(
+        ARG_ENABLE_MINT_BURN_FILTER,
+        Cep85Error::InvalidEnableMintBurnFilterFlag,
+    ) {
+        runtime::put_key(
+            ARG_ENABLE_MINT_BURN_FILTER,
+            storage::new_uref(enable_mint_burn_filter).into(),
+        );
+    }
+
+    if let Some(registered_ids_only) = get_optional_named_arg_with_user_errors::<bool>(
+        ARG_REGISTERED_IDS_ONLY,
+        Cep85Error::InvalidRegisteredIdsOnlyFlag,
+    ) {
+        runtime::put_key(
+            ARG_REGISTERED_IDS_ONLY,
+            storage::new_uref(registered_ids_only).into(),
+        );
+    }
+
+    if let Some(timelock_delay) = get_optional_named_arg_with_user_errors::<u64>(
+        ARG_TIMELOCK_DELAY,
+        Cep85Error::InvalidTimelockDelay,
+    ) {
+        runtime::put_key(ARG_TIMELOCK_DELAY, storage::new_uref(timelock_delay).into());
+    }
+
+    if let Some(optional_events_mode) = get_optional_named_arg_with_user_errors::<u8>(
+        ARG_EVENTS_MODE,
+        Cep85Error::InvalidEventsMode,
+    ) {
+        let old_events_mode: EventsMode = get_stored_value_with_user_errors::<u8>(
+            ARG_EVENTS_MODE,
+            Cep85Error::MissingEventsMode,
+            Cep85Error::InvalidEventsMode,
+        )
+        .try_into()
+        .unwrap_or_revert();
+
+        runtime::put_key(
+            ARG_EVENTS_MODE,
+            storage::new_uref(optional_events_mode).into(),
+        );
+
+        let new_events_mode: EventsMode = optional_events_mode
+            .try_into()
+            .unwrap_or_revert_with(Cep85Error::InvalidEventsMode);
+
+        // Check if current_events_mode and requested_events_mode are both CES
+        if old_events_mode != EventsMode::CES && new_events_mode == EventsMode::CES {
+            // Initialize events structures
+            init_events();
+        }
+    }
+
+    record_event_dictionary(Event::SetModalities(SetModalities {}));
+}
+
+/// Admin EntryPoint to set, replace or remove the transfer filter contract and method. Omitting
+/// the `transfer_filter_contract` argument removes the filter.
+#[no_mangle]
+pub extern "C" fn set_transfer_filter() {
+    sec_check_timelocked_action();
+
+    check_not_paused();
+
+    let transfer_filter_contract_key: Option<Key> = get_optional_named_arg_with_user_errors(
+        ARG_TRANSFER_FILTER_CONTRACT,
+        Cep85Error::InvalidTransferFilterContract,
+    );
+
+    let transfer_filter_method: Option<String> = get_optional_named_arg_with_user_errors(
+        ARG_TRANSFER_FILTER_METHOD,
+        Cep85Error::InvalidTransferFilterMethod,
+    );
+
+    check_hook_method(
+        &transfer_filter_contract_key,
+        &transfer_filter_method,
+        Cep85Error::InvalidTransferFilterMethod,
+    );
+
+    let transfer_filter_contract: Option<ContractHash> =
+        transfer_filter_contract_key.map(|transfer_filter_contract_key| {
+            ContractHash::from(
+                transfer_filter_contract_key
+                    .into_hash()
+                    .unwrap_or_revert_with(Cep85Error::InvalidTransferFilterContract),
+            )
+        });
+    // A method is only kept along with the contract it belongs to.
+    let transfer_filter_method = transfer_filter_contract.and(transfer_filter_method);
+
+    put_key(
+        ARG_TRANSFER_FILTER_CONTRACT,
+        storage::new_uref(transfer_filter_contract).into(),
+    );
+    put_key(
+        ARG_TRANSFER_FILTER_METHOD,
+        storage::new_uref(transfer_filter_method.clone()).into(),
+    );
+
+    record_event_dictionary(Event::SetTransferFilter(SetTransferFilter {
+        transfer_filter_contract,
+        transfer_filter_method,
+    }));
+}
+
+/// Admin EntryPoint to append a filter to the chain of transfer filters, applying to the ids from
+/// `id` to the optional `end_id` when given, or to every id otherwise.
+#[no_mangle]
+pub extern "C" fn add_transfer_filter() {
+    sec_check_timelocked_action();
+
+    check_not_paused();
+
+    let transfer_filter_contract_key: Key = get_named_arg_with_user_errors(
+        ARG_TRANSFER_FILTER_CONTRACT,
+        Cep85Error::MissingTransferFilterContract,
+        Cep85Error::InvalidTransferFilterContract,
+    )
+    .unwrap_or_revert();
+
+    let transfer_filter_method: String = get_named_arg_with_user_errors(
+        ARG_TRANSFER_FILTER_METHOD,
+        Cep85Error::MissingTransferFilterMethod,
+        Cep85Error::InvalidTransferFilterMethod,
+    )
+    .unwrap_or_revert();
+
+    check_hook_method(
+        &Some(transfer_filter_contract_key),
+        &Some(transfer_filter_method.clone()),
+        Cep85Error::InvalidTransferFilterMethod,
+    );
+
+    let transfer_filter_contract = ContractHash::from(
+        transfer_filter_contract_key
+            .into_hash()
+            .unwrap_or_revert_with(Cep85Error::InvalidTransferFilterContract),
+    );
+
+    let start_id: Option<U256> =
+        get_optional_named_arg_with_user_errors(ARG_ID, Cep85Error::InvalidId);
+    let end_id: Option<U256> =
+        get_optional_named_arg_with_user_errors(ARG_END_ID, Cep85Error::InvalidEndId);
+    let id_range = match (start_id, end_id) {
+        (Some(start_id), end_id) => {
+            let end_id = end_id.unwrap_or(start_id);
+            if end_id < start_id {
+                revert(Cep85Error::InvalidIdRange);
+            }
+            Some((start_id, end_id))
+        }
+        (None, Some(_)) => revert(Cep85Error::InvalidIdRange),
+        (None, None) => None,
+    };
+
+    let mut transfer_filters = read_transfer_filters();
+    transfer_filters.push((
+        transfer_filter_contract,
+        transfer_filter_method.clone(),
+        id_range,
+    ));
+    write_transfer_filters(transfer_filters);
+
+    record_event_dictionary(Event::AddTransferFilter(AddTransferFilter {
+        transfer_filter_contract,
+        transfer_filter_method,
+        start_id: id_range.map(|(start_id, _)| start_id),
+        end_id: id_range.map(|(_, end_id)| end_id),
+    }));
+}
+
+/// Admin EntryPoint to remove the filter at `index` from the chain of transfer filters, shifting
+/// the following filters down by one.
+#[no_mangle]
+pub extern "C" fn remove_transfer_filter() {
+    sec_check_timelocked_action();
+
+    check_not_paused();
+
+    let index: u32 = get_named_arg_with_user_errors(
+        ARG_INDEX,
+        Cep85Error::MissingTransferFilterIndex,
+        Cep85Error::InvalidTransferFilterIndex,
+    )
+    .unwrap_or_revert();
+
+    let mut transfer_filters = read_transfer_filters();
+    if index as usize >= transfer_filters.len() {
+        revert(Cep85Error::InvalidTransferFilterIndex);
+    }
+    let (transfer_filter_contract, transfer_filter_method, _) =
+        transfer_filters.remove(index as usize);
+    write_transfer_filters(transfer_filters);
+
+    record_event_dictionary(Event::RemoveTransferFilter(RemoveTransferFilter {
+        index,
+        transfer_filter_contract,
+        transfer_filter_method,
+    }));
+}
+
+/// Admin EntryPoint to set, replace or remove the contract and method called after every
+/// transfer, mint and burn. Omitting the `after_transfer_hook_contract` argument removes the hook.
+#[no_mangle]
+pub extern "C" fn set_after_transfer_hook() {
+    sec_check_timelocked_action();
+
+    check_not_paused();
+
+    let after_transfer_hook_contract_key: Option<Key> = get_optional_named_arg_with_user_errors(
+        ARG_AFTER_TRANSFER_HOOK_CONTRACT,
+        Cep85Error::InvalidAfterTransferHookContract,
+    );
+
+    let after_transfer_hook_method: Option<String> = get_optional_named_arg_with_user_errors(
+        ARG_AFTER_TRANSFER_HOOK_METHOD,
+        Cep85Error::InvalidAfterTransferHookMethod,
+    );
+
+    let (after_transfer_hook_contract, after_transfer_hook_method) =
+        get_after_transfer_hook_args(after_transfer_hook_contract_key, after_transfer_hook_method);
+
+    put_key(
+        ARG_AFTER_TRANSFER_HOOK_CONTRACT,
+        storage::new_uref(after_transfer_hook_contract).into(),
+    );
+    put_key(
+        ARG_AFTER_TRANSFER_HOOK_METHOD,
+        storage::new_uref(after_transfer_hook_method.clone()).into(),
+    );
+
+    record_event_dictionary(Event::SetAfterTransferHook(SetAfterTransferHook {
+        after_transfer_hook_contract,
+        after_transfer_hook_method,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn upgrade() {
+    // Only the installer, through the upgrade session, or an admin may repoint the contract hash.
+    let (caller, _) = get_verified_caller();
+    let is_installer = get_key(INSTALLER).is_some()
+        && get_stored_value_with_user_errors::<Key>(
+            INSTALLER,
+            Cep85Error::MissingInstaller,
+            Cep85Error::InvalidInstaller,
+        ) == caller;
+    if !is_installer {
+        sec_check(vec![SecurityBadge::Admin]);
+    }
+
+    // The contract hash must be the one of the new version being upgraded to, as it is the
+    // recipient of the calls made when executing proposals and operations.
+    let contract_hash = get_named_arg_with_user_errors::<Key>(
+        ARG_CONTRACT_HASH,
+        Cep85Error::MissingContractHash,
+        Cep85Error::InvalidContractHash,
+    )
+    .unwrap_or_revert();
+    if contract_hash != get_current_contract_hash() {
+        revert(Cep85Error::InvalidContractHash);
+    }
+    put_key(ARG_CONTRACT_HASH, contract_hash);
+
+    if get_key(DICT_ADMIN_PROPOSALS).is_none() {
+        storage::new_dictionary(DICT_ADMIN_PROPOSALS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_SCOPED_ROLES).is_none() {
+        storage::new_dictionary(DICT_SCOPED_ROLES)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+        storage::new_dictionary(DICT_SCOPED_ROLE_COUNTS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_SECURITY_ROLES).is_none() {
+        storage::new_dictionary(DICT_SECURITY_ROLES)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_PROPOSALS).is_none() {
+        storage::new_dictionary(DICT_PROPOSALS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+        storage::new_dictionary(DICT_PROPOSAL_STATES)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+        storage::new_dictionary(DICT_PROPOSAL_APPROVALS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(PROPOSAL_COUNT).is_none() {
+        put_key(PROPOSAL_COUNT, storage::new_uref(0u32).into());
+    }
+
+    // Versions installed before proposals existed keep executing admin actions directly.
+    if get_key(ARG_ADMIN_THRESHOLD).is_none() {
+        put_key(ARG_ADMIN_THRESHOLD, storage::new_uref(1u32).into());
+    }
+
+    if get_key(TRANSFER_FILTERS).is_none() {
+        write_transfer_filters(Vec::new());
+    }
+
+    if get_key(DICT_OPERATIONS).is_none() {
+        storage::new_dictionary(DICT_OPERATIONS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+        storage::new_dictionary(DICT_OPERATION_STATES)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    // Ids already in use are skipped when allocating, so the counter can start from the beginning.
+    if get_key(NEXT_ID).is_none() {
+        put_key(NEXT_ID, storage::new_uref(U256::one()).into());
+    }
+
+    if get_key(OPERATION_COUNT).is_none() {
+        put_key(OPERATION_COUNT, storage::new_uref(0u32).into());
+    }
+
+    if get_key(ARG_TIMELOCK_DELAY).is_none() {
+        put_key(ARG_TIMELOCK_DELAY, storage::new_uref(0u64).into());
+    }
+
+    if get_key(DICT_MINT_QUOTAS).is_none() {
+        storage::new_dictionary(DICT_MINT_QUOTAS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+        storage::new_dictionary(DICT_MINT_QUOTA_USAGES)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_ROLE_MEMBERS).is_none() {
+        storage::new_dictionary(DICT_ROLE_MEMBERS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+        storage::new_dictionary(DICT_ROLE_MEMBER_INDEXES)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+        storage::new_dictionary(DICT_ROLE_MEMBER_COUNTS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_ALLOWANCES).is_none() {
+        storage::new_dictionary(DICT_ALLOWANCES)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_FROZEN_ACCOUNTS).is_none() {
+        storage::new_dictionary(DICT_FROZEN_ACCOUNTS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_LOCKED_IDS).is_none() {
+        storage::new_dictionary(DICT_LOCKED_IDS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_OPERATOR_EXPIRIES).is_none() {
+        storage::new_dictionary(DICT_OPERATOR_EXPIRIES)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_TOKEN_KINDS).is_none() {
+        storage::new_dictionary(DICT_TOKEN_KINDS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_ID_NAMESPACES).is_none() {
+        storage::new_dictionary(DICT_ID_NAMESPACES)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_TOKEN_TYPES).is_none() {
+        storage::new_dictionary(DICT_TOKEN_TYPES)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_TYPE_ITEMS).is_none() {
+        storage::new_dictionary(DICT_TYPE_ITEMS)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_TYPE_BALANCES).is_none() {
+        storage::new_dictionary(DICT_TYPE_BALANCES)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    if get_key(DICT_TOKEN_METADATA).is_none() {
+        storage::new_dictionary(DICT_TOKEN_METADATA)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateDictionary);
+    }
+
+    // Versions installed before the receiver check existed keep their current behavior.
+    if get_key(ARG_ENABLE_RECEIVER_CHECK).is_none() {
+        put_key(ARG_ENABLE_RECEIVER_CHECK, storage::new_uref(false).into());
+    }
+
+    // Same for the mint and burn filter, mints and burns stay unfiltered.
+    if get_key(ARG_ENABLE_MINT_BURN_FILTER).is_none() {
+        put_key(ARG_ENABLE_MINT_BURN_FILTER, storage::new_uref(false).into());
+    }
+
+    // Same for filter reasons, existing filters may return any non-zero value to let a transfer
+    // proceed.
+    if get_key(ARG_ENABLE_FILTER_REASONS).is_none() {
+        put_key(ARG_ENABLE_FILTER_REASONS, storage::new_uref(false).into());
+    }
+
+    // Any id could be minted before tokens could be created.
+    if get_key(ARG_REGISTERED_IDS_ONLY).is_none() {
+        put_key(ARG_REGISTERED_IDS_ONLY, storage::new_uref(false).into());
+    }
+
+    // Burning required both a badge and ownership before burn modes existed.
+    if get_key(ARG_BURN_MODE).is_none() {
+        put_key(
+            ARG_BURN_MODE,
+            storage::new_uref(BurnMode::Both as u8).into(),
+        );
+    }
+
+    if get_key(ARG_AFTER_TRANSFER_HOOK_CONTRACT).is_none() {
+        put_key(
+            ARG_AFTER_TRANSFER_HOOK_CONTRACT,
+            storage::new_uref(None::<ContractHash>).into(),
+        );
+        put_key(
+            ARG_AFTER_TRANSFER_HOOK_METHOD,
+            storage::new_uref(None::<String>).into(),
+        );
+    }
+
+    if get_key(PAUSED).is_none() {
+        put_key(PAUSED, storage::new_uref(false).into());
+    }
+
+    // Badges granted before role members were tracked are listed from the holders passed by the
+    // upgrade session, which must all hold the badge.
+    let admin_list = backfill_listed_role_members(
+        ADMIN_LIST,
+        SecurityBadge::Admin,
+        Cep85Error::InvalidAdminList,
+    );
+    backfill_listed_role_members(
+        MINTER_LIST,
+        SecurityBadge::Minter,
+        Cep85Error::InvalidMinterList,
+    );
+    backfill_listed_role_members(
+        BURNER_LIST,
+        SecurityBadge::Burner,
+        Cep85Error::InvalidBurnerList,
+    );
+    backfill_listed_role_members(META_LIST, SecurityBadge::Meta, Cep85Error::InvalidMetaList);
+    backfill_listed_role_members(
+        PAUSER_LIST,
+        SecurityBadge::Pauser,
+        Cep85Error::InvalidPauserList,
+    );
+    backfill_listed_role_members(
+        CONTROLLER_LIST,
+        SecurityBadge::Controller,
+        Cep85Error::InvalidControllerList,
+    );
+
+    // Admins were not counted before, so the upgrade session has to list every current admin for
+    // the count to allow removing any of them.
+    if get_key(ADMIN_COUNT).is_none() {
+        let admin_count = admin_list
+            .filter(|admin_list| !admin_list.is_empty())
+            .unwrap_or_revert_with(Cep85Error::MissingAdminList)
+            .len() as u32;
+        put_key(ADMIN_COUNT, storage::new_uref(admin_count).into());
+    }
+
+    record_event_dictionary(Event::Upgrade(Upgrade {}))
+}
+
+/// Reads the optional list of the keys holding `badge` passed to `upgrade` and lists them among
+/// the members of `badge`, returning the deduplicated list.
+fn backfill_listed_role_members(
+    list_name: &str,
+    badge: SecurityBadge,
+    invalid_list_error: Cep85Error,
+) -> Option<Vec<Key>> {
+    let mut users: Vec<Key> =
+        get_optional_named_arg_with_user_errors(list_name, invalid_list_error)?;
+    users.sort();
+    users.dedup();
+    backfill_role_members(badge, &users);
+    Some(users)
+}
+
+fn install_contract() {
+    let name: String = get_named_arg_with_user_errors(
+        ARG_NAME,
+        Cep85Error::MissingCollectionName,
+        Cep85Error::InvalidCollectionName,
+    )
+    .unwrap_or_revert();
+
+    let uri: String = get_named_arg_with_user_errors(
+        ARG_URI,
+        Cep85Error::MissingUri,
+        Cep85Error::InvalidCollectionName,
+    )
+    .unwrap_or_revert();
+
+    let events_mode: u8 =
+        get_optional_named_arg_with_user_errors(ARG_EVENTS_MODE, Cep85Error::InvalidEventsMode)
+            .unwrap_or_default();
+
+    let enable_burn: bool =
+        get_optional_named_arg_with_user_errors(ARG_ENABLE_BURN, Cep85Error::InvalidEnableBurnFlag)
+            .unwrap_or_default();
+
+    let burn_mode: u8 =
+        get_optional_named_arg_with_user_errors(ARG_BURN_MODE, Cep85Error::InvalidBurnMode)
+            .unwrap_or(BurnMode::Both as u8);
+    BurnMode::try_from(burn_mode).unwrap_or_revert();
+
+    // Enabled on new installations, while upgraded contracts keep it disabled.
+    let enable_receiver_check: bool = get_optional_named_arg_with_user_errors(
+        ARG_ENABLE_RECEIVER_CHECK,
+        Cep85Error::InvalidEnableReceiverCheckFlag,
+    )
+    .unwrap_or(true);
+
+    let enable_filter_reasons: bool = get_optional_named_arg_with_user_errors(
+        ARG_ENABLE_FILTER_REASONS,
+        Cep85Error::InvalidEnableFilterReasonsFlag,
+    )
+    .unwrap_or_default();
+
+    let enable_mint_burn_filter: bool = get_optional_named_arg_with_user_errors(
+        ARG_ENABLE_MINT_BURN_FILTER,
+        Cep85Error::InvalidEnableMintBurnFilterFlag,
+    )
+    .unwrap_or_default();
+
+    let registered_ids_only: bool = get_optional_named_arg_with_user_errors(
+        ARG_REGISTERED_IDS_ONLY,
+        Cep85Error::InvalidRegisteredIdsOnlyFlag,
+    )
+    .unwrap_or_default();
+
+    let admin_threshold: u32 = get_optional_named_arg_with_user_errors(
+        ARG_ADMIN_THRESHOLD,
+        Cep85Error::InvalidAdminThreshold,
+    )
+    .unwrap_or(1);
+
+    if admin_threshold == 0 {
+        revert(Cep85Error::InvalidAdminThreshold);
+    }
+
+    let timelock_delay: u64 = get_optional_named_arg_with_user_errors(
+        ARG_TIMELOCK_DELAY,
+        Cep85Error::InvalidTimelockDelay,
+    )
+    .unwrap_or_default();
+
+    let transfer_filter_contract_key: Option<Key> = get_optional_named_arg_with_user_errors(
+        ARG_TRANSFER_FILTER_CONTRACT,
+        Cep85Error::InvalidTransferFilterContract,
+    );
+
+    let transfer_filter_method: Option<String> = get_optional_named_arg_with_user_errors(
+        ARG_TRANSFER_FILTER_METHOD,
+        Cep85Error::InvalidTransferFilterMethod,
+    );
+
+    check_hook_method(
+        &transfer_filter_contract_key,
+        &transfer_filter_method,
+        Cep85Error::InvalidTransferFilterMethod,
+    );
+
+    let after_transfer_hook_contract_key: Option<Key> = get_optional_named_arg_with_user_errors(
+        ARG_AFTER_TRANSFER_HOOK_CONTRACT,
+        Cep85Error::InvalidAfterTransferHookContract,
+    );
+
+    let after_transfer_hook_method: Option<String> = get_optional_named_arg_with_user_errors(
+        ARG_AFTER_TRANSFER_HOOK_METHOD,
+        Cep85Error::InvalidAfterTransferHookMethod,
+    );
+
+    let (after_transfer_hook_contract, after_transfer_hook_method) =
+        get_after_transfer_hook_args(after_transfer_hook_contract_key, after_transfer_hook_method);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(ARG_NAME.to_string(), storage::new_uref(name.clone()).into());
+    named_keys.insert(ARG_URI.to_string(), storage::new_uref(uri).into());
+    named_keys.insert(
         ARG_EVENTS_MODE.to_string(),
         storage::new_uref(events_mode).into(),
     );
     named_keys.insert(
-        ARG_ENABLE_BURN.to_string(),
-        storage::new_uref(enable_burn).into(),
+        ARG_ENABLE_BURN.to_string(),
+        storage::new_uref(enable_burn).into(),
+    );
+    named_keys.insert(
+        ARG_BURN_MODE.to_string(),
+        storage::new_uref(burn_mode).into(),
+    );
+    named_keys.insert(
+        ARG_ENABLE_RECEIVER_CHECK.to_string(),
+        storage::new_uref(enable_receiver_check).into(),
+    );
+    named_keys.insert(
+        ARG_ENABLE_FILTER_REASONS.to_string(),
+        storage::new_uref(enable_filter_reasons).into(),
+    );
+    named_keys.insert(
+        ARG_ENABLE_MINT_BURN_FILTER.to_string(),
+        storage::new_uref(enable_mint_burn_filter).into(),
+    );
+    named_keys.insert(
+        ARG_REGISTERED_IDS_ONLY.to_string(),
+        storage::new_uref(registered_ids_only).into(),
+    );
+    named_keys.insert(
+        ARG_AFTER_TRANSFER_HOOK_CONTRACT.to_string(),
+        storage::new_uref(after_transfer_hook_contract).into(),
+    );
+    named_keys.insert(
+        ARG_AFTER_TRANSFER_HOOK_METHOD.to_string(),
+        storage::new_uref(after_transfer_hook_method).into(),
+    );
+    named_keys.insert(PAUSED.to_string(), storage::new_uref(false).into());
+    named_keys.insert(
+        ARG_ADMIN_THRESHOLD.to_string(),
+        storage::new_uref(admin_threshold).into(),
+    );
+    named_keys.insert(
+        ARG_TIMELOCK_DELAY.to_string(),
+        storage::new_uref(timelock_delay).into(),
+    );
+    named_keys.insert(
+        INSTALLER.to_string(),
+        storage::new_uref(Key::from(runtime::get_caller())).into(),
     );
 
     let entry_points = generate_entry_points();
@@ -1145,6 +3018,10 @@ fn install_contract() {
         get_optional_named_arg_with_user_errors(BURNER_LIST, Cep85Error::InvalidBurnerList);
     let meta_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(META_LIST, Cep85Error::InvalidMetaList);
+    let pauser_list: Option<Vec<Key>> =
+        get_optional_named_arg_with_user_errors(PAUSER_LIST, Cep85Error::InvalidPauserList);
+    let controller_list: Option<Vec<Key>> =
+        get_optional_named_arg_with_user_errors(CONTROLLER_LIST, Cep85Error::InvalidControllerList);
     let none_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(NONE_LIST, Cep85Error::InvalidNoneList);
 
@@ -1164,6 +3041,16 @@ fn install_contract() {
     if let Some(meta_list) = meta_list {
         init_args.insert(META_LIST, meta_list).unwrap_or_revert();
     }
+    if let Some(pauser_list) = pauser_list {
+        init_args
+            .insert(PAUSER_LIST, pauser_list)
+            .unwrap_or_revert();
+    }
+    if let Some(controller_list) = controller_list {
+        init_args
+            .insert(CONTROLLER_LIST, controller_list)
+            .unwrap_or_revert();
+    }
     if let Some(none_list) = none_list {
         init_args.insert(NONE_LIST, none_list).unwrap_or_revert();
     }
@@ -1172,13 +3059,21 @@ fn install_contract() {
 }
 
 fn upgrade_contract(name: &str, contract_package_hash: Key) {
+    // The named keys of the previous version take precedence, so the installer is only recorded
+    // for contracts installed before it was.
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        INSTALLER.to_string(),
+        storage::new_uref(Key::from(runtime::get_caller())).into(),
+    );
+
     let (contract_hash, contract_version) = storage::add_contract_version(
         contract_package_hash
             .into_hash()
             .unwrap_or_revert_with(Cep85Error::InvalidPackageHash)
             .into(),
         generate_entry_points(),
-        NamedKeys::new(),
+        named_keys,
     );
 
     let contract_hash_key = Key::from(contract_hash);
@@ -1189,13 +3084,245 @@ fn upgrade_contract(name: &str, contract_package_hash: Key) {
         storage::new_uref(contract_version).into(),
     );
 
-    let runtime_args = runtime_args! {
+    let mut runtime_args = runtime_args! {
         ARG_CONTRACT_HASH => contract_hash_key,
     };
 
+    for (list_name, invalid_list_error) in [
+        (ADMIN_LIST, Cep85Error::InvalidAdminList),
+        (MINTER_LIST, Cep85Error::InvalidMinterList),
+        (BURNER_LIST, Cep85Error::InvalidBurnerList),
+        (META_LIST, Cep85Error::InvalidMetaList),
+        (PAUSER_LIST, Cep85Error::InvalidPauserList),
+        (CONTROLLER_LIST, Cep85Error::InvalidControllerList),
+    ] {
+        let list: Option<Vec<Key>> =
+            get_optional_named_arg_with_user_errors(list_name, invalid_list_error);
+        if let Some(list) = list {
+            runtime_args.insert(list_name, list).unwrap_or_revert();
+        }
+    }
+
     runtime::call_contract::<()>(contract_hash, ENTRY_POINT_UPGRADE, runtime_args);
 }
 
+/// Validates the after transfer hook arguments, only keeping the method along with the contract it
+/// belongs to.
+fn get_after_transfer_hook_args(
+    after_transfer_hook_contract_key: Option<Key>,
+    after_transfer_hook_method: Option<String>,
+) -> (Option<ContractHash>, Option<String>) {
+    check_hook_method(
+        &after_transfer_hook_contract_key,
+        &after_transfer_hook_method,
+        Cep85Error::InvalidAfterTransferHookMethod,
+    );
+
+    let after_transfer_hook_contract: Option<ContractHash> =
+        after_transfer_hook_contract_key.map(|after_transfer_hook_contract_key| {
+            ContractHash::from(
+                after_transfer_hook_contract_key
+                    .into_hash()
+                    .unwrap_or_revert_with(Cep85Error::InvalidAfterTransferHookContract),
+            )
+        });
+    let after_transfer_hook_method = after_transfer_hook_contract.and(after_transfer_hook_method);
+    (after_transfer_hook_contract, after_transfer_hook_method)
+}
+
+/// Reverts with `invalid_method_error` unless a hook contract, such as a transfer filter, comes
+/// with a non-empty method.
+fn check_hook_method(
+    contract_key: &Option<Key>,
+    method: &Option<String>,
+    invalid_method_error: Cep85Error,
+) {
+    if contract_key.is_some() && method.as_ref().map_or(true, |method| method.is_empty()) {
+        revert(invalid_method_error);
+    }
+}
+
+/// Reverts unless the caller can burn `ids` from `owner` under the burn mode, returning the caller.
+fn check_burn_rights(owner: &Key, ids: &[U256]) -> Key {
+    let burn_mode: BurnMode = get_stored_value_with_user_errors::<u8>(
+        ARG_BURN_MODE,
+        Cep85Error::MissingBurnMode,
+        Cep85Error::InvalidBurnMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    if burn_mode != BurnMode::OwnerOrOperator {
+        sec_check_ids(vec![SecurityBadge::Admin, SecurityBadge::Burner], ids);
+    }
+
+    let (caller, caller_package) = get_verified_caller();
+
+    if burn_mode != BurnMode::RoleGated {
+        // Check if the caller is the owner or operator
+        let is_approved: bool = match caller_package {
+            Some(caller_package) => {
+                *owner == caller_package
+                    || *owner == caller
+                    || read_operator(owner, &caller_package)
+                    || read_operator(owner, &caller)
+            }
+            None => *owner == caller || read_operator(owner, &caller),
+        };
+
+        if !is_approved {
+            revert(Cep85Error::InvalidBurnTarget);
+        }
+    }
+
+    caller
+}
+
+fn is_contract_paused() -> bool {
+    get_stored_value_with_user_errors::<bool>(
+        PAUSED,
+        Cep85Error::MissingPausedFlag,
+        Cep85Error::InvalidPausedFlag,
+    )
+}
+
+fn check_not_paused() {
+    if is_contract_paused() {
+        revert(Cep85Error::ContractPaused);
+    }
+}
+
+fn get_scoped_role_args() -> (Key, SecurityBadge, U256, U256) {
+    let account: Key = get_named_arg_with_user_errors(
+        ARG_ACCOUNT,
+        Cep85Error::MissingAccount,
+        Cep85Error::InvalidAccount,
+    )
+    .unwrap_or_revert();
+    let badge: SecurityBadge = get_named_arg_with_user_errors(
+        ARG_BADGE,
+        Cep85Error::MissingBadge,
+        Cep85Error::InvalidBadge,
+    )
+    .unwrap_or_revert();
+    let start_id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+    let end_id: U256 =
+        get_optional_named_arg_with_user_errors(ARG_END_ID, Cep85Error::InvalidEndId)
+            .unwrap_or(start_id);
+
+    if !matches!(
+        badge,
+        SecurityBadge::Minter | SecurityBadge::Burner | SecurityBadge::Meta
+    ) {
+        revert(Cep85Error::InvalidScopedBadge);
+    }
+    if end_id < start_id {
+        revert(Cep85Error::InvalidIdRange);
+    }
+
+    (account, badge, start_id, end_id)
+}
+
+/// Records the approval of `admin` and executes the proposal once the admin threshold is reached.
+fn add_proposal_approval(proposal_id: u32, admin: Key) {
+    let (_, expires_at) = read_pending_proposal_state(proposal_id);
+    // Approvals are recounted against the current admins, so that the approval of a key that
+    // lost its admin badge since no longer counts.
+    let approvals = write_proposal_approval(proposal_id, &admin)
+        .iter()
+        .filter(|approver| {
+            read_security_roles(approver)
+                .unwrap_or_default()
+                .contains(SecurityBadge::Admin)
+        })
+        .count() as u32;
+
+    record_event_dictionary(Event::ApproveProposal(ApproveProposal {
+        proposal_id,
+        admin,
+        approvals,
+    }));
+
+    if approvals < read_admin_threshold() {
+        write_proposal_state(proposal_id, ProposalStatus::Pending, approvals, expires_at);
+        return;
+    }
+
+    // Mark the proposal as executed first so that the action can not execute it again.
+    write_proposal_state(proposal_id, ProposalStatus::Executed, approvals, expires_at);
+
+    let (action, action_args) = read_proposal_action(proposal_id);
+    // Approved timelocked actions still wait for the timelock delay before being executed.
+    if read_timelock_delay() > 0 && TIMELOCKED_ACTIONS.contains(&action.as_str()) {
+        add_operation(action, action_args, admin);
+    } else {
+        call_own_action(&action, &action_args);
+    }
+
+    record_event_dictionary(Event::ExecuteProposal(ExecuteProposal { proposal_id }));
+}
+
+/// Queues a call to the admin `action` entry point, executable once the timelock delay has
+/// elapsed, and returns the id of the operation.
+fn add_operation(action: String, action_args: Bytes, proposer: Key) -> u32 {
+    let operation_id: u32 = get_stored_value_with_user_errors(
+        OPERATION_COUNT,
+        Cep85Error::MissingOperationCount,
+        Cep85Error::InvalidOperationCount,
+    );
+    let operation_count = operation_id
+        .checked_add(1)
+        .unwrap_or_revert_with(Cep85Error::Overflow);
+    put_key(OPERATION_COUNT, storage::new_uref(operation_count).into());
+
+    let executable_at = u64::from(runtime::get_blocktime())
+        .checked_add(read_timelock_delay())
+        .unwrap_or_revert_with(Cep85Error::Overflow);
+
+    write_operation_action(operation_id, &action, &action_args);
+    write_operation_state(operation_id, OperationStatus::Queued, executable_at);
+
+    record_event_dictionary(Event::QueueOperation(QueueOperation {
+        operation_id,
+        proposer,
+        action,
+        action_args,
+        executable_at,
+    }));
+
+    operation_id
+}
+
+/// Calls the admin `action` entry point of this contract with the serialized `action_args`.
+fn call_own_action(action: &str, action_args: &Bytes) {
+    let (action_args, _) =
+        RuntimeArgs::from_bytes(action_args).unwrap_or_revert_with(Cep85Error::InvalidActionArgs);
+    let contract_hash = ContractHash::from(
+        get_key(ARG_CONTRACT_HASH)
+            .unwrap_or_revert_with(Cep85Error::MissingContractHash)
+            .into_hash()
+            .unwrap_or_revert_with(Cep85Error::InvalidContractHash),
+    );
+    call_contract::<()>(contract_hash, action, action_args);
+}
+
+/// Charges a minted `amount` of token `id` to the quotas of the caller and of its package.
+fn spend_caller_mint_quota(id: &U256, amount: &U256) {
+    let (caller, caller_package) = get_verified_caller();
+    spend_mint_quota(&caller, id, amount);
+    if let Some(caller_package) = caller_package {
+        spend_mint_quota(&caller_package, id, amount);
+    }
+}
+
+fn check_not_frozen(account: &Key) {
+    if read_frozen(account) {
+        revert(Cep85Error::AccountFrozen);
+    }
+}
+
 fn before_token_transfer(
     operator: &Key,
     from: &Key,
@@ -1214,8 +3341,262 @@ fn before_token_transfer(
         }
     }
 
+    for id in ids {
+        if read_id_lock(id) {
+            runtime::revert(Cep85Error::TokenIdLocked);
+        }
+    }
+
+    check_not_frozen(operator);
+    check_not_frozen(from);
+    check_not_frozen(to);
+
+    call_transfer_filters(operator, from, to, ids, amounts, data);
+}
+
+/// Creates freshly allocated ids as non-fungible tokens and mints one of each to `recipient`,
+/// returning the caller.
+fn mint_allocated_ids(recipient: &Key, ids: &[U256]) -> Key {
+    sec_check_ids(vec![SecurityBadge::Admin, SecurityBadge::Minter], ids);
+
+    check_not_paused();
+
+    let amount = U256::one();
+    let amounts = vec![amount; ids.len()];
+    let (caller, _) = get_verified_caller();
+    check_mint_burn_filters(&caller, &NULL_KEY, recipient, ids, &amounts);
+
+    for id in ids.iter() {
+        if is_type_item_id(id) {
+            write_type_item(id);
+        }
+        write_token_kind(id, TokenKind::NonFungible);
+        write_total_supply_of(id, &amount);
+        spend_caller_mint_quota(id, &amount);
+        write_supply_of(id, &amount);
+        write_balance_to(recipient, id, &amount);
+    }
+
+    after_token_transfer(&caller, &NULL_KEY, recipient, ids, &amounts, None);
+    caller
+}
+
+/// Creates `id` as a non-fungible item on its first mint when it is an item of a created split
+/// id type.
+fn create_type_item(id: &U256) {
+    if is_type_item_id(id) && read_total_supply_of(id).unwrap_or_default().is_zero() {
+        write_type_item(id);
+        write_token_kind(id, TokenKind::NonFungible);
+        write_total_supply_of(id, &U256::one());
+    }
+}
+
+/// Reads the optional metadata arguments of `mint`, `mint_next` and `create_token`: when a `name`
+/// is given, `symbol` and `decimals` are required too.
+fn get_optional_metadata_args() -> Option<TokenMetadata> {
+    let name: Option<String> =
+        get_optional_named_arg_with_user_errors(ARG_NAME, Cep85Error::InvalidTokenName);
+    name.map(get_metadata_args)
+}
+
+/// Reads the `symbol` and `decimals` arguments completing the metadata of a token `name`.
+fn get_metadata_args(name: String) -> TokenMetadata {
+    let symbol: String = get_named_arg_with_user_errors(
+        ARG_SYMBOL,
+        Cep85Error::MissingTokenSymbol,
+        Cep85Error::InvalidTokenSymbol,
+    )
+    .unwrap_or_revert();
+
+    let decimals: u8 = get_named_arg_with_user_errors(
+        ARG_DECIMALS,
+        Cep85Error::MissingTokenDecimals,
+        Cep85Error::InvalidTokenDecimals,
+    )
+    .unwrap_or_revert();
+
+    (name, symbol, decimals)
+}
+
+/// Batch version of `get_optional_metadata_args`, reading one name, symbol and decimals per id
+/// from the `names`, `symbols` and `decimals_list` arguments.
+fn get_optional_batch_metadata_args(count: usize) -> Option<Vec<TokenMetadata>> {
+    let names: Vec<String> =
+        get_optional_named_arg_with_user_errors(ARG_NAMES, Cep85Error::InvalidTokenNames)?;
+
+    let symbols: Vec<String> = get_named_arg_with_user_errors(
+        ARG_SYMBOLS,
+        Cep85Error::MissingTokenSymbols,
+        Cep85Error::InvalidTokenSymbols,
+    )
+    .unwrap_or_revert();
+
+    let decimals_list: Vec<u8> = get_named_arg_with_user_errors(
+        ARG_DECIMALS_LIST,
+        Cep85Error::MissingTokenDecimalsList,
+        Cep85Error::InvalidTokenDecimalsList,
+    )
+    .unwrap_or_revert();
+
+    if names.len() != count || symbols.len() != count || decimals_list.len() != count {
+        revert(Cep85Error::MismatchParamsLength);
+    }
+
+    Some(
+        names
+            .into_iter()
+            .zip(symbols)
+            .zip(decimals_list)
+            .map(|((name, symbol), decimals)| (name, symbol, decimals))
+            .collect(),
+    )
+}
+
+/// Sets the metadata of each of the `ids` when the optional batch metadata arguments are given.
+fn set_optional_batch_metadata(ids: &[U256]) {
+    if let Some(metadata_list) = get_optional_batch_metadata_args(ids.len()) {
+        for (id, metadata) in ids.iter().zip(metadata_list) {
+            set_metadata(*id, metadata);
+        }
+    }
+}
+
+fn set_metadata(id: U256, metadata: TokenMetadata) {
+    write_metadata_of(&id, &metadata);
+    let (name, symbol, decimals) = metadata;
+    record_event_dictionary(Event::MetadataUpdate(MetadataUpdate {
+        id,
+        name,
+        symbol,
+        decimals,
+    }));
+}
+
+/// Reverts when the collection only mints created ids and `id` was not created with
+/// `create_token`.
+fn check_registered_id(id: &U256) {
+    if get_stored_value_with_user_errors::<bool>(
+        ARG_REGISTERED_IDS_ONLY,
+        Cep85Error::MissingRegisteredIdsOnlyFlag,
+        Cep85Error::InvalidRegisteredIdsOnlyFlag,
+    ) && read_token_kind(id).is_none()
+    {
+        revert(Cep85Error::TokenNotCreated);
+    }
+}
+
+/// Reverts when `total_supply` is not allowed for an id of the given kind.
+fn check_token_kind_total_supply(token_kind: Option<TokenKind>, total_supply: &U256) {
+    if token_kind == Some(TokenKind::NonFungible) && *total_supply != U256::one() {
+        revert(Cep85Error::InvalidTotalSupply);
+    }
+}
+
+/// Registers the kind and total supply of an id, which can be one already minted before tokens
+/// could be created as long as the total supply covers its current supply.
+fn register_token(id: &U256, token_kind: u8, total_supply: &U256) {
+    let token_kind = TokenKind::try_from(token_kind).unwrap_or_revert();
+
+    if read_token_kind(id).is_some() {
+        revert(Cep85Error::TokenAlreadyCreated);
+    }
+    if total_supply.is_zero() || *total_supply < read_supply_of(id) {
+        revert(Cep85Error::InvalidTotalSupply);
+    }
+    check_token_kind_total_supply(Some(token_kind), total_supply);
+
+    write_token_kind(id, token_kind);
+    write_total_supply_of(id, total_supply);
+}
+
+/// Calls the transfer filters on a mint or burn when the mint and burn filter modality is
+/// enabled.
+fn check_mint_burn_filters(operator: &Key, from: &Key, to: &Key, ids: &[U256], amounts: &[U256]) {
+    if get_stored_value_with_user_errors::<bool>(
+        ARG_ENABLE_MINT_BURN_FILTER,
+        Cep85Error::MissingEnableMintBurnFilterFlag,
+        Cep85Error::InvalidEnableMintBurnFilterFlag,
+    ) {
+        call_transfer_filters(operator, from, to, ids, amounts, None);
+    }
+}
+
+/// Calls every transfer filter that applies to at least one of the ids, reverting as soon as one
+/// denies the transfer.
+fn call_transfer_filters(
+    operator: &Key,
+    from: &Key,
+    to: &Key,
+    ids: &[U256],
+    amounts: &[U256],
+    data: Option<Bytes>,
+) {
+    let mut transfer_filters = read_transfer_filters();
+    // The filter set at installation or with `set_transfer_filter` runs first.
     if let Some(filter_contract) = get_transfer_filter_contract() {
         if let Some(filter_method) = get_transfer_filter_method() {
+            transfer_filters.insert(0, (filter_contract, filter_method, None));
+        }
+    }
+
+    let enable_filter_reasons = get_stored_value_with_user_errors::<bool>(
+        ARG_ENABLE_FILTER_REASONS,
+        Cep85Error::MissingEnableFilterReasonsFlag,
+        Cep85Error::InvalidEnableFilterReasonsFlag,
+    );
+
+    for transfer_filter in &transfer_filters {
+        let (filter_ids, filter_amounts) = filter_ids_in_scope(transfer_filter, ids, amounts);
+        if filter_ids.is_empty() {
+            continue;
+        }
+
+        let mut args = RuntimeArgs::new();
+        args.insert(ARG_OPERATOR, *operator)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_FROM, *from)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_TO, *to)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_IDS, filter_ids)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_AMOUNTS, filter_amounts)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_DATA, data.clone())
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+
+        let (filter_contract, filter_method, _) = transfer_filter;
+        let value = call_contract::<u8>(*filter_contract, filter_method, args);
+        let result = if enable_filter_reasons {
+            TransferFilterContractResult::from(value)
+        } else {
+            TransferFilterContractResult::from_legacy(value)
+        };
+
+        match result {
+            TransferFilterContractResult::DenyTransfer => {
+                revert(Cep85Error::TransferFilterContractDenied)
+            }
+            TransferFilterContractResult::DenyTransferWithReason(reason) => revert(ApiError::User(
+                TRANSFER_FILTER_REASON_ERROR_OFFSET + u16::from(reason),
+            )),
+            TransferFilterContractResult::ProceedTransfer => {}
+        }
+    }
+}
+
+/// Notifies the after transfer hook contract, if any, once balances have changed. `from` and `to`
+/// are set to `NULL_KEY` for mints and burns.
+fn after_token_transfer(
+    operator: &Key,
+    from: &Key,
+    to: &Key,
+    ids: &[U256],
+    amounts: &[U256],
+    data: Option<Bytes>,
+) {
+    if let Some(hook_contract) = get_after_transfer_hook_contract() {
+        if let Some(hook_method) = get_after_transfer_hook_method() {
             let mut args = RuntimeArgs::new();
             args.insert(ARG_OPERATOR, *operator)
                 .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
@@ -1223,19 +3604,155 @@ fn before_token_transfer(
                 .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
             args.insert(ARG_TO, *to)
                 .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
-            args.insert(ARG_IDS, ids.to_owned())
+            args.insert(ARG_IDS, ids.to_vec())
                 .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
-            args.insert(ARG_AMOUNTS, amounts.to_owned())
+            args.insert(ARG_AMOUNTS, amounts.to_vec())
                 .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
             args.insert(ARG_DATA, data)
                 .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
 
-            let result: TransferFilterContractResult =
-                call_contract::<u8>(filter_contract, &filter_method, args).into();
+            call_contract::<()>(hook_contract, &hook_method, args);
+        }
+    }
+}
+
+/// Reads the spender, id and amount arguments of the allowance entry points, the caller being the
+/// owner.
+fn get_allowance_args() -> (Key, Key, U256, U256) {
+    let spender: Key = get_named_arg_with_user_errors(
+        ARG_SPENDER,
+        Cep85Error::MissingSpender,
+        Cep85Error::InvalidSpender,
+    )
+    .unwrap_or_revert();
+
+    let (caller, caller_package) = get_verified_caller();
+
+    // If caller tries to approve itself as spender that's probably a mistake and we revert.
+    let is_self_approval: bool = match caller_package {
+        Some(caller_package) => spender == caller_package || spender == caller,
+        None => spender == caller,
+    };
+
+    if is_self_approval {
+        runtime::revert(Cep85Error::SelfSpenderApproval);
+    }
+
+    let id: U256 =
+        get_named_arg_with_user_errors(ARG_ID, Cep85Error::MissingId, Cep85Error::InvalidId)
+            .unwrap_or_revert();
+
+    let amount: U256 = get_named_arg_with_user_errors(
+        ARG_AMOUNT,
+        Cep85Error::MissingAmount,
+        Cep85Error::InvalidAmount,
+    )
+    .unwrap_or_revert();
+
+    (caller, spender, id, amount)
+}
 
-            if TransferFilterContractResult::DenyTransfer == result {
-                revert(Cep85Error::TransferFilterContractDenied);
+/// Consumes `amount` from the allowance granted by `owner` on token `id` to the caller or to its
+/// package. Reverts with `NotApproved` when neither has any allowance.
+fn spend_allowance(
+    owner: &Key,
+    caller: &Key,
+    caller_package: Option<Key>,
+    id: &U256,
+    amount: &U256,
+) {
+    let mut is_insufficient = false;
+    for spender in [Some(*caller), caller_package].into_iter().flatten() {
+        let allowance = read_allowance_of(owner, &spender, id);
+        if allowance.is_zero() {
+            continue;
+        }
+        match allowance.checked_sub(*amount) {
+            Some(new_allowance) => {
+                write_allowance_to(owner, &spender, id, &new_allowance);
+                return;
             }
+            None => is_insufficient = true,
+        }
+    }
+
+    if is_insufficient {
+        revert(Cep85Error::InsufficientAllowance);
+    }
+    revert(Cep85Error::NotApproved);
+}
+
+/// Returns the contract to notify when `to` is a contract and the receiver check is enabled.
+fn get_token_receiver(to: &Key) -> Option<ContractHash> {
+    if !get_stored_value_with_user_errors::<bool>(
+        ARG_ENABLE_RECEIVER_CHECK,
+        Cep85Error::MissingEnableReceiverCheckFlag,
+        Cep85Error::InvalidEnableReceiverCheckFlag,
+    ) {
+        return None;
+    }
+    to.into_hash().map(ContractHash::new)
+}
+
+/// Calls `on_cep85_received` on a recipient contract and reverts unless it accepts the tokens.
+/// `from` is `None` for mints.
+fn do_safe_transfer_acceptance_check(
+    operator: &Key,
+    from: Option<Key>,
+    to: &Key,
+    id: &U256,
+    amount: &U256,
+    data: Option<Bytes>,
+) {
+    if let Some(receiver) = get_token_receiver(to) {
+        let mut args = RuntimeArgs::new();
+        args.insert(ARG_OPERATOR, *operator)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_FROM, from)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_ID, *id)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_AMOUNT, *amount)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_DATA, data)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+
+        let result: TokenReceiverResult =
+            call_contract::<u8>(receiver, ENTRY_POINT_ON_CEP85_RECEIVED, args).into();
+
+        if TokenReceiverResult::AcceptTransfer != result {
+            revert(Cep85Error::TransferRejectedByReceiver);
+        }
+    }
+}
+
+/// Batch version of `do_safe_transfer_acceptance_check` calling `on_cep85_batch_received`.
+fn do_safe_batch_transfer_acceptance_check(
+    operator: &Key,
+    from: Option<Key>,
+    to: &Key,
+    ids: &[U256],
+    amounts: &[U256],
+    data: Option<Bytes>,
+) {
+    if let Some(receiver) = get_token_receiver(to) {
+        let mut args = RuntimeArgs::new();
+        args.insert(ARG_OPERATOR, *operator)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_FROM, from)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_IDS, ids.to_owned())
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_AMOUNTS, amounts.to_owned())
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+        args.insert(ARG_DATA, data)
+            .unwrap_or_revert_with(Cep85Error::FailedToCreateArg);
+
+        let result: TokenReceiverResult =
+            call_contract::<u8>(receiver, ENTRY_POINT_ON_CEP85_BATCH_RECEIVED, args).into();
+
+        if TokenReceiverResult::AcceptTransfer != result {
+            revert(Cep85Error::TransferRejectedByReceiver);
         }
     }
 }
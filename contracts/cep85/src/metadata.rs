@@ -0,0 +1,20 @@
+//! Implementation of per-id metadata.
+use alloc::string::{String, ToString};
+use casper_types::U256;
+
+use crate::{
+    constants::DICT_TOKEN_METADATA,
+    utils::{get_dictionary_value_from_key, set_dictionary_value_for_key},
+};
+
+/// Name, symbol and decimals of a token id.
+pub type TokenMetadata = (String, String, u8);
+
+pub fn write_metadata_of(id: &U256, metadata: &TokenMetadata) {
+    set_dictionary_value_for_key(DICT_TOKEN_METADATA, &id.to_string(), metadata)
+}
+
+/// Returns the metadata of `id`, `None` when it was never set.
+pub fn read_metadata_of(id: &U256) -> Option<TokenMetadata> {
+    get_dictionary_value_from_key(DICT_TOKEN_METADATA, &id.to_string())
+}
@@ -26,17 +26,78 @@ impl TryFrom<u8> for EventsMode {
     }
 }
 
+/// Who can burn tokens: accounts holding the `Admin` or `Burner` badge, the owner of the tokens or
+/// one of its operators, or only callers that are both.
 #[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum BurnMode {
+    RoleGated = 0,
+    OwnerOrOperator = 1,
+    Both = 2,
+}
+
+impl TryFrom<u8> for BurnMode {
+    type Error = Cep85Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BurnMode::RoleGated),
+            1 => Ok(BurnMode::OwnerOrOperator),
+            2 => Ok(BurnMode::Both),
+            _ => Err(Cep85Error::InvalidBurnMode),
+        }
+    }
+}
+
+/// Kind of a token id registered with `create_token`. Fungible and semi-fungible ids accept any
+/// positive total supply, non-fungible ids a total supply of exactly one.
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum TokenKind {
+    Fungible = 0,
+    NonFungible = 1,
+    SemiFungible = 2,
+}
+
+impl TryFrom<u8> for TokenKind {
+    type Error = Cep85Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TokenKind::Fungible),
+            1 => Ok(TokenKind::NonFungible),
+            2 => Ok(TokenKind::SemiFungible),
+            _ => Err(Cep85Error::InvalidTokenKind),
+        }
+    }
+}
+
+/// Value returned by a transfer filter method, represented as a u8: `0` denies the transfer, `1`
+/// lets it proceed and any other value denies it with that value as reason code. Reason codes are
+/// only honored when the filter reasons modality is enabled, see `from_legacy`.
 #[non_exhaustive]
 #[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
 pub enum TransferFilterContractResult {
     #[default]
-    DenyTransfer = 0,
+    DenyTransfer,
     ProceedTransfer,
+    DenyTransferWithReason(u8),
 }
 
 impl From<u8> for TransferFilterContractResult {
     fn from(value: u8) -> Self {
+        match value {
+            0 => TransferFilterContractResult::DenyTransfer,
+            1 => TransferFilterContractResult::ProceedTransfer,
+            reason => TransferFilterContractResult::DenyTransferWithReason(reason),
+        }
+    }
+}
+
+impl TransferFilterContractResult {
+    /// Decodes the value returned by a filter written before reason codes existed, for which any
+    /// value other than `0` lets the transfer proceed.
+    pub fn from_legacy(value: u8) -> Self {
         match value {
             0 => TransferFilterContractResult::DenyTransfer,
             _ => TransferFilterContractResult::ProceedTransfer,
@@ -44,6 +105,16 @@ impl From<u8> for TransferFilterContractResult {
     }
 }
 
+impl From<TransferFilterContractResult> for u8 {
+    fn from(result: TransferFilterContractResult) -> Self {
+        match result {
+            TransferFilterContractResult::DenyTransfer => 0,
+            TransferFilterContractResult::ProceedTransfer => 1,
+            TransferFilterContractResult::DenyTransferWithReason(reason) => reason,
+        }
+    }
+}
+
 impl CLTyped for TransferFilterContractResult {
     fn cl_type() -> casper_types::CLType {
         CLType::U8
@@ -54,14 +125,56 @@ impl FromBytes for TransferFilterContractResult {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
         match bytes.split_first() {
             None => Err(casper_types::bytesrepr::Error::EarlyEndOfStream),
-            Some((byte, rem)) => match TransferFilterContractResult::try_from(*byte) {
-                Ok(kind) => Ok((kind, rem)),
-                Err(_) => Err(casper_types::bytesrepr::Error::EarlyEndOfStream),
-            },
+            Some((byte, rem)) => Ok((TransferFilterContractResult::from(*byte), rem)),
         }
     }
 }
 impl ToBytes for TransferFilterContractResult {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        Ok(vec![u8::from(*self)])
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+    }
+}
+
+/// Value returned by the `on_cep85_received` and `on_cep85_batch_received` entry points of a
+/// recipient contract. Any value other than `AcceptTransfer` is treated as a rejection.
+#[repr(u8)]
+#[non_exhaustive]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum TokenReceiverResult {
+    #[default]
+    RejectTransfer = 0,
+    AcceptTransfer = 1,
+}
+
+impl From<u8> for TokenReceiverResult {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => TokenReceiverResult::AcceptTransfer,
+            _ => TokenReceiverResult::RejectTransfer,
+        }
+    }
+}
+
+impl CLTyped for TokenReceiverResult {
+    fn cl_type() -> casper_types::CLType {
+        CLType::U8
+    }
+}
+
+impl FromBytes for TokenReceiverResult {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        match bytes.split_first() {
+            None => Err(casper_types::bytesrepr::Error::EarlyEndOfStream),
+            Some((byte, rem)) => Ok((TokenReceiverResult::from(*byte), rem)),
+        }
+    }
+}
+
+impl ToBytes for TokenReceiverResult {
     fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
         Ok(vec![*self as u8])
     }
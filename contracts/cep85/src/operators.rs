@@ -1,23 +1,41 @@
 //! Implementation of allowances.
 
+use casper_contract::contract_api::runtime;
 use casper_types::Key;
 
 use crate::{
-    constants::DICT_OPERATORS,
+    constants::{DICT_OPERATORS, DICT_OPERATOR_EXPIRIES},
     utils::{
         get_dictionary_value_from_key, make_dictionary_item_key, set_dictionary_value_for_key,
     },
 };
 
-pub fn write_operator(&owner: &Key, &operator: &Key, approved: bool) {
-    set_dictionary_value_for_key(
-        DICT_OPERATORS,
-        &make_dictionary_item_key(&owner, &operator),
-        &approved,
-    )
+/// Writes the approval of `operator` over all tokens of `owner`, optionally limited to a block
+/// time (in milliseconds) after which the approval is considered revoked.
+pub fn write_operator(&owner: &Key, &operator: &Key, approved: bool, expires_at: Option<u64>) {
+    let dictionary_item_key = make_dictionary_item_key(&owner, &operator);
+    set_dictionary_value_for_key(DICT_OPERATORS, &dictionary_item_key, &approved);
+    set_dictionary_value_for_key(DICT_OPERATOR_EXPIRIES, &dictionary_item_key, &expires_at)
 }
 
+/// Reads the approval of `spender` over all tokens of `owner`. Expired approvals are read as
+/// revoked.
 pub fn read_operator(&owner: &Key, &spender: &Key) -> bool {
-    get_dictionary_value_from_key(DICT_OPERATORS, &make_dictionary_item_key(&owner, &spender))
-        .unwrap_or_default()
+    let approved: bool =
+        get_dictionary_value_from_key(DICT_OPERATORS, &make_dictionary_item_key(&owner, &spender))
+            .unwrap_or_default();
+    approved
+        && match read_operator_expiry(&owner, &spender) {
+            Some(expires_at) => u64::from(runtime::get_blocktime()) < expires_at,
+            None => true,
+        }
+}
+
+/// Reads the block time at which the approval of `spender` expires, if any.
+pub fn read_operator_expiry(&owner: &Key, &spender: &Key) -> Option<u64> {
+    get_dictionary_value_from_key::<Option<u64>>(
+        DICT_OPERATOR_EXPIRIES,
+        &make_dictionary_item_key(&owner, &spender),
+    )
+    .flatten()
 }
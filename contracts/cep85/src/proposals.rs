@@ -0,0 +1,129 @@
+//! Implementation of multi-signature admin proposals.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_contract::{
+    contract_api::runtime::{self, revert},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::Bytes, Key};
+
+use crate::{
+    constants::{
+        DICT_PROPOSALS, DICT_PROPOSAL_APPROVALS, DICT_PROPOSAL_STATES,
+        ENTRY_POINT_ADD_TRANSFER_FILTER, ENTRY_POINT_BATCH_LOCK_IDS, ENTRY_POINT_BATCH_UNLOCK_IDS,
+        ENTRY_POINT_CHANGE_SECURITY, ENTRY_POINT_CREATE_TOKEN, ENTRY_POINT_CREATE_TOKEN_BATCH,
+        ENTRY_POINT_CREATE_TYPE, ENTRY_POINT_FREEZE_ACCOUNTS, ENTRY_POINT_GRANT_SCOPED_ROLE,
+        ENTRY_POINT_LOCK_ID, ENTRY_POINT_REMOVE_TRANSFER_FILTER, ENTRY_POINT_REVOKE_SCOPED_ROLE,
+        ENTRY_POINT_SET_AFTER_TRANSFER_HOOK, ENTRY_POINT_SET_ID_NAMESPACE,
+        ENTRY_POINT_SET_METADATA_OF, ENTRY_POINT_SET_MINT_QUOTA, ENTRY_POINT_SET_MODALITIES,
+        ENTRY_POINT_SET_TOTAL_SUPPLY_OF, ENTRY_POINT_SET_TOTAL_SUPPLY_OF_BATCH,
+        ENTRY_POINT_SET_TRANSFER_FILTER, ENTRY_POINT_SET_URI, ENTRY_POINT_UNFREEZE_ACCOUNTS,
+        ENTRY_POINT_UNLOCK_ID,
+    },
+    error::Cep85Error,
+    utils::{get_dictionary_value_from_key, set_dictionary_value_for_key},
+};
+
+/// Entry points that can be executed through a proposal. `propose_admin` is left out as it only
+/// hands the caller's own admin badge over, and `pause` and `unpause` as the pauser badge is
+/// meant for emergencies.
+pub const PROPOSAL_ACTIONS: [&str; 23] = [
+    ENTRY_POINT_ADD_TRANSFER_FILTER,
+    ENTRY_POINT_BATCH_LOCK_IDS,
+    ENTRY_POINT_BATCH_UNLOCK_IDS,
+    ENTRY_POINT_CHANGE_SECURITY,
+    ENTRY_POINT_CREATE_TOKEN,
+    ENTRY_POINT_CREATE_TOKEN_BATCH,
+    ENTRY_POINT_CREATE_TYPE,
+    ENTRY_POINT_FREEZE_ACCOUNTS,
+    ENTRY_POINT_GRANT_SCOPED_ROLE,
+    ENTRY_POINT_LOCK_ID,
+    ENTRY_POINT_REMOVE_TRANSFER_FILTER,
+    ENTRY_POINT_REVOKE_SCOPED_ROLE,
+    ENTRY_POINT_SET_AFTER_TRANSFER_HOOK,
+    ENTRY_POINT_SET_ID_NAMESPACE,
+    ENTRY_POINT_SET_METADATA_OF,
+    ENTRY_POINT_SET_MINT_QUOTA,
+    ENTRY_POINT_SET_MODALITIES,
+    ENTRY_POINT_SET_TOTAL_SUPPLY_OF,
+    ENTRY_POINT_SET_TOTAL_SUPPLY_OF_BATCH,
+    ENTRY_POINT_SET_TRANSFER_FILTER,
+    ENTRY_POINT_SET_URI,
+    ENTRY_POINT_UNFREEZE_ACCOUNTS,
+    ENTRY_POINT_UNLOCK_ID,
+];
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Pending = 0,
+    Executed = 1,
+    Cancelled = 2,
+}
+
+/// State of a proposal: its status, the number of its approvers that were still admins at the
+/// last approval and the block time at which it expires, if any.
+pub type ProposalState = (u8, u32, Option<u64>);
+
+pub fn write_proposal_action(proposal_id: u32, action: &str, action_args: &Bytes) {
+    set_dictionary_value_for_key(
+        DICT_PROPOSALS,
+        &proposal_id.to_string(),
+        &(action.to_string(), action_args.clone()),
+    )
+}
+
+pub fn read_proposal_action(proposal_id: u32) -> (String, Bytes) {
+    get_dictionary_value_from_key(DICT_PROPOSALS, &proposal_id.to_string())
+        .unwrap_or_revert_with(Cep85Error::ProposalNotFound)
+}
+
+pub fn write_proposal_state(
+    proposal_id: u32,
+    status: ProposalStatus,
+    approvals: u32,
+    expires_at: Option<u64>,
+) {
+    set_dictionary_value_for_key(
+        DICT_PROPOSAL_STATES,
+        &proposal_id.to_string(),
+        &(status as u8, approvals, expires_at),
+    )
+}
+
+/// Reads the state of a proposal that can still be approved or cancelled, reverting if it does
+/// not exist, is no longer pending or has expired.
+pub fn read_pending_proposal_state(proposal_id: u32) -> (u32, Option<u64>) {
+    let (status, approvals, expires_at): ProposalState =
+        get_dictionary_value_from_key(DICT_PROPOSAL_STATES, &proposal_id.to_string())
+            .unwrap_or_revert_with(Cep85Error::ProposalNotFound);
+    if status != ProposalStatus::Pending as u8 {
+        revert(Cep85Error::ProposalNotPending);
+    }
+    if let Some(expires_at) = expires_at {
+        if u64::from(runtime::get_blocktime()) >= expires_at {
+            revert(Cep85Error::ProposalExpired);
+        }
+    }
+    (approvals, expires_at)
+}
+
+/// Records the approval of `admin`, reverting if it already approved the proposal, and returns
+/// every key that approved the proposal so far.
+pub fn write_proposal_approval(proposal_id: u32, admin: &Key) -> Vec<Key> {
+    let mut approvers: Vec<Key> =
+        get_dictionary_value_from_key(DICT_PROPOSAL_APPROVALS, &proposal_id.to_string())
+            .unwrap_or_default();
+    if approvers.contains(admin) {
+        revert(Cep85Error::ProposalAlreadyApproved);
+    }
+    approvers.push(*admin);
+    set_dictionary_value_for_key(
+        DICT_PROPOSAL_APPROVALS,
+        &proposal_id.to_string(),
+        &approvers,
+    );
+    approvers
+}
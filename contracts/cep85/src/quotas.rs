@@ -0,0 +1,113 @@
+//! Implementation of minter quotas.
+use alloc::string::String;
+use casper_contract::{
+    contract_api::runtime::{self, revert},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, Key, U256};
+
+use crate::{
+    constants::{DICT_MINT_QUOTAS, DICT_MINT_QUOTA_USAGES},
+    error::Cep85Error,
+    utils::{
+        get_dictionary_value_from_key, make_dictionary_item_key, set_dictionary_value_for_key,
+    },
+};
+
+/// Limits of a mint quota: the amount mintable over the lifetime of the quota, the amount
+/// mintable per window and the length of a window in milliseconds. `None` limits are unlimited.
+pub type MintQuota = (Option<U256>, Option<U256>, u64);
+
+/// Usage of a mint quota: the amount minted over its lifetime, the block time at which the current
+/// window started and the amount minted within that window.
+type MintQuotaUsage = (U256, u64, U256);
+
+fn make_mint_quota_item_key(minter: &Key, id: Option<&U256>) -> String {
+    match id {
+        Some(id) => make_dictionary_item_key(minter, id),
+        None => hex::encode(minter.to_bytes().unwrap_or_revert()),
+    }
+}
+
+/// Writes the quota of `minter` for token `id`, or for the whole collection when `id` is `None`.
+pub fn write_mint_quota(minter: &Key, id: Option<&U256>, quota: &MintQuota) {
+    set_dictionary_value_for_key(
+        DICT_MINT_QUOTAS,
+        &make_mint_quota_item_key(minter, id),
+        quota,
+    )
+}
+
+/// Reads the usage of a quota, starting a new window if the current one has elapsed.
+fn read_mint_quota_usage(item_key: &str, window: u64) -> MintQuotaUsage {
+    let (minted, window_start, window_minted): MintQuotaUsage =
+        get_dictionary_value_from_key(DICT_MINT_QUOTA_USAGES, item_key).unwrap_or_default();
+    let now = u64::from(runtime::get_blocktime());
+    if now >= window_start.saturating_add(window) {
+        (minted, now, U256::zero())
+    } else {
+        (minted, window_start, window_minted)
+    }
+}
+
+fn min_remaining(a: Option<U256>, b: Option<U256>) -> Option<U256> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn remaining_of(quota: &MintQuota, usage: &MintQuotaUsage) -> Option<U256> {
+    let (lifetime_limit, window_limit, _) = *quota;
+    let (minted, _, window_minted) = *usage;
+    min_remaining(
+        lifetime_limit.map(|limit| limit.saturating_sub(minted)),
+        window_limit.map(|limit| limit.saturating_sub(window_minted)),
+    )
+}
+
+fn read_remaining_of(item_key: &str) -> Option<U256> {
+    let quota: MintQuota = get_dictionary_value_from_key(DICT_MINT_QUOTAS, item_key)?;
+    remaining_of(&quota, &read_mint_quota_usage(item_key, quota.2))
+}
+
+/// Returns the amount `minter` can still mint of token `id`, or of any token when `id` is `None`.
+/// Returns `None` if no quota limits it.
+pub fn read_remaining_mint_quota(minter: &Key, id: Option<&U256>) -> Option<U256> {
+    let collection_remaining = read_remaining_of(&make_mint_quota_item_key(minter, None));
+    let id_remaining =
+        id.and_then(|id| read_remaining_of(&make_mint_quota_item_key(minter, Some(id))));
+    min_remaining(collection_remaining, id_remaining)
+}
+
+fn spend(item_key: &str, amount: &U256) {
+    let quota: MintQuota = match get_dictionary_value_from_key(DICT_MINT_QUOTAS, item_key) {
+        Some(quota) => quota,
+        None => return,
+    };
+    let usage = read_mint_quota_usage(item_key, quota.2);
+    let remaining = match remaining_of(&quota, &usage) {
+        Some(remaining) => remaining,
+        None => return,
+    };
+    if *amount > remaining {
+        revert(Cep85Error::MintQuotaExceeded);
+    }
+    let (minted, window_start, window_minted) = usage;
+    set_dictionary_value_for_key(
+        DICT_MINT_QUOTA_USAGES,
+        item_key,
+        &(
+            minted.saturating_add(*amount),
+            window_start,
+            window_minted.saturating_add(*amount),
+        ),
+    );
+}
+
+/// Charges `amount` of token `id` to both the collection-wide and the id quota of `minter`,
+/// reverting with `MintQuotaExceeded` if either would be exceeded.
+pub fn spend_mint_quota(minter: &Key, id: &U256, amount: &U256) {
+    spend(&make_mint_quota_item_key(minter, None), amount);
+    spend(&make_mint_quota_item_key(minter, Some(id)), amount);
+}
@@ -0,0 +1,103 @@
+//! Implementation of roles scoped to token id ranges.
+use alloc::{format, string::String};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{bytesrepr::ToBytes, Key, U256};
+
+use crate::{
+    constants::{DICT_SCOPED_ROLES, DICT_SCOPED_ROLE_COUNTS},
+    security::SecurityBadge,
+    utils::{get_dictionary_value_from_key, set_dictionary_value_for_key},
+};
+
+fn make_scoped_role_count_key(account: &Key, badge: SecurityBadge) -> String {
+    format!(
+        "{}_{}",
+        hex::encode(account.to_bytes().unwrap_or_revert()),
+        badge as u8
+    )
+}
+
+fn make_scoped_role_item_key(account: &Key, badge: SecurityBadge, index: u32) -> String {
+    format!("{}_{}", make_scoped_role_count_key(account, badge), index)
+}
+
+fn read_scoped_role_count(account: &Key, badge: SecurityBadge) -> u32 {
+    get_dictionary_value_from_key(
+        DICT_SCOPED_ROLE_COUNTS,
+        &make_scoped_role_count_key(account, badge),
+    )
+    .unwrap_or_default()
+}
+
+fn read_scoped_role(account: &Key, badge: SecurityBadge, index: u32) -> (U256, U256) {
+    get_dictionary_value_from_key(
+        DICT_SCOPED_ROLES,
+        &make_scoped_role_item_key(account, badge, index),
+    )
+    .unwrap_or_revert()
+}
+
+fn find_scoped_role(
+    account: &Key,
+    badge: SecurityBadge,
+    start_id: &U256,
+    end_id: &U256,
+) -> Option<u32> {
+    (0..read_scoped_role_count(account, badge))
+        .find(|&index| read_scoped_role(account, badge, index) == (*start_id, *end_id))
+}
+
+/// Returns whether `account` holds `badge` for `id` through one of its scoped grants.
+pub fn has_scoped_role(account: &Key, badge: SecurityBadge, id: &U256) -> bool {
+    (0..read_scoped_role_count(account, badge)).any(|index| {
+        let (start_id, end_id) = read_scoped_role(account, badge, index);
+        start_id <= *id && *id <= end_id
+    })
+}
+
+/// Grants `badge` to `account` for the inclusive range `start_id..=end_id`.
+pub fn add_scoped_role(account: &Key, badge: SecurityBadge, start_id: &U256, end_id: &U256) {
+    if find_scoped_role(account, badge, start_id, end_id).is_some() {
+        return;
+    }
+    let count = read_scoped_role_count(account, badge);
+    set_dictionary_value_for_key(
+        DICT_SCOPED_ROLES,
+        &make_scoped_role_item_key(account, badge, count),
+        &(*start_id, *end_id),
+    );
+    set_dictionary_value_for_key(
+        DICT_SCOPED_ROLE_COUNTS,
+        &make_scoped_role_count_key(account, badge),
+        &(count + 1),
+    );
+}
+
+/// Removes the grant of `badge` to `account` for exactly `start_id..=end_id`, returning whether
+/// such a grant existed.
+pub fn remove_scoped_role(
+    account: &Key,
+    badge: SecurityBadge,
+    start_id: &U256,
+    end_id: &U256,
+) -> bool {
+    let index = match find_scoped_role(account, badge, start_id, end_id) {
+        Some(index) => index,
+        None => return false,
+    };
+    let last_index = read_scoped_role_count(account, badge) - 1;
+    if index != last_index {
+        let last_scoped_role = read_scoped_role(account, badge, last_index);
+        set_dictionary_value_for_key(
+            DICT_SCOPED_ROLES,
+            &make_scoped_role_item_key(account, badge, index),
+            &last_scoped_role,
+        );
+    }
+    set_dictionary_value_for_key(
+        DICT_SCOPED_ROLE_COUNTS,
+        &make_scoped_role_count_key(account, badge),
+        &last_index,
+    );
+    true
+}
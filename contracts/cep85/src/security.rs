@@ -1,20 +1,39 @@
 #[cfg(feature = "contract-support")]
-use alloc::collections::BTreeMap;
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+};
 use alloc::{vec, vec::Vec};
 #[cfg(feature = "contract-support")]
-use casper_contract::{contract_api::runtime::revert, unwrap_or_revert::UnwrapOrRevert};
-#[cfg(feature = "contract-support")]
-use casper_types::Key;
+use casper_contract::{
+    contract_api::{
+        runtime::{get_key, put_key, revert},
+        storage,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
     CLTyped,
 };
+#[cfg(feature = "contract-support")]
+use casper_types::{Key, U256};
 
 #[cfg(feature = "contract-support")]
 use crate::{
-    constants::DICT_SECURITY_BADGES,
+    constants::{
+        ADMIN_COUNT, ARG_ADMIN_THRESHOLD, ARG_PACKAGE_HASH, DICT_ADMIN_PROPOSALS,
+        DICT_ROLE_MEMBERS, DICT_ROLE_MEMBER_COUNTS, DICT_ROLE_MEMBER_INDEXES, DICT_SECURITY_BADGES,
+        DICT_SECURITY_ROLES,
+    },
     error::Cep85Error,
-    utils::{get_dictionary_value_from_key, get_verified_caller, set_dictionary_value_for_key},
+    scoped_roles::has_scoped_role,
+    timelock::read_timelock_delay,
+    utils::{
+        get_dictionary_value_from_key, get_stored_value_with_user_errors, get_verified_caller,
+        set_dictionary_value_for_key,
+    },
 };
 
 #[repr(u8)]
@@ -25,6 +44,8 @@ pub enum SecurityBadge {
     Burner = 2,
     Meta = 3,
     None = 4,
+    Pauser = 5,
+    Controller = 6,
 }
 
 impl CLTyped for SecurityBadge {
@@ -52,6 +73,8 @@ impl FromBytes for SecurityBadge {
                 2 => SecurityBadge::Burner,
                 3 => SecurityBadge::Meta,
                 4 => SecurityBadge::None,
+                5 => SecurityBadge::Pauser,
+                6 => SecurityBadge::Controller,
                 _ => return Err(bytesrepr::Error::LeftOverBytes),
             },
             &[],
@@ -59,38 +82,402 @@ impl FromBytes for SecurityBadge {
     }
 }
 
+/// Badges that can be held as roles, `SecurityBadge::None` standing for the absence of any.
+pub const ROLE_BADGES: [SecurityBadge; 6] = [
+    SecurityBadge::Admin,
+    SecurityBadge::Minter,
+    SecurityBadge::Burner,
+    SecurityBadge::Meta,
+    SecurityBadge::Pauser,
+    SecurityBadge::Controller,
+];
+
+/// Set of badges held by a key, stored as a bitset where bit `n` stands for the badge with
+/// discriminant `n`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct SecurityRoles(u8);
+
+impl SecurityRoles {
+    pub fn contains(&self, badge: SecurityBadge) -> bool {
+        badge != SecurityBadge::None && self.0 & (1 << badge as u8) != 0
+    }
+
+    /// Grants `badge`. Granting `SecurityBadge::None` removes every role.
+    pub fn insert(&mut self, badge: SecurityBadge) {
+        match badge {
+            SecurityBadge::None => self.0 = 0,
+            _ => self.0 |= 1 << badge as u8,
+        }
+    }
+
+    pub fn remove(&mut self, badge: SecurityBadge) {
+        if badge != SecurityBadge::None {
+            self.0 &= !(1 << badge as u8);
+        }
+    }
+
+    pub fn badges(&self) -> Vec<SecurityBadge> {
+        ROLE_BADGES
+            .into_iter()
+            .filter(|badge| self.contains(*badge))
+            .collect()
+    }
+}
+
+impl From<SecurityBadge> for SecurityRoles {
+    fn from(badge: SecurityBadge) -> Self {
+        let mut roles = SecurityRoles::default();
+        roles.insert(badge);
+        roles
+    }
+}
+
+impl CLTyped for SecurityRoles {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::U8
+    }
+}
+
+impl ToBytes for SecurityRoles {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for SecurityRoles {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (bits, remainder) = u8::from_bytes(bytes)?;
+        Ok((SecurityRoles(bits), remainder))
+    }
+}
+
+#[cfg(feature = "contract-support")]
+fn caller_has_badge(allowed_badge_list: &[SecurityBadge]) -> bool {
+    let (caller, caller_package) = get_verified_caller();
+    let caller_roles = read_security_roles(&caller);
+    let package_roles = caller_package.and_then(|package| read_security_roles(&package));
+
+    caller_roles.or(package_roles).map_or(false, |roles| {
+        allowed_badge_list
+            .iter()
+            .any(|badge| roles.contains(*badge))
+    })
+}
+
 #[cfg(feature = "contract-support")]
 pub fn sec_check(allowed_badge_list: Vec<SecurityBadge>) {
+    if !caller_has_badge(&allowed_badge_list) {
+        revert(Cep85Error::InsufficientRights);
+    }
+}
+
+/// Like `sec_check`, but also lets through callers holding one of the allowed badges scoped to
+/// every id in `ids`.
+#[cfg(feature = "contract-support")]
+pub fn sec_check_ids(allowed_badge_list: Vec<SecurityBadge>, ids: &[U256]) {
+    if caller_has_badge(&allowed_badge_list) {
+        return;
+    }
+
     let (caller, caller_package) = get_verified_caller();
-    let caller_badge = get_security_badge(&caller);
-    let package_badge = caller_package.and_then(|package| get_security_badge(&package));
+    let is_scoped = |id: &U256| {
+        allowed_badge_list.iter().any(|badge| {
+            has_scoped_role(&caller, *badge, id)
+                || caller_package.map_or(false, |package| has_scoped_role(&package, *badge, id))
+        })
+    };
+    if ids.is_empty() || !ids.iter().all(is_scoped) {
+        revert(Cep85Error::InsufficientRights);
+    }
+}
 
-    if let Some(badge) = caller_badge.or(package_badge) {
-        if allowed_badge_list.contains(&badge)
-            || (allowed_badge_list.contains(&SecurityBadge::Burner)
-                && badge == SecurityBadge::Burner)
-        {
-            return;
-        }
+/// Returns the number of admin approvals a proposal needs to be executed.
+#[cfg(feature = "contract-support")]
+pub fn read_admin_threshold() -> u32 {
+    get_stored_value_with_user_errors(
+        ARG_ADMIN_THRESHOLD,
+        Cep85Error::MissingAdminThreshold,
+        Cep85Error::InvalidAdminThreshold,
+    )
+}
+
+/// Like `sec_check`, or `sec_check_ids` when `ids` are given, for actions that can be executed
+/// through a proposal. The contract itself always passes, as it calls the action when executing
+/// an approved proposal, while the admin badge only allows calling the action directly when the
+/// admin threshold is one.
+#[cfg(feature = "contract-support")]
+pub fn sec_check_admin_action(mut allowed_badge_list: Vec<SecurityBadge>, ids: Option<&[U256]>) {
+    if is_self_call() {
+        return;
+    }
+
+    if read_admin_threshold() > 1 {
+        allowed_badge_list.retain(|badge| *badge != SecurityBadge::Admin);
+    }
+    match ids {
+        Some(ids) => sec_check_ids(allowed_badge_list, ids),
+        None => sec_check(allowed_badge_list),
+    }
+}
+
+/// Like `sec_check_admin_action` for the admin actions subject to the timelock. While a timelock
+/// delay is set, they can only be called by the contract itself when executing a queued
+/// operation.
+#[cfg(feature = "contract-support")]
+pub fn sec_check_timelocked_action() {
+    if read_timelock_delay() > 0 && !is_self_call() {
+        revert(Cep85Error::TimelockedAction);
     }
-    revert(Cep85Error::InsufficientRights);
+    sec_check_admin_action(vec![SecurityBadge::Admin], None);
 }
 
 #[cfg(feature = "contract-support")]
-fn get_security_badge(entity: &Key) -> Option<SecurityBadge> {
+fn is_self_call() -> bool {
+    // The package hash is only written at init, unlike the contract hash which is repointed on
+    // every upgrade.
+    let package_hash =
+        get_key(ARG_PACKAGE_HASH).unwrap_or_revert_with(Cep85Error::MissingPackageHash);
+    get_verified_caller().1 == Some(package_hash)
+}
+
+/// Returns the roles of `entity`, or `None` if it never had any badge.
+#[cfg(feature = "contract-support")]
+pub fn read_security_roles(entity: &Key) -> Option<SecurityRoles> {
+    let item_key = hex::encode(entity.to_bytes().unwrap_or_revert());
+    get_dictionary_value_from_key(DICT_SECURITY_ROLES, &item_key).or_else(|| {
+        // Contracts installed before roles were combined stored a single badge per key.
+        get_key(DICT_SECURITY_BADGES)?;
+        get_dictionary_value_from_key::<SecurityBadge>(DICT_SECURITY_BADGES, &item_key)
+            .map(SecurityRoles::from)
+    })
+}
+
+/// Adds `badge` to the roles of every key in `accounts`, on top of the roles already recorded in
+/// `roles_map` or stored for the key.
+#[cfg(feature = "contract-support")]
+pub fn grant_badge(
+    roles_map: &mut BTreeMap<Key, SecurityRoles>,
+    accounts: Vec<Key>,
+    badge: SecurityBadge,
+) {
+    for account_key in accounts {
+        roles_map
+            .entry(account_key)
+            .or_insert_with(|| read_security_roles(&account_key).unwrap_or_default())
+            .insert(badge);
+    }
+}
+
+/// Removes `badge` from the roles of every key in `accounts`.
+#[cfg(feature = "contract-support")]
+pub fn revoke_badge(
+    roles_map: &mut BTreeMap<Key, SecurityRoles>,
+    accounts: Vec<Key>,
+    badge: SecurityBadge,
+) {
+    for account_key in accounts {
+        roles_map
+            .entry(account_key)
+            .or_insert_with(|| read_security_roles(&account_key).unwrap_or_default())
+            .remove(badge);
+    }
+}
+
+/// Records `proposer` as the admin handing over to `candidate`, or clears the proposal with `None`.
+#[cfg(feature = "contract-support")]
+pub fn write_admin_proposal(candidate: &Key, proposer: Option<Key>) {
+    set_dictionary_value_for_key(
+        DICT_ADMIN_PROPOSALS,
+        &hex::encode(candidate.to_bytes().unwrap_or_revert()),
+        &proposer,
+    );
+}
+
+#[cfg(feature = "contract-support")]
+pub fn read_admin_proposal(candidate: &Key) -> Option<Key> {
+    get_dictionary_value_from_key::<Option<Key>>(
+        DICT_ADMIN_PROPOSALS,
+        &hex::encode(candidate.to_bytes().unwrap_or_revert()),
+    )
+    .flatten()
+}
+
+#[cfg(feature = "contract-support")]
+fn make_role_member_item_key(badge: SecurityBadge, index: u32) -> String {
+    format!("{}_{}", badge as u8, index)
+}
+
+/// Returns the number of keys currently holding `badge`.
+#[cfg(feature = "contract-support")]
+pub fn read_role_member_count(badge: SecurityBadge) -> u32 {
+    get_dictionary_value_from_key(DICT_ROLE_MEMBER_COUNTS, &(badge as u8).to_string())
+        .unwrap_or_default()
+}
+
+/// Returns up to `limit` keys holding `badge`, starting at position `start`.
+#[cfg(feature = "contract-support")]
+pub fn read_role_members(badge: SecurityBadge, start: u32, limit: u32) -> Vec<Key> {
+    let end = start
+        .saturating_add(limit)
+        .min(read_role_member_count(badge));
+    (start..end)
+        .filter_map(|index| {
+            get_dictionary_value_from_key(
+                DICT_ROLE_MEMBERS,
+                &make_role_member_item_key(badge, index),
+            )
+        })
+        .collect()
+}
+
+#[cfg(feature = "contract-support")]
+fn make_role_member_index_key(badge: SecurityBadge, user: &Key) -> String {
+    format!(
+        "{}_{}",
+        badge as u8,
+        hex::encode(user.to_bytes().unwrap_or_revert())
+    )
+}
+
+#[cfg(feature = "contract-support")]
+fn read_role_member_index(badge: SecurityBadge, user: &Key) -> Option<u32> {
     get_dictionary_value_from_key(
-        DICT_SECURITY_BADGES,
-        &hex::encode(entity.to_bytes().unwrap_or_revert()),
+        DICT_ROLE_MEMBER_INDEXES,
+        &make_role_member_index_key(badge, user),
     )
 }
 
 #[cfg(feature = "contract-support")]
-pub fn change_sec_badge(badge_map: &BTreeMap<Key, SecurityBadge>) {
-    for (&user, &badge) in badge_map {
+fn add_role_member(badge: SecurityBadge, user: &Key) {
+    let count = read_role_member_count(badge);
+    set_dictionary_value_for_key(
+        DICT_ROLE_MEMBERS,
+        &make_role_member_item_key(badge, count),
+        user,
+    );
+    set_dictionary_value_for_key(
+        DICT_ROLE_MEMBER_INDEXES,
+        &make_role_member_index_key(badge, user),
+        &count,
+    );
+    set_dictionary_value_for_key(
+        DICT_ROLE_MEMBER_COUNTS,
+        &(badge as u8).to_string(),
+        &(count + 1),
+    );
+}
+
+/// Removes `user` from the members of `badge` by moving the last member into its slot.
+#[cfg(feature = "contract-support")]
+fn remove_role_member(badge: SecurityBadge, user: &Key) {
+    // Badges granted before members were tracked have no index and are not listed.
+    let index = match read_role_member_index(badge, user) {
+        Some(index) => index,
+        None => return,
+    };
+    let last_index = match read_role_member_count(badge).checked_sub(1) {
+        Some(last_index) => last_index,
+        None => return,
+    };
+
+    if index != last_index {
+        let last_member: Key = get_dictionary_value_from_key(
+            DICT_ROLE_MEMBERS,
+            &make_role_member_item_key(badge, last_index),
+        )
+        .unwrap_or_revert();
+        set_dictionary_value_for_key(
+            DICT_ROLE_MEMBERS,
+            &make_role_member_item_key(badge, index),
+            &last_member,
+        );
         set_dictionary_value_for_key(
-            DICT_SECURITY_BADGES,
+            DICT_ROLE_MEMBER_INDEXES,
+            &make_role_member_index_key(badge, &last_member),
+            &index,
+        );
+    }
+    set_dictionary_value_for_key(
+        DICT_ROLE_MEMBER_COUNTS,
+        &(badge as u8).to_string(),
+        &last_index,
+    );
+}
+
+/// Lists the `users` among the members of `badge`, for badges granted before members were
+/// tracked. Keys already listed are skipped.
+///
+/// Reverts if one of the `users` does not hold `badge`.
+#[cfg(feature = "contract-support")]
+pub fn backfill_role_members(badge: SecurityBadge, users: &[Key]) {
+    for user in users {
+        if !read_security_roles(user)
+            .unwrap_or_default()
+            .contains(badge)
+        {
+            revert(Cep85Error::ListedKeyWithoutBadge);
+        }
+        if read_role_member_index(badge, user).is_none() {
+            add_role_member(badge, user);
+        }
+    }
+}
+
+/// Replaces the roles of every key in `roles_map`, keeping the admin count and role members in
+/// sync.
+///
+/// Reverts if the change would leave the contract without any admin, or with fewer admins than
+/// the admin threshold.
+#[cfg(feature = "contract-support")]
+pub fn change_sec_badge(roles_map: &BTreeMap<Key, SecurityRoles>) {
+    let mut admin_count = get_stored_value_with_user_errors::<u32>(
+        ADMIN_COUNT,
+        Cep85Error::MissingAdminCount,
+        Cep85Error::InvalidAdminCount,
+    );
+
+    for (&user, &roles) in roles_map {
+        let previous_roles = read_security_roles(&user).unwrap_or_default();
+        for badge in ROLE_BADGES {
+            if roles.contains(badge) {
+                if !previous_roles.contains(badge) || read_role_member_index(badge, &user).is_none()
+                {
+                    add_role_member(badge, &user);
+                }
+            } else if previous_roles.contains(badge) {
+                remove_role_member(badge, &user);
+            }
+        }
+
+        let was_admin = previous_roles.contains(SecurityBadge::Admin);
+        let is_admin = roles.contains(SecurityBadge::Admin);
+        if was_admin && !is_admin {
+            admin_count = admin_count
+                .checked_sub(1)
+                .unwrap_or_revert_with(Cep85Error::LastAdmin);
+        } else if !was_admin && is_admin {
+            admin_count = admin_count
+                .checked_add(1)
+                .unwrap_or_revert_with(Cep85Error::Overflow);
+        }
+
+        set_dictionary_value_for_key(
+            DICT_SECURITY_ROLES,
             &hex::encode(user.to_bytes().unwrap_or_revert()),
-            &badge,
+            &roles,
         );
     }
+
+    if admin_count == 0 {
+        revert(Cep85Error::LastAdmin);
+    }
+    if admin_count < read_admin_threshold() {
+        revert(Cep85Error::AdminCountBelowThreshold);
+    }
+
+    put_key(ADMIN_COUNT, storage::new_uref(admin_count).into());
 }
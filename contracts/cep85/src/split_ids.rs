@@ -0,0 +1,75 @@
+//! Implementation of split ids, packing a base type in the high 128 bits of an id and the index
+//! of a non-fungible item of that type in the low 128 bits.
+use alloc::string::{String, ToString};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{DICT_TOKEN_TYPES, DICT_TYPE_BALANCES, DICT_TYPE_ITEMS},
+    utils::{
+        get_dictionary_value_from_key, make_dictionary_item_key, replace_token_id_in_uri,
+        set_dictionary_value_for_key,
+    },
+};
+
+const ITEM_INDEX_MASK: U256 = U256([u64::MAX, u64::MAX, 0, 0]);
+
+/// Returns the base type id of `id`, that is `id` with its item index cleared.
+pub fn type_id_of(id: &U256) -> U256 {
+    *id & !ITEM_INDEX_MASK
+}
+
+pub fn item_index_of(id: &U256) -> U256 {
+    *id & ITEM_INDEX_MASK
+}
+
+/// Stores a created type along with its raw uri, empty when the type has none.
+pub fn write_token_type(type_id: &U256, raw_uri: &str) {
+    set_dictionary_value_for_key(DICT_TOKEN_TYPES, &type_id.to_string(), &raw_uri.to_string())
+}
+
+/// Returns the raw uri of a created type, `None` when the type was never created.
+pub fn read_token_type(type_id: &U256) -> Option<String> {
+    get_dictionary_value_from_key(DICT_TOKEN_TYPES, &type_id.to_string())
+}
+
+/// Returns whether `id` is the id of an item under a created type, as opposed to the base type
+/// id itself or an id of a type that does not exist.
+pub fn is_type_item_id(id: &U256) -> bool {
+    !item_index_of(id).is_zero() && read_token_type(&type_id_of(id)).is_some()
+}
+
+/// Resolves the uri of an item from the uri of its type, if any.
+pub fn read_type_uri_of(id: &U256) -> Option<String> {
+    if item_index_of(id).is_zero() {
+        return None;
+    }
+    read_token_type(&type_id_of(id))
+        .filter(|raw_uri| !raw_uri.is_empty())
+        .map(|raw_uri| replace_token_id_in_uri(&raw_uri, id))
+}
+
+/// Marks `id` as an item counted in the balances of its type.
+pub fn write_type_item(id: &U256) {
+    set_dictionary_value_for_key(DICT_TYPE_ITEMS, &id.to_string(), &true)
+}
+
+pub fn read_type_item(id: &U256) -> bool {
+    get_dictionary_value_from_key(DICT_TYPE_ITEMS, &id.to_string()).unwrap_or_default()
+}
+
+pub fn write_type_balance_to(account: &Key, type_id: &U256, amount: &U256) {
+    set_dictionary_value_for_key(
+        DICT_TYPE_BALANCES,
+        &make_dictionary_item_key(account, type_id),
+        amount,
+    )
+}
+
+/// Reads the number of items of a type held by an account.
+pub fn read_type_balance_from(account: &Key, type_id: &U256) -> U256 {
+    get_dictionary_value_from_key(
+        DICT_TYPE_BALANCES,
+        &make_dictionary_item_key(account, type_id),
+    )
+    .unwrap_or_default()
+}
@@ -0,0 +1,102 @@
+//! Implementation of timelocked admin operations.
+use alloc::string::{String, ToString};
+use casper_contract::{
+    contract_api::runtime::{self, revert},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::bytesrepr::Bytes;
+
+use crate::{
+    constants::{
+        ARG_TIMELOCK_DELAY, DICT_OPERATIONS, DICT_OPERATION_STATES,
+        ENTRY_POINT_ADD_TRANSFER_FILTER, ENTRY_POINT_CHANGE_SECURITY,
+        ENTRY_POINT_GRANT_SCOPED_ROLE, ENTRY_POINT_REMOVE_TRANSFER_FILTER,
+        ENTRY_POINT_SET_AFTER_TRANSFER_HOOK, ENTRY_POINT_SET_MINT_QUOTA,
+        ENTRY_POINT_SET_MODALITIES, ENTRY_POINT_SET_TOTAL_SUPPLY_OF,
+        ENTRY_POINT_SET_TOTAL_SUPPLY_OF_BATCH, ENTRY_POINT_SET_TRANSFER_FILTER,
+    },
+    error::Cep85Error,
+    utils::{
+        get_dictionary_value_from_key, get_stored_value_with_user_errors,
+        set_dictionary_value_for_key,
+    },
+};
+
+/// Entry points that can only be executed through a queued operation while a timelock delay is
+/// set. `revoke_scoped_role` is left out so that a scoped role can be withdrawn at once.
+pub const TIMELOCKED_ACTIONS: [&str; 10] = [
+    ENTRY_POINT_ADD_TRANSFER_FILTER,
+    ENTRY_POINT_CHANGE_SECURITY,
+    ENTRY_POINT_GRANT_SCOPED_ROLE,
+    ENTRY_POINT_REMOVE_TRANSFER_FILTER,
+    ENTRY_POINT_SET_AFTER_TRANSFER_HOOK,
+    ENTRY_POINT_SET_MINT_QUOTA,
+    ENTRY_POINT_SET_MODALITIES,
+    ENTRY_POINT_SET_TOTAL_SUPPLY_OF,
+    ENTRY_POINT_SET_TOTAL_SUPPLY_OF_BATCH,
+    ENTRY_POINT_SET_TRANSFER_FILTER,
+];
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OperationStatus {
+    Queued = 0,
+    Executed = 1,
+    Cancelled = 2,
+}
+
+/// State of an operation: its status and the block time from which it can be executed.
+pub type OperationState = (u8, u64);
+
+/// Returns the delay in milliseconds between queueing and executing an operation, zero when the
+/// timelock is disabled.
+pub fn read_timelock_delay() -> u64 {
+    get_stored_value_with_user_errors(
+        ARG_TIMELOCK_DELAY,
+        Cep85Error::MissingTimelockDelay,
+        Cep85Error::InvalidTimelockDelay,
+    )
+}
+
+pub fn write_operation_action(operation_id: u32, action: &str, action_args: &Bytes) {
+    set_dictionary_value_for_key(
+        DICT_OPERATIONS,
+        &operation_id.to_string(),
+        &(action.to_string(), action_args.clone()),
+    )
+}
+
+pub fn read_operation_action(operation_id: u32) -> (String, Bytes) {
+    get_dictionary_value_from_key(DICT_OPERATIONS, &operation_id.to_string())
+        .unwrap_or_revert_with(Cep85Error::OperationNotFound)
+}
+
+pub fn write_operation_state(operation_id: u32, status: OperationStatus, executable_at: u64) {
+    set_dictionary_value_for_key(
+        DICT_OPERATION_STATES,
+        &operation_id.to_string(),
+        &(status as u8, executable_at),
+    )
+}
+
+/// Reads the block time from which a queued operation can be executed, reverting if it does not
+/// exist or is no longer queued.
+pub fn read_queued_operation_state(operation_id: u32) -> u64 {
+    let (status, executable_at): OperationState =
+        get_dictionary_value_from_key(DICT_OPERATION_STATES, &operation_id.to_string())
+            .unwrap_or_revert_with(Cep85Error::OperationNotFound);
+    if status != OperationStatus::Queued as u8 {
+        revert(Cep85Error::OperationNotQueued);
+    }
+    executable_at
+}
+
+/// Like `read_queued_operation_state`, also reverting if the delay of the operation has not
+/// elapsed yet.
+pub fn read_ready_operation_state(operation_id: u32) -> u64 {
+    let executable_at = read_queued_operation_state(operation_id);
+    if u64::from(runtime::get_blocktime()) < executable_at {
+        revert(Cep85Error::OperationNotReady);
+    }
+    executable_at
+}
@@ -0,0 +1,20 @@
+//! Implementation of the token kind registry.
+use alloc::string::ToString;
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::U256;
+
+use crate::{
+    constants::DICT_TOKEN_KINDS,
+    modalities::TokenKind,
+    utils::{get_dictionary_value_from_key, set_dictionary_value_for_key},
+};
+
+pub fn write_token_kind(id: &U256, token_kind: TokenKind) {
+    set_dictionary_value_for_key(DICT_TOKEN_KINDS, &id.to_string(), &(token_kind as u8))
+}
+
+/// Returns the kind `id` was created with, `None` when it was never registered.
+pub fn read_token_kind(id: &U256) -> Option<TokenKind> {
+    get_dictionary_value_from_key::<u8>(DICT_TOKEN_KINDS, &id.to_string())
+        .map(|token_kind| TokenKind::try_from(token_kind).unwrap_or_revert())
+}
@@ -0,0 +1,41 @@
+//! Implementation of chained transfer filters.
+use alloc::{string::String, vec::Vec};
+use casper_contract::contract_api::{runtime::put_key, storage};
+use casper_types::{ContractHash, U256};
+
+use crate::{
+    constants::TRANSFER_FILTERS, error::Cep85Error, utils::get_stored_value_with_user_errors,
+};
+
+/// A transfer filter contract, the method called on it and the inclusive id range it applies to,
+/// or `None` when it applies to every id.
+pub type TransferFilter = (ContractHash, String, Option<(U256, U256)>);
+
+pub fn read_transfer_filters() -> Vec<TransferFilter> {
+    get_stored_value_with_user_errors(
+        TRANSFER_FILTERS,
+        Cep85Error::MissingTransferFilters,
+        Cep85Error::InvalidTransferFilters,
+    )
+}
+
+pub fn write_transfer_filters(transfer_filters: Vec<TransferFilter>) {
+    put_key(TRANSFER_FILTERS, storage::new_uref(transfer_filters).into());
+}
+
+/// Returns the ids, and their amounts, that `transfer_filter` applies to.
+pub fn filter_ids_in_scope(
+    transfer_filter: &TransferFilter,
+    ids: &[U256],
+    amounts: &[U256],
+) -> (Vec<U256>, Vec<U256>) {
+    match transfer_filter.2 {
+        Some((start_id, end_id)) => ids
+            .iter()
+            .zip(amounts.iter())
+            .filter(|(id, _)| start_id <= **id && **id <= end_id)
+            .map(|(id, amount)| (*id, *amount))
+            .unzip(),
+        None => (ids.to_vec(), amounts.to_vec()),
+    }
+}
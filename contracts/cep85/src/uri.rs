@@ -7,6 +7,7 @@ use core::ops::Deref;
 use crate::{
     constants::{ARG_URI, DICT_TOKEN_URI},
     error::Cep85Error,
+    split_ids::read_type_uri_of,
     utils::{
         get_dictionary_value_from_key, get_stored_value_with_user_errors, replace_token_id_in_uri,
         set_dictionary_value_for_key,
@@ -22,6 +23,7 @@ pub fn read_uri_of(id: Option<U256>) -> String {
     let uri: String = match id {
         Some(id) => get_dictionary_value_from_key(DICT_TOKEN_URI, &id.to_string())
             .filter(|value: &String| !value.is_empty())
+            .or_else(|| read_type_uri_of(&id))
             .unwrap_or_else(|| {
                 let global_uri: String = get_stored_value_with_user_errors(
                     ARG_URI,
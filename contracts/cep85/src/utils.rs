@@ -1,6 +1,9 @@
 #[cfg(feature = "contract-support")]
 use crate::{
-    constants::{ARG_TRANSFER_FILTER_CONTRACT, ARG_TRANSFER_FILTER_METHOD},
+    constants::{
+        ARG_AFTER_TRANSFER_HOOK_CONTRACT, ARG_AFTER_TRANSFER_HOOK_METHOD,
+        ARG_TRANSFER_FILTER_CONTRACT, ARG_TRANSFER_FILTER_METHOD,
+    },
     error::Cep85Error,
 };
 #[cfg(feature = "contract-support")]
@@ -60,6 +63,15 @@ pub fn get_verified_caller() -> (Key, Option<Key>) {
     }
 }
 
+/// Returns the hash of the contract currently executing.
+#[cfg(feature = "contract-support")]
+pub fn get_current_contract_hash() -> Key {
+    match *runtime::get_call_stack().last().unwrap_or_revert() {
+        CallStackElement::StoredContract { contract_hash, .. } => contract_hash.into(),
+        _ => runtime::revert(Cep85Error::InvalidContractHash),
+    }
+}
+
 #[cfg(feature = "contract-support")]
 pub fn get_stored_value<T>(name: &str) -> T
 where
@@ -165,7 +177,7 @@ pub fn get_dictionary_value_from_key<T: CLTyped + FromBytes>(
 }
 
 #[cfg(feature = "contract-support")]
-pub fn set_dictionary_value_for_key<T: CLTyped + ToBytes + Copy>(
+pub fn set_dictionary_value_for_key<T: CLTyped + ToBytes + Clone>(
     dictionary_name: &str,
     key: &str,
     value: &T,
@@ -175,7 +187,7 @@ pub fn set_dictionary_value_for_key<T: CLTyped + ToBytes + Copy>(
         Cep85Error::MissingStorageUref,
         Cep85Error::InvalidStorageUref,
     );
-    storage::dictionary_put::<T>(seed_uref, key, *value)
+    storage::dictionary_put::<T>(seed_uref, key, value.clone())
 }
 
 #[cfg(feature = "contract-support")]
@@ -196,6 +208,24 @@ pub fn get_transfer_filter_method() -> Option<String> {
     )
 }
 
+#[cfg(feature = "contract-support")]
+pub fn get_after_transfer_hook_contract() -> Option<ContractHash> {
+    get_stored_value_with_user_errors(
+        ARG_AFTER_TRANSFER_HOOK_CONTRACT,
+        Cep85Error::MissingAfterTransferHookContract,
+        Cep85Error::InvalidAfterTransferHookContract,
+    )
+}
+
+#[cfg(feature = "contract-support")]
+pub fn get_after_transfer_hook_method() -> Option<String> {
+    get_stored_value_with_user_errors(
+        ARG_AFTER_TRANSFER_HOOK_METHOD,
+        Cep85Error::MissingAfterTransferHookMethod,
+        Cep85Error::InvalidAfterTransferHookMethod,
+    )
+}
+
 pub fn replace_token_id_in_uri(raw_uri: &str, id: &U256) -> String {
     raw_uri.replace("{id}", &format!("{}", id))
 }
@@ -3,18 +3,34 @@ pub const CEP85_TEST_PACKAGE_NAME: &str = "cep85_test_contract_package_hash";
 pub const RESULT_KEY: &str = "result";
 
 pub const ARG_FILTER_CONTRACT_RETURN_VALUE: &str = "return_value";
+pub const ARG_RECEIVER_RETURN_VALUE: &str = "receiver_return_value";
 
+pub const ENTRY_POINT_AFTER_TRANSFER_HOOK_METHOD: &str = "after_transfer";
+pub const ENTRY_POINT_CHECK_ALLOWANCE: &str = "check_allowance";
+pub const ENTRY_POINT_CHECK_APPROVAL_EXPIRES_AT: &str = "check_approval_expires_at";
 pub const ENTRY_POINT_CHECK_BALANCE_OF: &str = "check_balance_of";
 pub const ENTRY_POINT_CHECK_BALANCE_OF_BATCH: &str = "check_balance_of_batch";
+pub const ENTRY_POINT_CHECK_BALANCE_OF_TYPE: &str = "check_balance_of_type";
+pub const ENTRY_POINT_CHECK_GET_SECURITY_BADGE: &str = "check_get_security_badge";
 pub const ENTRY_POINT_CHECK_IS_APPROVED_FOR_ALL: &str = "check_is_approved_for_all";
 pub const ENTRY_POINT_CHECK_IS_NON_FUNGIBLE: &str = "check_is_non_fungible";
+pub const ENTRY_POINT_CHECK_IS_PAUSED: &str = "check_is_paused";
+pub const ENTRY_POINT_CHECK_IS_ID_LOCKED: &str = "check_is_id_locked";
+pub const ENTRY_POINT_CHECK_IS_FROZEN: &str = "check_is_frozen";
+pub const ENTRY_POINT_CHECK_METADATA_OF: &str = "check_metadata_of";
+pub const ENTRY_POINT_CHECK_METADATA_OF_BATCH: &str = "check_metadata_of_batch";
 pub const ENTRY_POINT_CHECK_BATCH_TRANSFER_FROM: &str = "check_batch_transfer_from";
 pub const ENTRY_POINT_CHECK_TRANSFER_FROM: &str = "check_transfer_from";
+pub const ENTRY_POINT_CHECK_REMAINING_MINT_QUOTA: &str = "check_remaining_mint_quota";
+pub const ENTRY_POINT_CHECK_ROLE_MEMBER_COUNT: &str = "check_role_member_count";
+pub const ENTRY_POINT_CHECK_ROLE_MEMBERS: &str = "check_role_members";
 pub const ENTRY_POINT_CHECK_SUPPLY_OF: &str = "check_supply_of";
 pub const ENTRY_POINT_CHECK_SUPPLY_OF_BATCH: &str = "check_supply_of_batch";
 pub const ENTRY_POINT_CHECK_TOTAL_FUNGIBLE_SUPPLY: &str = "check_total_fungible_supply";
 pub const ENTRY_POINT_CHECK_TOTAL_SUPPLY_OF: &str = "check_total_supply_of";
 pub const ENTRY_POINT_CHECK_TOTAL_SUPPLY_OF_BATCH: &str = "check_total_supply_of_batch";
 pub const ENTRY_POINT_CHECK_URI: &str = "check_uri";
+pub const ENTRY_POINT_DENY_TRANSFER_FILTER_METHOD: &str = "deny_transfer";
 pub const ENTRY_POINT_SET_FILTER_CONTRACT_RETURN_VALUE: &str = "set_filter_contract_return_value";
+pub const ENTRY_POINT_SET_RECEIVER_RETURN_VALUE: &str = "set_receiver_return_value";
 pub const ENTRY_POINT_TRANSFER_FILTER_METHOD: &str = "can_transfer";
@@ -27,25 +27,39 @@ use casper_types::{
 };
 use cep85::{
     constants::{
-        ARG_ACCOUNT, ARG_ACCOUNTS, ARG_AMOUNTS, ARG_DATA, ARG_FROM, ARG_ID, ARG_IDS, ARG_OPERATOR,
-        ARG_OWNER, ARG_TO, ARG_TOKEN_CONTRACT, ENTRY_POINT_BALANCE_OF,
-        ENTRY_POINT_BALANCE_OF_BATCH, ENTRY_POINT_BATCH_BURN, ENTRY_POINT_BATCH_TRANSFER_FROM,
-        ENTRY_POINT_BURN, ENTRY_POINT_INIT, ENTRY_POINT_IS_APPROVED_FOR_ALL,
-        ENTRY_POINT_IS_NON_FUNGIBLE, ENTRY_POINT_SUPPLY_OF, ENTRY_POINT_SUPPLY_OF_BATCH,
-        ENTRY_POINT_TOTAL_FUNGIBLE_SUPPLY, ENTRY_POINT_TOTAL_SUPPLY_OF,
-        ENTRY_POINT_TOTAL_SUPPLY_OF_BATCH, ENTRY_POINT_TRANSFER_FROM, ENTRY_POINT_URI,
+        ARG_ACCOUNT, ARG_ACCOUNTS, ARG_AMOUNTS, ARG_BADGE, ARG_DATA, ARG_FROM, ARG_ID, ARG_IDS,
+        ARG_LIMIT, ARG_OPERATOR, ARG_OWNER, ARG_SPENDER, ARG_START, ARG_TO, ARG_TOKEN_CONTRACT,
+        ENTRY_POINT_ALLOWANCE, ENTRY_POINT_APPROVAL_EXPIRES_AT, ENTRY_POINT_BALANCE_OF,
+        ENTRY_POINT_BALANCE_OF_BATCH, ENTRY_POINT_BALANCE_OF_TYPE, ENTRY_POINT_BATCH_BURN,
+        ENTRY_POINT_BATCH_TRANSFER_FROM, ENTRY_POINT_BURN, ENTRY_POINT_GET_SECURITY_BADGE,
+        ENTRY_POINT_INIT, ENTRY_POINT_IS_APPROVED_FOR_ALL, ENTRY_POINT_IS_FROZEN,
+        ENTRY_POINT_IS_ID_LOCKED, ENTRY_POINT_IS_NON_FUNGIBLE, ENTRY_POINT_IS_PAUSED,
+        ENTRY_POINT_METADATA_OF, ENTRY_POINT_METADATA_OF_BATCH,
+        ENTRY_POINT_ON_CEP85_BATCH_RECEIVED, ENTRY_POINT_ON_CEP85_RECEIVED,
+        ENTRY_POINT_REMAINING_MINT_QUOTA, ENTRY_POINT_ROLE_MEMBERS, ENTRY_POINT_ROLE_MEMBER_COUNT,
+        ENTRY_POINT_SUPPLY_OF, ENTRY_POINT_SUPPLY_OF_BATCH, ENTRY_POINT_TOTAL_FUNGIBLE_SUPPLY,
+        ENTRY_POINT_TOTAL_SUPPLY_OF, ENTRY_POINT_TOTAL_SUPPLY_OF_BATCH, ENTRY_POINT_TRANSFER_FROM,
+        ENTRY_POINT_URI,
     },
-    modalities::TransferFilterContractResult,
+    modalities::{TokenReceiverResult, TransferFilterContractResult},
+    security::SecurityBadge,
 };
 use constants::{
-    ARG_FILTER_CONTRACT_RETURN_VALUE, CEP85_TEST_CONTRACT_NAME, CEP85_TEST_PACKAGE_NAME,
-    ENTRY_POINT_CHECK_BALANCE_OF, ENTRY_POINT_CHECK_BALANCE_OF_BATCH,
-    ENTRY_POINT_CHECK_BATCH_TRANSFER_FROM, ENTRY_POINT_CHECK_IS_APPROVED_FOR_ALL,
-    ENTRY_POINT_CHECK_IS_NON_FUNGIBLE, ENTRY_POINT_CHECK_SUPPLY_OF,
+    ARG_FILTER_CONTRACT_RETURN_VALUE, ARG_RECEIVER_RETURN_VALUE, CEP85_TEST_CONTRACT_NAME,
+    CEP85_TEST_PACKAGE_NAME, ENTRY_POINT_AFTER_TRANSFER_HOOK_METHOD, ENTRY_POINT_CHECK_ALLOWANCE,
+    ENTRY_POINT_CHECK_APPROVAL_EXPIRES_AT, ENTRY_POINT_CHECK_BALANCE_OF,
+    ENTRY_POINT_CHECK_BALANCE_OF_BATCH, ENTRY_POINT_CHECK_BALANCE_OF_TYPE,
+    ENTRY_POINT_CHECK_BATCH_TRANSFER_FROM, ENTRY_POINT_CHECK_GET_SECURITY_BADGE,
+    ENTRY_POINT_CHECK_IS_APPROVED_FOR_ALL, ENTRY_POINT_CHECK_IS_FROZEN,
+    ENTRY_POINT_CHECK_IS_ID_LOCKED, ENTRY_POINT_CHECK_IS_NON_FUNGIBLE, ENTRY_POINT_CHECK_IS_PAUSED,
+    ENTRY_POINT_CHECK_METADATA_OF, ENTRY_POINT_CHECK_METADATA_OF_BATCH,
+    ENTRY_POINT_CHECK_REMAINING_MINT_QUOTA, ENTRY_POINT_CHECK_ROLE_MEMBERS,
+    ENTRY_POINT_CHECK_ROLE_MEMBER_COUNT, ENTRY_POINT_CHECK_SUPPLY_OF,
     ENTRY_POINT_CHECK_SUPPLY_OF_BATCH, ENTRY_POINT_CHECK_TOTAL_FUNGIBLE_SUPPLY,
     ENTRY_POINT_CHECK_TOTAL_SUPPLY_OF, ENTRY_POINT_CHECK_TOTAL_SUPPLY_OF_BATCH,
     ENTRY_POINT_CHECK_TRANSFER_FROM, ENTRY_POINT_CHECK_URI,
-    ENTRY_POINT_SET_FILTER_CONTRACT_RETURN_VALUE, ENTRY_POINT_TRANSFER_FILTER_METHOD,
+    ENTRY_POINT_DENY_TRANSFER_FILTER_METHOD, ENTRY_POINT_SET_FILTER_CONTRACT_RETURN_VALUE,
+    ENTRY_POINT_SET_RECEIVER_RETURN_VALUE, ENTRY_POINT_TRANSFER_FILTER_METHOD,
 };
 use utils::{get_token_contract, store_result};
 
@@ -87,6 +101,65 @@ pub extern "C" fn can_transfer() {
     ret(CLValue::from_t(value).unwrap_or_revert());
 }
 
+// Transfer filter method that denies every transfer
+#[no_mangle]
+pub extern "C" fn deny_transfer() {
+    ret(CLValue::from_t(TransferFilterContractResult::DenyTransfer).unwrap_or_revert());
+}
+
+// After transfer hook storing the sender, recipient, ids and amounts it is called with
+#[no_mangle]
+pub extern "C" fn after_transfer() {
+    let _operator: Key = get_named_arg(ARG_OPERATOR);
+    let from: Key = get_named_arg(ARG_FROM);
+    let to: Key = get_named_arg(ARG_TO);
+    let ids: Vec<U256> = get_named_arg(ARG_IDS);
+    let amounts: Vec<U256> = get_named_arg(ARG_AMOUNTS);
+    let _data: Option<Bytes> = get_named_arg(ARG_DATA);
+
+    store_result((from, to, (ids, amounts)));
+}
+
+// Update stored value returned by this contract when receiving tokens
+#[no_mangle]
+pub extern "C" fn set_receiver_return_value() {
+    let value: TokenReceiverResult = get_named_arg(ARG_RECEIVER_RETURN_VALUE);
+    let uref = storage::new_uref(value);
+    put_key(ARG_RECEIVER_RETURN_VALUE, uref.into());
+}
+
+fn read_receiver_return_value() -> TokenReceiverResult {
+    match get_key(ARG_RECEIVER_RETURN_VALUE) {
+        Some(key) => storage::read(key.into_uref().unwrap_or_revert())
+            .unwrap_or_revert()
+            .unwrap_or_default(),
+        None => TokenReceiverResult::RejectTransfer,
+    }
+}
+
+// Check that some values are sent by token contract and return a TokenReceiverResult
+#[no_mangle]
+pub extern "C" fn on_cep85_received() {
+    let _operator: Key = get_named_arg(ARG_OPERATOR);
+    let _from: Option<Key> = get_named_arg(ARG_FROM);
+    let _id: U256 = get_named_arg(ARG_ID);
+    let _amount: U256 = get_named_arg(ARG_AMOUNT);
+    let _data: Option<Bytes> = get_named_arg(ARG_DATA);
+
+    ret(CLValue::from_t(read_receiver_return_value()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn on_cep85_batch_received() {
+    let _operator: Key = get_named_arg(ARG_OPERATOR);
+    let _from: Option<Key> = get_named_arg(ARG_FROM);
+    let _ids: Vec<U256> = get_named_arg(ARG_IDS);
+    let _amounts: Vec<U256> = get_named_arg(ARG_AMOUNTS);
+    let _data: Option<Bytes> = get_named_arg(ARG_DATA);
+
+    ret(CLValue::from_t(read_receiver_return_value()).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn burn() {
     let token_contract: ContractHash = get_token_contract();
@@ -145,6 +218,20 @@ pub extern "C" fn check_balance_of_batch() {
     store_result(result);
 }
 
+#[no_mangle]
+pub extern "C" fn check_balance_of_type() {
+    let token_contract: ContractHash = get_token_contract();
+    let account: Key = get_named_arg(ARG_ACCOUNT);
+    let id: U256 = get_named_arg(ARG_ID);
+    let balance_args = runtime_args! {
+        ARG_ACCOUNT => account,
+        ARG_ID => id,
+    };
+    let result: Option<U256> =
+        call_contract(token_contract, ENTRY_POINT_BALANCE_OF_TYPE, balance_args);
+    store_result(result);
+}
+
 #[no_mangle]
 pub extern "C" fn check_is_approved_for_all() {
     let token_contract: ContractHash = get_token_contract();
@@ -162,6 +249,38 @@ pub extern "C" fn check_is_approved_for_all() {
     store_result(result);
 }
 
+#[no_mangle]
+pub extern "C" fn check_approval_expires_at() {
+    let token_contract: ContractHash = get_token_contract();
+    let account: Key = get_named_arg(ARG_ACCOUNT);
+    let operator: Key = get_named_arg(ARG_OPERATOR);
+    let approval_expires_at_args = runtime_args! {
+        ARG_ACCOUNT => account,
+        ARG_OPERATOR => operator,
+    };
+    let result: Option<u64> = call_contract(
+        token_contract,
+        ENTRY_POINT_APPROVAL_EXPIRES_AT,
+        approval_expires_at_args,
+    );
+    store_result(result);
+}
+
+#[no_mangle]
+pub extern "C" fn check_allowance() {
+    let token_contract: ContractHash = get_token_contract();
+    let owner: Key = get_named_arg(ARG_OWNER);
+    let spender: Key = get_named_arg(ARG_SPENDER);
+    let id: U256 = get_named_arg(ARG_ID);
+    let allowance_args = runtime_args! {
+        ARG_OWNER => owner,
+        ARG_SPENDER => spender,
+        ARG_ID => id,
+    };
+    let result: U256 = call_contract(token_contract, ENTRY_POINT_ALLOWANCE, allowance_args);
+    store_result(result);
+}
+
 #[no_mangle]
 pub extern "C" fn check_transfer_from() {
     let token_contract: ContractHash = get_token_contract();
@@ -240,6 +359,36 @@ pub extern "C" fn check_supply_of_batch() {
     store_result(result);
 }
 
+#[no_mangle]
+pub extern "C" fn check_metadata_of() {
+    let token_contract: ContractHash = get_token_contract();
+    let id: U256 = get_named_arg(ARG_ID);
+    let check_metadata_of_args = runtime_args! {
+        ARG_ID => id,
+    };
+    let result: Option<(String, String, u8)> = call_contract(
+        token_contract,
+        ENTRY_POINT_METADATA_OF,
+        check_metadata_of_args,
+    );
+    store_result(result);
+}
+
+#[no_mangle]
+pub extern "C" fn check_metadata_of_batch() {
+    let token_contract: ContractHash = get_token_contract();
+    let ids: Vec<U256> = get_named_arg(ARG_IDS);
+    let check_metadata_of_batch_args = runtime_args! {
+        ARG_IDS => ids,
+    };
+    let result = call_contract::<Vec<Option<(String, String, u8)>>>(
+        token_contract,
+        ENTRY_POINT_METADATA_OF_BATCH,
+        check_metadata_of_batch_args,
+    );
+    store_result(result);
+}
+
 #[no_mangle]
 pub extern "C" fn check_total_supply_of() {
     let token_contract: ContractHash = get_token_contract();
@@ -285,6 +434,110 @@ pub extern "C" fn check_uri() {
     store_result(result);
 }
 
+#[no_mangle]
+pub extern "C" fn check_is_paused() {
+    let token_contract: ContractHash = get_token_contract();
+    let result: bool = call_contract(token_contract, ENTRY_POINT_IS_PAUSED, runtime_args! {});
+    store_result(result);
+}
+
+#[no_mangle]
+pub extern "C" fn check_is_id_locked() {
+    let token_contract: ContractHash = get_token_contract();
+    let id: U256 = get_named_arg(ARG_ID);
+    let result: bool = call_contract(
+        token_contract,
+        ENTRY_POINT_IS_ID_LOCKED,
+        runtime_args! {
+            ARG_ID => id,
+        },
+    );
+    store_result(result);
+}
+
+#[no_mangle]
+pub extern "C" fn check_is_frozen() {
+    let token_contract: ContractHash = get_token_contract();
+    let account: Key = get_named_arg(ARG_ACCOUNT);
+    let result: bool = call_contract(
+        token_contract,
+        ENTRY_POINT_IS_FROZEN,
+        runtime_args! {
+            ARG_ACCOUNT => account,
+        },
+    );
+    store_result(result);
+}
+
+#[no_mangle]
+pub extern "C" fn check_get_security_badge() {
+    let token_contract: ContractHash = get_token_contract();
+    let account: Key = get_named_arg(ARG_ACCOUNT);
+    let result: Vec<SecurityBadge> = call_contract(
+        token_contract,
+        ENTRY_POINT_GET_SECURITY_BADGE,
+        runtime_args! {
+            ARG_ACCOUNT => account,
+        },
+    );
+    store_result(result);
+}
+
+#[no_mangle]
+pub extern "C" fn check_role_member_count() {
+    let token_contract: ContractHash = get_token_contract();
+    let badge: SecurityBadge = get_named_arg(ARG_BADGE);
+    let result: u32 = call_contract(
+        token_contract,
+        ENTRY_POINT_ROLE_MEMBER_COUNT,
+        runtime_args! {
+            ARG_BADGE => badge,
+        },
+    );
+    store_result(result);
+}
+
+#[no_mangle]
+pub extern "C" fn check_role_members() {
+    let token_contract: ContractHash = get_token_contract();
+    let badge: SecurityBadge = get_named_arg(ARG_BADGE);
+    let start: u32 = get_named_arg(ARG_START);
+    let limit: u32 = get_named_arg(ARG_LIMIT);
+    let result: Vec<Key> = call_contract(
+        token_contract,
+        ENTRY_POINT_ROLE_MEMBERS,
+        runtime_args! {
+            ARG_BADGE => badge,
+            ARG_START => start,
+            ARG_LIMIT => limit,
+        },
+    );
+    store_result(result);
+}
+
+#[no_mangle]
+pub extern "C" fn check_remaining_mint_quota() {
+    let token_contract: ContractHash = get_token_contract();
+    let account: Key = get_named_arg(ARG_ACCOUNT);
+    let id: Option<U256> = get_named_arg(ARG_ID);
+    let check_remaining_mint_quota_args = if let Some(id) = id {
+        runtime_args! {
+            ARG_ACCOUNT => account,
+            ARG_ID => id,
+        }
+    } else {
+        runtime_args! {
+            ARG_ACCOUNT => account,
+        }
+    };
+    let result: Option<U256> = call_contract(
+        token_contract,
+        ENTRY_POINT_REMAINING_MINT_QUOTA,
+        check_remaining_mint_quota_args,
+    );
+    store_result(result);
+}
+
 #[no_mangle]
 pub extern "C" fn check_is_non_fungible() {
     let token_contract: ContractHash = get_token_contract();
@@ -353,6 +606,70 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let deny_transfer = EntryPoint::new(
+        ENTRY_POINT_DENY_TRANSFER_FILTER_METHOD,
+        vec![
+            Parameter::new(ARG_OPERATOR, CLType::Key),
+            Parameter::new(ARG_FROM, CLType::Key),
+            Parameter::new(ARG_TO, CLType::Key),
+            Parameter::new(ARG_IDS, CLType::List(Box::new(CLType::U256))),
+            Parameter::new(ARG_AMOUNTS, CLType::List(Box::new(CLType::U256))),
+            Parameter::new(ARG_DATA, CLType::Option(Box::new(Bytes::cl_type()))),
+        ],
+        TransferFilterContractResult::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let after_transfer = EntryPoint::new(
+        ENTRY_POINT_AFTER_TRANSFER_HOOK_METHOD,
+        vec![
+            Parameter::new(ARG_OPERATOR, CLType::Key),
+            Parameter::new(ARG_FROM, CLType::Key),
+            Parameter::new(ARG_TO, CLType::Key),
+            Parameter::new(ARG_IDS, CLType::List(Box::new(CLType::U256))),
+            Parameter::new(ARG_AMOUNTS, CLType::List(Box::new(CLType::U256))),
+            Parameter::new(ARG_DATA, CLType::Option(Box::new(Bytes::cl_type()))),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let set_receiver_return_value = EntryPoint::new(
+        ENTRY_POINT_SET_RECEIVER_RETURN_VALUE,
+        vec![Parameter::new(
+            ARG_RECEIVER_RETURN_VALUE,
+            TokenReceiverResult::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let on_cep85_received = EntryPoint::new(
+        ENTRY_POINT_ON_CEP85_RECEIVED,
+        vec![
+            Parameter::new(ARG_OPERATOR, CLType::Key),
+            Parameter::new(ARG_FROM, CLType::Option(Box::new(CLType::Key))),
+            Parameter::new(ARG_ID, CLType::U256),
+            Parameter::new(ARG_AMOUNT, CLType::U256),
+            Parameter::new(ARG_DATA, CLType::Option(Box::new(Bytes::cl_type()))),
+        ],
+        TokenReceiverResult::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let on_cep85_batch_received = EntryPoint::new(
+        ENTRY_POINT_ON_CEP85_BATCH_RECEIVED,
+        vec![
+            Parameter::new(ARG_OPERATOR, CLType::Key),
+            Parameter::new(ARG_FROM, CLType::Option(Box::new(CLType::Key))),
+            Parameter::new(ARG_IDS, CLType::List(Box::new(CLType::U256))),
+            Parameter::new(ARG_AMOUNTS, CLType::List(Box::new(CLType::U256))),
+            Parameter::new(ARG_DATA, CLType::Option(Box::new(Bytes::cl_type()))),
+        ],
+        TokenReceiverResult::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
     let burn = EntryPoint::new(
         ENTRY_POINT_BURN,
         vec![
@@ -397,6 +714,16 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let check_balance_of_type = EntryPoint::new(
+        ENTRY_POINT_CHECK_BALANCE_OF_TYPE,
+        vec![
+            Parameter::new(ARG_ACCOUNT, CLType::Key),
+            Parameter::new(ARG_ID, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
     let check_is_approved_for_all = EntryPoint::new(
         ENTRY_POINT_CHECK_IS_APPROVED_FOR_ALL,
         vec![
@@ -407,6 +734,27 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let check_approval_expires_at = EntryPoint::new(
+        ENTRY_POINT_CHECK_APPROVAL_EXPIRES_AT,
+        vec![
+            Parameter::new(ARG_ACCOUNT, CLType::Key),
+            Parameter::new(ARG_OPERATOR, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_allowance = EntryPoint::new(
+        ENTRY_POINT_CHECK_ALLOWANCE,
+        vec![
+            Parameter::new(ARG_OWNER, CLType::Key),
+            Parameter::new(ARG_SPENDER, CLType::Key),
+            Parameter::new(ARG_ID, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
     let check_transfer_from = EntryPoint::new(
         ENTRY_POINT_CHECK_TRANSFER_FROM,
         vec![
@@ -457,6 +805,23 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let check_metadata_of = EntryPoint::new(
+        ENTRY_POINT_CHECK_METADATA_OF,
+        vec![Parameter::new(ARG_ID, CLType::U256)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_metadata_of_batch = EntryPoint::new(
+        ENTRY_POINT_CHECK_METADATA_OF_BATCH,
+        vec![Parameter::new(
+            ARG_IDS,
+            CLType::List(Box::new(CLType::U256)),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
     let check_total_supply_of_batch = EntryPoint::new(
         ENTRY_POINT_CHECK_TOTAL_SUPPLY_OF_BATCH,
         vec![Parameter::new(
@@ -477,6 +842,62 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let check_is_paused = EntryPoint::new(
+        ENTRY_POINT_CHECK_IS_PAUSED,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_is_id_locked = EntryPoint::new(
+        ENTRY_POINT_CHECK_IS_ID_LOCKED,
+        vec![Parameter::new(ARG_ID, CLType::U256)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_is_frozen = EntryPoint::new(
+        ENTRY_POINT_CHECK_IS_FROZEN,
+        vec![Parameter::new(ARG_ACCOUNT, CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_get_security_badge = EntryPoint::new(
+        ENTRY_POINT_CHECK_GET_SECURITY_BADGE,
+        vec![Parameter::new(ARG_ACCOUNT, CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_role_member_count = EntryPoint::new(
+        ENTRY_POINT_CHECK_ROLE_MEMBER_COUNT,
+        vec![Parameter::new(ARG_BADGE, SecurityBadge::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_role_members = EntryPoint::new(
+        ENTRY_POINT_CHECK_ROLE_MEMBERS,
+        vec![
+            Parameter::new(ARG_BADGE, SecurityBadge::cl_type()),
+            Parameter::new(ARG_START, CLType::U32),
+            Parameter::new(ARG_LIMIT, CLType::U32),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_remaining_mint_quota = EntryPoint::new(
+        ENTRY_POINT_CHECK_REMAINING_MINT_QUOTA,
+        vec![
+            Parameter::new(ARG_ACCOUNT, CLType::Key),
+            Parameter::new(ARG_ID, CLType::Option(Box::new(CLType::U256))),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
     let check_is_non_fungible = EntryPoint::new(
         ENTRY_POINT_CHECK_IS_NON_FUNGIBLE,
         vec![Parameter::new(ARG_ID, CLType::U256)],
@@ -495,18 +916,35 @@ pub extern "C" fn call() {
     entry_points.add_entry_point(init);
     entry_points.add_entry_point(set_filter_contract_return_value);
     entry_points.add_entry_point(can_transfer);
+    entry_points.add_entry_point(deny_transfer);
+    entry_points.add_entry_point(after_transfer);
+    entry_points.add_entry_point(set_receiver_return_value);
+    entry_points.add_entry_point(on_cep85_received);
+    entry_points.add_entry_point(on_cep85_batch_received);
     entry_points.add_entry_point(burn);
     entry_points.add_entry_point(batch_burn);
     entry_points.add_entry_point(check_balance_of);
     entry_points.add_entry_point(check_balance_of_batch);
+    entry_points.add_entry_point(check_balance_of_type);
     entry_points.add_entry_point(check_is_approved_for_all);
+    entry_points.add_entry_point(check_approval_expires_at);
+    entry_points.add_entry_point(check_allowance);
     entry_points.add_entry_point(check_transfer_from);
     entry_points.add_entry_point(check_batch_transfer_from);
     entry_points.add_entry_point(check_supply_of);
     entry_points.add_entry_point(check_supply_of_batch);
+    entry_points.add_entry_point(check_metadata_of);
+    entry_points.add_entry_point(check_metadata_of_batch);
     entry_points.add_entry_point(check_total_supply_of);
     entry_points.add_entry_point(check_total_supply_of_batch);
     entry_points.add_entry_point(check_uri);
+    entry_points.add_entry_point(check_is_paused);
+    entry_points.add_entry_point(check_is_id_locked);
+    entry_points.add_entry_point(check_is_frozen);
+    entry_points.add_entry_point(check_get_security_badge);
+    entry_points.add_entry_point(check_role_member_count);
+    entry_points.add_entry_point(check_role_members);
+    entry_points.add_entry_point(check_remaining_mint_quota);
     entry_points.add_entry_point(check_is_non_fungible);
     entry_points.add_entry_point(check_total_fungible_supply);
 
@@ -1,14 +1,22 @@
-use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
-use casper_types::{bytesrepr::Bytes, Key, U256};
-use cep85::{constants::DEFAULT_DICT_ITEM_KEY_NAME, error::Cep85Error};
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{bytesrepr::Bytes, runtime_args, Key, RuntimeArgs, U256};
+use cep85::{
+    constants::{
+        ARG_AMOUNT, ARG_FROM, ARG_ID, ARG_TO, DEFAULT_DICT_ITEM_KEY_NAME, ENTRY_POINT_TRANSFER_FROM,
+    },
+    error::Cep85Error,
+};
 
 use crate::utility::{
     constants::{ACCOUNT_USER_1, ACCOUNT_USER_2},
     installer_request_builders::{
-        cep85_batch_mint, cep85_batch_transfer_from, cep85_batch_transfer_from_as_contract,
-        cep85_check_balance_of, cep85_check_balance_of_batch, cep85_check_is_approved,
+        cep85_approve, cep85_batch_mint, cep85_batch_transfer_from,
+        cep85_batch_transfer_from_as_contract, cep85_check_allowance,
+        cep85_check_approval_expires_at, cep85_check_balance_of, cep85_check_balance_of_batch,
+        cep85_check_is_approved, cep85_decrease_allowance, cep85_increase_allowance,
         cep85_make_dictionary_item_key, cep85_mint, cep85_set_approval_for_all,
-        cep85_transfer_from, cep85_transfer_from_as_contract, setup, TestContext, TransferData,
+        cep85_set_approval_for_all_with_expiry, cep85_transfer_from,
+        cep85_transfer_from_as_contract, setup, TestContext, TransferData,
     },
     support::assert_expected_error,
 };
@@ -1494,3 +1502,469 @@ fn should_make_dictionary_item_key_for_dict_operators_queries() {
         "b0abf6fee8caa5d4b683c1dfcd9af88d5166c483c7dc90540bb29ad3461af31f".to_string()
     );
 }
+
+#[test]
+fn should_not_allow_self_spender_approval() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let spender = Key::from(account_user_1);
+    let id = U256::one();
+    let amount = U256::from(10);
+
+    let failing_approve_call = cep85_approve(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &spender,
+        &id,
+        &amount,
+    );
+    failing_approve_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        Cep85Error::SelfSpenderApproval as u16,
+        "setting self allowance is not allowed",
+    );
+
+    let allowance = cep85_check_allowance(
+        &mut builder,
+        &cep85_test_contract_package,
+        &spender,
+        &spender,
+        &id,
+    );
+
+    assert_eq!(allowance, U256::zero());
+}
+
+#[test]
+fn should_approve_increase_and_decrease_allowance() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let owner = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let spender = Key::from(account_user_1);
+    let id = U256::one();
+
+    let approve_call = cep85_approve(
+        &mut builder,
+        &cep85_token,
+        &owner,
+        &spender,
+        &id,
+        &U256::from(10),
+    );
+    approve_call.expect_success().commit();
+
+    let allowance = cep85_check_allowance(
+        &mut builder,
+        &cep85_test_contract_package,
+        &Key::from(owner),
+        &spender,
+        &id,
+    );
+    assert_eq!(allowance, U256::from(10));
+
+    let increase_allowance_call = cep85_increase_allowance(
+        &mut builder,
+        &cep85_token,
+        &owner,
+        &spender,
+        &id,
+        &U256::from(5),
+    );
+    increase_allowance_call.expect_success().commit();
+
+    let allowance = cep85_check_allowance(
+        &mut builder,
+        &cep85_test_contract_package,
+        &Key::from(owner),
+        &spender,
+        &id,
+    );
+    assert_eq!(allowance, U256::from(15));
+
+    // Decreasing by more than the current allowance saturates to zero
+    let decrease_allowance_call = cep85_decrease_allowance(
+        &mut builder,
+        &cep85_token,
+        &owner,
+        &spender,
+        &id,
+        &U256::from(20),
+    );
+    decrease_allowance_call.expect_success().commit();
+
+    let allowance = cep85_check_allowance(
+        &mut builder,
+        &cep85_test_contract_package,
+        &Key::from(owner),
+        &spender,
+        &id,
+    );
+    assert_eq!(allowance, U256::zero());
+
+    // Allowances are scoped per id
+    let other_id_allowance = cep85_check_allowance(
+        &mut builder,
+        &cep85_test_contract_package,
+        &Key::from(owner),
+        &spender,
+        &U256::from(2),
+    );
+    assert_eq!(other_id_allowance, U256::zero());
+}
+
+#[test]
+fn should_transfer_from_account_to_account_within_allowance() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let minting_account = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let owner = Key::from(minting_account);
+    let spender = Key::from(account_user_1);
+    let id = U256::one();
+    let mint_amount = U256::from(10);
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &owner,
+        &id,
+        &mint_amount,
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let approve_call = cep85_approve(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &spender,
+        &id,
+        &U256::from(5),
+    );
+    approve_call.expect_success().commit();
+
+    let to = Key::from(account_user_2);
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &to,
+            ids: vec![id],
+            amounts: vec![U256::from(3)],
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    let allowance = cep85_check_allowance(
+        &mut builder,
+        &cep85_test_contract_package,
+        &owner,
+        &spender,
+        &id,
+    );
+    assert_eq!(allowance, U256::from(2));
+
+    let balance = cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &to, &id);
+    assert_eq!(balance, Some(U256::from(3)));
+
+    // Remaining allowance is not enough for this transfer
+    let failing_transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &to,
+            ids: vec![id],
+            amounts: vec![U256::from(3)],
+            data: None,
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientAllowance as u16,
+        "spender can not transfer more than its allowance",
+    );
+
+    let allowance = cep85_check_allowance(
+        &mut builder,
+        &cep85_test_contract_package,
+        &owner,
+        &spender,
+        &id,
+    );
+    assert_eq!(allowance, U256::from(2));
+
+    let balance = cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &to, &id);
+    assert_eq!(balance, Some(U256::from(3)));
+}
+
+#[test]
+fn should_batch_transfer_from_account_to_account_through_package_within_allowance() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let minting_account = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let minting_recipient = Key::from(account_user_1);
+    let ids: Vec<U256> = vec![U256::one(), U256::from(2)];
+    let amounts: Vec<U256> = vec![U256::from(2), U256::from(2)];
+
+    let mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &minting_recipient,
+        ids.clone(),
+        amounts.clone(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let spender = Key::from(cep85_test_contract_package);
+    for id in &ids {
+        let approve_call = cep85_approve(
+            &mut builder,
+            &cep85_token,
+            &account_user_1,
+            &spender,
+            id,
+            &U256::from(2),
+        );
+        approve_call.expect_success().commit();
+    }
+
+    let from = minting_recipient;
+    let to = Key::from(account_user_2);
+    let transfer_call = cep85_batch_transfer_from_as_contract(
+        &mut builder,
+        &cep85_test_contract_package,
+        &minting_account,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: ids.clone(),
+            amounts: vec![U256::one(), U256::from(2)],
+            data: None,
+        },
+    );
+    transfer_call.expect_success().commit();
+
+    let allowance = cep85_check_allowance(
+        &mut builder,
+        &cep85_test_contract_package,
+        &from,
+        &spender,
+        &ids[0],
+    );
+    assert_eq!(allowance, U256::one());
+
+    let allowance = cep85_check_allowance(
+        &mut builder,
+        &cep85_test_contract_package,
+        &from,
+        &spender,
+        &ids[1],
+    );
+    assert_eq!(allowance, U256::zero());
+
+    let actual_balances_after = cep85_check_balance_of_batch(
+        &mut builder,
+        &cep85_test_contract_package,
+        vec![from, from, to, to],
+        vec![ids; 2_usize].into_iter().flatten().collect(),
+    );
+
+    assert_eq!(
+        actual_balances_after,
+        [U256::one(), U256::zero(), U256::one(), U256::from(2)]
+            .iter()
+            .map(|&amount| Some(amount))
+            .collect::<Vec<Option<U256>>>()
+    );
+}
+
+#[test]
+fn should_expire_operator_approval() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let minting_account = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let owner = Key::from(minting_account);
+    let operator = Key::from(account_user_1);
+    let id = U256::one();
+    let amount = U256::from(2);
+    let expires_at = 1_000u64;
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &owner,
+        &id,
+        &amount,
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let set_approval_for_all_call = cep85_set_approval_for_all_with_expiry(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &operator,
+        expires_at,
+        0,
+    );
+    set_approval_for_all_call.expect_success().commit();
+
+    let approval_expires_at = cep85_check_approval_expires_at(
+        &mut builder,
+        &cep85_test_contract_package,
+        &owner,
+        &operator,
+    );
+    assert_eq!(approval_expires_at, Some(expires_at));
+
+    let to = Key::from(account_user_2);
+    let transfer_args = runtime_args! {
+        ARG_FROM => owner,
+        ARG_TO => to,
+        ARG_ID => id,
+        ARG_AMOUNT => U256::one(),
+    };
+
+    // Operator can transfer before the approval expires
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        cep85_token,
+        ENTRY_POINT_TRANSFER_FROM,
+        transfer_args.clone(),
+    )
+    .with_block_time(expires_at - 1)
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    // Approval is considered revoked once expired
+    let failing_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        cep85_token,
+        ENTRY_POINT_TRANSFER_FROM,
+        transfer_args,
+    )
+    .with_block_time(expires_at)
+    .build();
+    builder.exec(failing_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        Cep85Error::NotApproved as u16,
+        "expired operator can not transfer token on behalf of token owner",
+    );
+
+    let balance = cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &to, &id);
+    assert_eq!(balance, Some(U256::one()));
+}
+
+#[test]
+fn should_not_approve_operator_with_expiry_in_the_past() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let owner = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let operator = Key::from(account_user_1);
+
+    let failing_set_approval_for_all_call = cep85_set_approval_for_all_with_expiry(
+        &mut builder,
+        &cep85_token,
+        &owner,
+        &operator,
+        1_000,
+        2_000,
+    );
+    failing_set_approval_for_all_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        Cep85Error::ExpiresAtInPast as u16,
+        "approval can not expire in the past",
+    );
+
+    let is_approved = cep85_check_is_approved(
+        &mut builder,
+        &cep85_test_contract_package,
+        &Key::from(owner),
+        &operator,
+    );
+    assert!(!is_approved);
+}
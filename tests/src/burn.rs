@@ -11,11 +11,12 @@ use crate::utility::{
 };
 use std::collections::HashMap;
 
-use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
 use casper_types::{runtime_args, Key, RuntimeArgs, U256};
 use cep85::{
-    constants::{ARG_ENABLE_BURN, BURNER_LIST},
+    constants::{ARG_BURN_MODE, ARG_ENABLE_BURN, BURNER_LIST, ENTRY_POINT_SET_MODALITIES},
     error::Cep85Error,
+    modalities::BurnMode,
 };
 
 #[test]
@@ -1110,6 +1111,8 @@ fn should_allow_contract_to_burn_token() {
         minter_list: None,
         meta_list: None,
         admin_list: None,
+        pauser_list: None,
+        controller_list: None,
         none_list: None,
     };
 
@@ -1177,6 +1180,8 @@ fn should_allow_contract_to_batch_burn_token() {
         minter_list: None,
         meta_list: None,
         admin_list: None,
+        pauser_list: None,
+        controller_list: None,
         none_list: None,
     };
 
@@ -1245,6 +1250,8 @@ fn should_allow_contract_package_to_burn_token() {
         minter_list: None,
         meta_list: None,
         admin_list: None,
+        pauser_list: None,
+        controller_list: None,
         none_list: None,
     };
 
@@ -1313,6 +1320,8 @@ fn should_allow_contract_package_to_batch_burn_token() {
         minter_list: None,
         meta_list: None,
         admin_list: None,
+        pauser_list: None,
+        controller_list: None,
         none_list: None,
     };
 
@@ -1576,6 +1585,8 @@ fn should_allow_contract_as_operator_to_burn_token() {
         minter_list: None,
         meta_list: None,
         admin_list: None,
+        pauser_list: None,
+        controller_list: None,
         none_list: None,
     };
 
@@ -1685,6 +1696,8 @@ fn should_allow_contract_as_operator_to_batch_burn_token() {
         minter_list: None,
         meta_list: None,
         admin_list: None,
+        pauser_list: None,
+        controller_list: None,
         none_list: None,
     };
 
@@ -1794,6 +1807,8 @@ fn should_allow_contract_package_as_operator_to_burn_token() {
         minter_list: None,
         meta_list: None,
         admin_list: None,
+        pauser_list: None,
+        controller_list: None,
         none_list: None,
     };
 
@@ -1903,6 +1918,8 @@ fn should_allow_contract_package_as_operator_to_batch_burn_token() {
         minter_list: None,
         meta_list: None,
         admin_list: None,
+        pauser_list: None,
+        controller_list: None,
         none_list: None,
     };
 
@@ -2038,3 +2055,226 @@ fn should_not_burn_in_non_burn_mode() {
         "Can not burn when burn mode is disabled",
     );
 }
+
+#[test]
+fn should_burn_own_tokens_without_badge_in_owner_or_operator_burn_mode() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_ENABLE_BURN => true,
+            ARG_BURN_MODE => BurnMode::OwnerOrOperator as u8,
+        },
+        None,
+    );
+
+    let minting_account = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let owner: Key = Key::from(account_user_1);
+    let ids = vec![U256::one(), U256::from(2)];
+
+    let mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &owner,
+        ids.clone(),
+        vec![U256::from(2), U256::from(2)],
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let failing_burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &account_user_2,
+        &owner,
+        &ids[0],
+        &U256::one(),
+    );
+    failing_burn_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidBurnTarget as u16,
+        "should not burn tokens of another owner without being its operator",
+    );
+
+    let burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &owner,
+        &ids[0],
+        &U256::one(),
+    );
+    burn_call.expect_success().commit();
+
+    let batch_burn_call = cep85_batch_burn(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &owner,
+        ids.clone(),
+        vec![U256::one(), U256::from(2)],
+    );
+    batch_burn_call.expect_success().commit();
+
+    let actual_balances = cep85_check_balance_of_batch(
+        &mut builder,
+        &cep85_test_contract_package,
+        vec![owner, owner],
+        ids,
+    );
+    assert_eq!(
+        actual_balances,
+        vec![Some(U256::zero()), Some(U256::zero())]
+    );
+}
+
+#[test]
+fn should_apply_burn_mode_set_through_modalities() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_ENABLE_BURN => true,
+        },
+        None,
+    );
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let owner: Key = Key::from(account_user_1);
+    let id = U256::one();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::from(2),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    // The default burn mode requires both a badge and ownership
+    let failing_burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &owner,
+        &id,
+        &U256::one(),
+    );
+    failing_burn_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "should not burn without the burner badge by default",
+    );
+
+    let failing_burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::one(),
+    );
+    failing_burn_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidBurnTarget as u16,
+        "should not burn tokens of another owner by default",
+    );
+
+    let failing_set_modalities_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_SET_MODALITIES,
+        runtime_args! {
+            ARG_BURN_MODE => 3u8,
+        },
+    )
+    .build();
+    builder
+        .exec(failing_set_modalities_request)
+        .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidBurnMode as u16,
+        "should not set an unknown burn mode",
+    );
+
+    let set_modalities_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_SET_MODALITIES,
+        runtime_args! {
+            ARG_BURN_MODE => BurnMode::RoleGated as u8,
+        },
+    )
+    .build();
+    builder
+        .exec(set_modalities_request)
+        .expect_success()
+        .commit();
+
+    let burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::one(),
+    );
+    burn_call.expect_success().commit();
+
+    let batch_burn_call = cep85_batch_burn(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        vec![id],
+        vec![U256::one()],
+    );
+    batch_burn_call.expect_success().commit();
+
+    let failing_burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &owner,
+        &id,
+        &U256::one(),
+    );
+    failing_burn_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "should not burn without the burner badge in role gated mode",
+    );
+}
@@ -1,7 +1,8 @@
 use crate::utility::{
     constants::{ACCOUNT_USER_1, TOKEN_URI},
     installer_request_builders::{
-        cep85_check_balance_of, cep85_mint, setup, setup_with_args, TestContext,
+        cep85_check_balance_of, cep85_mint, cep85_set_approval_for_all_with_expiry, setup,
+        setup_with_args, TestContext,
     },
     support::{get_dictionary_value_from_key, get_event},
 };
@@ -11,8 +12,14 @@ use casper_types::{runtime_args, Key, RuntimeArgs, U256};
 use cep85::{
     constants::ARG_EVENTS_MODE,
     events::{
-        ApprovalForAll, Burn, BurnBatch, ChangeSecurity, Mint, MintBatch, SetModalities,
-        SetTotalSupply, Transfer, TransferBatch, Upgrade, Uri, UriBatch,
+        AddTransferFilter, ApprovalForAll, ApproveProposal, Burn, BurnBatch, CancelOperation,
+        CancelProposal, ChangeSecurityRoles, CreateProposal, CreateToken, CreateTokenBatch,
+        CreateType, DecreaseAllowance, ExecuteOperation, ExecuteProposal, ForcedTransfer,
+        FreezeAccounts, GrantScopedRole, IncreaseAllowance, LockId, LockIdBatch, MetadataUpdate,
+        Mint, MintBatch, Paused, ProposeAdmin, QueueOperation, RemoveTransferFilter,
+        RevokeScopedRole, SetAfterTransferHook, SetAllowance, SetIdNamespace, SetMintQuota,
+        SetModalities, SetTotalSupply, SetTransferFilter, Transfer, TransferBatch,
+        UnfreezeAccounts, UnlockId, UnlockIdBatch, Unpaused, Upgrade, Uri, UriBatch,
     },
     modalities::EventsMode,
 };
@@ -31,12 +38,44 @@ fn should_have_events_schema_in_events_mode() {
         .with::<Burn>()
         .with::<BurnBatch>()
         .with::<ApprovalForAll>()
+        .with::<SetAllowance>()
+        .with::<IncreaseAllowance>()
+        .with::<DecreaseAllowance>()
         .with::<Transfer>()
         .with::<TransferBatch>()
+        .with::<ForcedTransfer>()
         .with::<Uri>()
         .with::<UriBatch>()
+        .with::<MetadataUpdate>()
         .with::<SetTotalSupply>()
-        .with::<ChangeSecurity>()
+        .with::<CreateToken>()
+        .with::<CreateTokenBatch>()
+        .with::<CreateType>()
+        .with::<ChangeSecurityRoles>()
+        .with::<ProposeAdmin>()
+        .with::<GrantScopedRole>()
+        .with::<RevokeScopedRole>()
+        .with::<SetMintQuota>()
+        .with::<SetIdNamespace>()
+        .with::<CreateProposal>()
+        .with::<ApproveProposal>()
+        .with::<ExecuteProposal>()
+        .with::<CancelProposal>()
+        .with::<QueueOperation>()
+        .with::<ExecuteOperation>()
+        .with::<CancelOperation>()
+        .with::<FreezeAccounts>()
+        .with::<UnfreezeAccounts>()
+        .with::<LockId>()
+        .with::<LockIdBatch>()
+        .with::<UnlockId>()
+        .with::<UnlockIdBatch>()
+        .with::<Paused>()
+        .with::<Unpaused>()
+        .with::<SetTransferFilter>()
+        .with::<AddTransferFilter>()
+        .with::<RemoveTransferFilter>()
+        .with::<SetAfterTransferHook>()
         .with::<SetModalities>()
         .with::<Upgrade>();
     let actual_schemas: Schemas = builder.get_value(cep85_token, EVENTS_SCHEMA);
@@ -128,6 +167,45 @@ fn should_record_events_in_events_mode() {
     assert_eq!(actual_event, expected_event, "Expected Uri event.");
 }
 
+#[test]
+fn should_record_expiry_in_approval_for_all_event() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_EVENTS_MODE => EventsMode::CES as u8,
+        },
+        None,
+    );
+
+    let owner = *DEFAULT_ACCOUNT_ADDR;
+    let operator = Key::from(*test_accounts.get(&ACCOUNT_USER_1).unwrap());
+    let expires_at = 1_000u64;
+
+    let set_approval_for_all_call = cep85_set_approval_for_all_with_expiry(
+        &mut builder,
+        &cep85_token,
+        &owner,
+        &operator,
+        expires_at,
+        0,
+    );
+    set_approval_for_all_call.expect_success().commit();
+
+    // Expect ApprovalForAll event carrying the expiry
+    let expected_event = ApprovalForAll::new(Key::from(owner), operator, true, Some(expires_at));
+    let actual_event: ApprovalForAll = get_event(&builder, &cep85_token.into(), 0);
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected ApprovalForAll event."
+    );
+}
+
 #[test]
 #[should_panic]
 fn should_not_record_events_in_no_events_mode() {
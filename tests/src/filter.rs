@@ -10,15 +10,18 @@ use casper_types::{
 };
 use cep85::{
     constants::{
-        ARG_DATA, ARG_FROM, ARG_NAME, ARG_TOKEN_CONTRACT, ARG_TRANSFER_FILTER_CONTRACT,
-        ARG_TRANSFER_FILTER_METHOD, ARG_URI, ENTRY_POINT_INIT, ENTRY_POINT_TRANSFER_FROM,
+        ARG_DATA, ARG_ENABLE_BURN, ARG_ENABLE_FILTER_REASONS, ARG_ENABLE_MINT_BURN_FILTER,
+        ARG_FROM, ARG_NAME, ARG_TOKEN_CONTRACT, ARG_TRANSFER_FILTER_CONTRACT,
+        ARG_TRANSFER_FILTER_METHOD, ARG_URI, ENTRY_POINT_INIT, ENTRY_POINT_SET_MODALITIES,
+        ENTRY_POINT_TRANSFER_FROM,
     },
-    error::Cep85Error,
+    error::{Cep85Error, TRANSFER_FILTER_REASON_ERROR_OFFSET},
     modalities::TransferFilterContractResult,
 };
 use cep85_test_contract::constants::{
     ARG_FILTER_CONTRACT_RETURN_VALUE, CEP85_TEST_CONTRACT_NAME, CEP85_TEST_PACKAGE_NAME,
-    ENTRY_POINT_SET_FILTER_CONTRACT_RETURN_VALUE, ENTRY_POINT_TRANSFER_FILTER_METHOD,
+    ENTRY_POINT_DENY_TRANSFER_FILTER_METHOD, ENTRY_POINT_SET_FILTER_CONTRACT_RETURN_VALUE,
+    ENTRY_POINT_TRANSFER_FILTER_METHOD,
 };
 
 use crate::utility::{
@@ -27,8 +30,10 @@ use crate::utility::{
         CEP85_TEST_TOKEN_CONTRACT_NAME, TOKEN_NAME, TOKEN_URI,
     },
     installer_request_builders::{
-        cep85_batch_mint, cep85_check_balance_of, cep85_check_balance_of_batch,
-        cep85_set_total_supply_of_batch, cep85_transfer_from, TransferData,
+        cep85_add_transfer_filter, cep85_batch_mint, cep85_burn, cep85_check_balance_of,
+        cep85_check_balance_of_batch, cep85_mint, cep85_remove_transfer_filter,
+        cep85_set_total_supply_of_batch, cep85_set_transfer_filter, cep85_transfer_from, setup,
+        setup_with_args, TestContext, TransferData,
     },
     support::{assert_expected_error, create_funded_dummy_account},
 };
@@ -390,3 +395,550 @@ fn should_revert_with_invalid_filter_contract_method() {
         "should not allow installation with filter contract and empty filter contract method",
     );
 }
+
+#[test]
+fn should_set_replace_and_remove_transfer_filter() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let from = Key::from(account_user_1);
+    let to = Key::from(account_user_2);
+    let id = U256::one();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &from,
+        &id,
+        &U256::from(2),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let failing_set_transfer_filter_call = cep85_set_transfer_filter(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        Some(Key::from(cep85_test_contract)),
+        Some(ENTRY_POINT_TRANSFER_FILTER_METHOD),
+    );
+    failing_set_transfer_filter_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only admins can set the transfer filter",
+    );
+
+    let failing_set_transfer_filter_call = cep85_set_transfer_filter(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        Some(Key::from(cep85_test_contract)),
+        Some(""),
+    );
+    failing_set_transfer_filter_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidTransferFilterMethod as u16,
+        "should not set a filter contract with an empty filter method",
+    );
+
+    let set_transfer_filter_call = cep85_set_transfer_filter(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        Some(Key::from(cep85_test_contract)),
+        Some(ENTRY_POINT_TRANSFER_FILTER_METHOD),
+    );
+    set_transfer_filter_call.expect_success().commit();
+
+    let transfer_filter_contract_stored: Option<ContractHash> =
+        builder.get_value::<Option<ContractHash>>(cep85_token, ARG_TRANSFER_FILTER_CONTRACT);
+    assert_eq!(transfer_filter_contract_stored, Some(cep85_test_contract));
+
+    let failing_transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TransferFilterContractDenied as u16,
+        "should apply the transfer filter set after installation",
+    );
+
+    let remove_transfer_filter_call =
+        cep85_set_transfer_filter(&mut builder, &cep85_token, &admin, None, None);
+    remove_transfer_filter_call.expect_success().commit();
+
+    let transfer_filter_method_stored: Option<String> =
+        builder.get_value::<Option<String>>(cep85_token, ARG_TRANSFER_FILTER_METHOD);
+    assert_eq!(transfer_filter_method_stored, None);
+
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+}
+
+#[test]
+fn should_chain_transfer_filters_scoped_to_ids() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let from = Key::from(account_user_1);
+    let to = Key::from(account_user_2);
+    let ids = vec![U256::one(), U256::from(2)];
+
+    let mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &from,
+        ids.clone(),
+        vec![U256::from(2), U256::from(2)],
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let transfer_filter_contract_set_return_value_request =
+        ExecuteRequestBuilder::contract_call_by_hash(
+            admin,
+            cep85_test_contract,
+            ENTRY_POINT_SET_FILTER_CONTRACT_RETURN_VALUE,
+            runtime_args! {
+                ARG_FILTER_CONTRACT_RETURN_VALUE => TransferFilterContractResult::ProceedTransfer
+            },
+        )
+        .build();
+    builder
+        .exec(transfer_filter_contract_set_return_value_request)
+        .expect_success()
+        .commit();
+
+    let failing_add_transfer_filter_call = cep85_add_transfer_filter(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        Key::from(cep85_test_contract),
+        ENTRY_POINT_TRANSFER_FILTER_METHOD,
+        None,
+        None,
+    );
+    failing_add_transfer_filter_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only admins can add transfer filters",
+    );
+
+    let failing_add_transfer_filter_call = cep85_add_transfer_filter(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        Key::from(cep85_test_contract),
+        ENTRY_POINT_DENY_TRANSFER_FILTER_METHOD,
+        Some(U256::from(2)),
+        Some(U256::one()),
+    );
+    failing_add_transfer_filter_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidIdRange as u16,
+        "should not add a transfer filter with an end id before its start id",
+    );
+
+    let add_transfer_filter_call = cep85_add_transfer_filter(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        Key::from(cep85_test_contract),
+        ENTRY_POINT_TRANSFER_FILTER_METHOD,
+        None,
+        None,
+    );
+    add_transfer_filter_call.expect_success().commit();
+
+    let add_transfer_filter_call = cep85_add_transfer_filter(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        Key::from(cep85_test_contract),
+        ENTRY_POINT_DENY_TRANSFER_FILTER_METHOD,
+        Some(U256::from(2)),
+        None,
+    );
+    add_transfer_filter_call.expect_success().commit();
+
+    // Id 1 is only checked by the unscoped filter
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: vec![ids[0]],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    let failing_transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: vec![ids[1]],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TransferFilterContractDenied as u16,
+        "should apply the transfer filter scoped to id 2",
+    );
+
+    let failing_remove_transfer_filter_call =
+        cep85_remove_transfer_filter(&mut builder, &cep85_token, &admin, 2);
+    failing_remove_transfer_filter_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidTransferFilterIndex as u16,
+        "should not remove a transfer filter that does not exist",
+    );
+
+    let remove_transfer_filter_call =
+        cep85_remove_transfer_filter(&mut builder, &cep85_token, &admin, 1);
+    remove_transfer_filter_call.expect_success().commit();
+
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: vec![ids[1]],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+}
+
+#[test]
+fn should_surface_transfer_filter_denial_reason() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let from = Key::from(account_user_1);
+    let to = Key::from(account_user_2);
+    let id = U256::one();
+    let reason = 42u8;
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &from,
+        &id,
+        &U256::from(2),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let transfer_filter_contract_set_return_value_request =
+        ExecuteRequestBuilder::contract_call_by_hash(
+            admin,
+            cep85_test_contract,
+            ENTRY_POINT_SET_FILTER_CONTRACT_RETURN_VALUE,
+            runtime_args! {
+                ARG_FILTER_CONTRACT_RETURN_VALUE =>
+                    TransferFilterContractResult::DenyTransferWithReason(reason)
+            },
+        )
+        .build();
+    builder
+        .exec(transfer_filter_contract_set_return_value_request)
+        .expect_success()
+        .commit();
+
+    let set_transfer_filter_call = cep85_set_transfer_filter(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        Some(Key::from(cep85_test_contract)),
+        Some(ENTRY_POINT_TRANSFER_FILTER_METHOD),
+    );
+    set_transfer_filter_call.expect_success().commit();
+
+    // Without the filter reasons modality, any value other than 0 lets the transfer proceed
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    let set_modalities_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_SET_MODALITIES,
+        runtime_args! {
+            ARG_ENABLE_FILTER_REASONS => true,
+        },
+    )
+    .build();
+    builder
+        .exec(set_modalities_request)
+        .expect_success()
+        .commit();
+
+    let failing_transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        TRANSFER_FILTER_REASON_ERROR_OFFSET + u16::from(reason),
+        "should revert with the reason code returned by the transfer filter",
+    );
+}
+
+#[test]
+fn should_apply_transfer_filters_to_mint_and_burn_when_enabled() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_ENABLE_BURN => true,
+            ARG_ENABLE_MINT_BURN_FILTER => true,
+        },
+        None,
+    );
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let owner = Key::from(admin);
+    let id = U256::one();
+
+    let set_transfer_filter_call = cep85_set_transfer_filter(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        Some(Key::from(cep85_test_contract)),
+        Some(ENTRY_POINT_TRANSFER_FILTER_METHOD),
+    );
+    set_transfer_filter_call.expect_success().commit();
+
+    let failing_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::from(2),
+        None,
+    );
+    failing_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TransferFilterContractDenied as u16,
+        "should apply the transfer filter to mints",
+    );
+
+    let failing_batch_mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        vec![id],
+        vec![U256::from(2)],
+        None,
+    );
+    failing_batch_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TransferFilterContractDenied as u16,
+        "should apply the transfer filter to batch mints",
+    );
+
+    let transfer_filter_contract_set_return_value_request =
+        ExecuteRequestBuilder::contract_call_by_hash(
+            admin,
+            cep85_test_contract,
+            ENTRY_POINT_SET_FILTER_CONTRACT_RETURN_VALUE,
+            runtime_args! {
+                ARG_FILTER_CONTRACT_RETURN_VALUE => TransferFilterContractResult::ProceedTransfer
+            },
+        )
+        .build();
+    builder
+        .exec(transfer_filter_contract_set_return_value_request)
+        .expect_success()
+        .commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::from(2),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let add_transfer_filter_call = cep85_add_transfer_filter(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        Key::from(cep85_test_contract),
+        ENTRY_POINT_DENY_TRANSFER_FILTER_METHOD,
+        Some(id),
+        None,
+    );
+    add_transfer_filter_call.expect_success().commit();
+
+    let failing_burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::one(),
+    );
+    failing_burn_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TransferFilterContractDenied as u16,
+        "should apply the transfer filter to burns",
+    );
+
+    let set_modalities_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_SET_MODALITIES,
+        runtime_args! {
+            ARG_ENABLE_MINT_BURN_FILTER => false,
+        },
+    )
+    .build();
+    builder
+        .exec(set_modalities_request)
+        .expect_success()
+        .commit();
+
+    let burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::one(),
+    );
+    burn_call.expect_success().commit();
+}
@@ -0,0 +1,227 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2},
+    installer_request_builders::{
+        cep85_batch_mint, cep85_change_security, cep85_check_balance_of,
+        cep85_force_batch_transfer, cep85_force_transfer, cep85_freeze_accounts, cep85_mint, setup,
+        SecurityLists, TestContext, TransferData,
+    },
+    support::assert_expected_error,
+};
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{Key, U256};
+use cep85::error::Cep85Error;
+
+#[test]
+fn should_force_transfer_from_frozen_holder_with_controller_badge() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let holder = Key::from(account_user_1);
+    let recovery = Key::from(admin);
+    let id = U256::one();
+    let amount = U256::from(3);
+
+    let change_security_call = cep85_change_security(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        SecurityLists {
+            minter_list: None,
+            burner_list: None,
+            meta_list: None,
+            admin_list: None,
+            pauser_list: None,
+            controller_list: Some(vec![Key::from(account_user_2)]),
+            none_list: None,
+        },
+    );
+    change_security_call.expect_success().commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &holder,
+        &id,
+        &amount,
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let freeze_call = cep85_freeze_accounts(&mut builder, &cep85_token, &admin, vec![holder]);
+    freeze_call.expect_success().commit();
+
+    let force_transfer_call = cep85_force_transfer(
+        &mut builder,
+        &cep85_token,
+        &account_user_2,
+        TransferData {
+            from: &holder,
+            to: &recovery,
+            ids: vec![id],
+            amounts: vec![amount],
+            data: None,
+        },
+        "court order",
+    );
+    force_transfer_call.expect_success().commit();
+
+    let holder_balance =
+        cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &holder, &id);
+    assert_eq!(holder_balance, Some(U256::zero()));
+
+    let recovery_balance =
+        cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &recovery, &id);
+    assert_eq!(recovery_balance, Some(amount));
+}
+
+#[test]
+fn should_force_batch_transfer_and_validate_balances() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let holder = Key::from(account_user_1);
+    let recovery = Key::from(account_user_2);
+    let ids = vec![U256::one(), U256::from(2)];
+    let amounts = vec![U256::one(), U256::from(2)];
+
+    let change_security_call = cep85_change_security(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        SecurityLists {
+            minter_list: None,
+            burner_list: None,
+            meta_list: None,
+            admin_list: None,
+            pauser_list: None,
+            controller_list: Some(vec![Key::from(account_user_2)]),
+            none_list: None,
+        },
+    );
+    change_security_call.expect_success().commit();
+
+    let mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &holder,
+        ids.clone(),
+        amounts.clone(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let failing_force_batch_transfer_call = cep85_force_batch_transfer(
+        &mut builder,
+        &cep85_token,
+        &account_user_2,
+        TransferData {
+            from: &holder,
+            to: &recovery,
+            ids: ids.clone(),
+            amounts: vec![U256::one(), U256::from(3)],
+            data: None,
+        },
+        "key loss",
+    );
+    failing_force_batch_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientBalance as u16,
+        "should not force transfer more than the balance",
+    );
+
+    let force_batch_transfer_call = cep85_force_batch_transfer(
+        &mut builder,
+        &cep85_token,
+        &account_user_2,
+        TransferData {
+            from: &holder,
+            to: &recovery,
+            ids: ids.clone(),
+            amounts: amounts.clone(),
+            data: None,
+        },
+        "key loss",
+    );
+    force_batch_transfer_call.expect_success().commit();
+
+    for (id, amount) in ids.iter().zip(amounts.iter()) {
+        let recovery_balance =
+            cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &recovery, id);
+        assert_eq!(recovery_balance, Some(*amount));
+    }
+}
+
+#[test]
+fn should_not_force_transfer_without_controller_badge() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let holder = Key::from(account_user_1);
+    let id = U256::one();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &holder,
+        &id,
+        &U256::one(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let failing_force_transfer_call = cep85_force_transfer(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        TransferData {
+            from: &holder,
+            to: &Key::from(admin),
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        "court order",
+    );
+    failing_force_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only controllers can force transfers",
+    );
+}
@@ -0,0 +1,293 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2},
+    installer_request_builders::{
+        cep85_burn, cep85_change_security, cep85_check_balance_of, cep85_check_is_frozen,
+        cep85_freeze_accounts, cep85_mint, cep85_set_approval_for_all, cep85_transfer_from,
+        cep85_unfreeze_accounts, setup, setup_with_args, SecurityLists, TestContext, TransferData,
+    },
+    support::assert_expected_error,
+};
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cep85::{constants::ARG_ENABLE_BURN, error::Cep85Error};
+
+#[test]
+fn should_not_transfer_from_or_to_frozen_account() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let owner = Key::from(account_user_1);
+    let recipient = Key::from(account_user_2);
+    let id = U256::one();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::from(2),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let freeze_call = cep85_freeze_accounts(&mut builder, &cep85_token, &admin, vec![owner]);
+    freeze_call.expect_success().commit();
+
+    assert!(cep85_check_is_frozen(
+        &mut builder,
+        &cep85_test_contract_package,
+        &owner
+    ));
+
+    let failing_transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &recipient,
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::AccountFrozen as u16,
+        "should not transfer from a frozen account",
+    );
+
+    let unfreeze_call = cep85_unfreeze_accounts(&mut builder, &cep85_token, &admin, vec![owner]);
+    unfreeze_call.expect_success().commit();
+
+    let freeze_call = cep85_freeze_accounts(&mut builder, &cep85_token, &admin, vec![recipient]);
+    freeze_call.expect_success().commit();
+
+    let failing_transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &recipient,
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::AccountFrozen as u16,
+        "should not transfer to a frozen account",
+    );
+
+    let unfreeze_call =
+        cep85_unfreeze_accounts(&mut builder, &cep85_token, &admin, vec![recipient]);
+    unfreeze_call.expect_success().commit();
+
+    assert!(!cep85_check_is_frozen(
+        &mut builder,
+        &cep85_test_contract_package,
+        &recipient
+    ));
+
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &recipient,
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    let balance =
+        cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &recipient, &id);
+    assert_eq!(balance, Some(U256::one()));
+}
+
+#[test]
+fn should_not_transfer_as_frozen_operator() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let owner = Key::from(account_user_1);
+    let operator = Key::from(account_user_2);
+    let id = U256::one();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::one(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let set_approval_for_all_call =
+        cep85_set_approval_for_all(&mut builder, &cep85_token, &account_user_1, &operator, true);
+    set_approval_for_all_call.expect_success().commit();
+
+    let freeze_call = cep85_freeze_accounts(&mut builder, &cep85_token, &admin, vec![operator]);
+    freeze_call.expect_success().commit();
+
+    let failing_transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_2,
+        TransferData {
+            from: &owner,
+            to: &Key::from(admin),
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::AccountFrozen as u16,
+        "frozen operator should not transfer",
+    );
+}
+
+#[test]
+fn should_not_burn_from_frozen_account() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_ENABLE_BURN => true,
+        },
+        None,
+    );
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let owner = Key::from(account_user_1);
+    let id = U256::one();
+
+    let change_security_call = cep85_change_security(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        SecurityLists {
+            minter_list: None,
+            burner_list: Some(vec![owner]),
+            meta_list: None,
+            admin_list: None,
+            pauser_list: None,
+            controller_list: None,
+            none_list: None,
+        },
+    );
+    change_security_call.expect_success().commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::one(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let freeze_call = cep85_freeze_accounts(&mut builder, &cep85_token, &admin, vec![owner]);
+    freeze_call.expect_success().commit();
+
+    let failing_burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &owner,
+        &id,
+        &U256::one(),
+    );
+    failing_burn_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::AccountFrozen as u16,
+        "should not burn from a frozen account",
+    );
+}
+
+#[test]
+fn should_not_freeze_without_admin_badge() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let target = Key::from(account_user_2);
+
+    let failing_freeze_call =
+        cep85_freeze_accounts(&mut builder, &cep85_token, &account_user_1, vec![target]);
+    failing_freeze_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only admins can freeze accounts",
+    );
+
+    assert!(!cep85_check_is_frozen(
+        &mut builder,
+        &cep85_test_contract_package,
+        &target
+    ));
+}
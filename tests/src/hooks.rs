@@ -0,0 +1,164 @@
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cep85::{
+    constants::{ARG_ENABLE_BURN, NULL_KEY},
+    error::Cep85Error,
+};
+use cep85_test_contract::constants::ENTRY_POINT_AFTER_TRANSFER_HOOK_METHOD;
+
+use crate::utility::{
+    constants::ACCOUNT_USER_1,
+    installer_request_builders::{
+        cep85_burn, cep85_mint, cep85_set_after_transfer_hook, cep85_transfer_from,
+        get_test_result, setup, setup_with_args, TestContext, TransferData,
+    },
+    support::assert_expected_error,
+};
+
+type AfterTransferResult = (Key, Key, (Vec<U256>, Vec<U256>));
+
+#[test]
+fn should_call_after_transfer_hook_on_transfer_mint_and_burn() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_ENABLE_BURN => true,
+        },
+        None,
+    );
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let owner = Key::from(admin);
+    let recipient = Key::from(account_user_1);
+    let id = U256::one();
+
+    let set_after_transfer_hook_call = cep85_set_after_transfer_hook(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        Some(Key::from(cep85_test_contract)),
+        Some(ENTRY_POINT_AFTER_TRANSFER_HOOK_METHOD),
+    );
+    set_after_transfer_hook_call.expect_success().commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::from(3),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let result: AfterTransferResult = get_test_result(&mut builder, cep85_test_contract_package);
+    assert_eq!(result, (NULL_KEY, owner, (vec![id], vec![U256::from(3)])));
+
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        TransferData {
+            from: &owner,
+            to: &recipient,
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    let result: AfterTransferResult = get_test_result(&mut builder, cep85_test_contract_package);
+    assert_eq!(result, (owner, recipient, (vec![id], vec![U256::one()])));
+
+    let burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::from(2),
+    );
+    burn_call.expect_success().commit();
+
+    let result: AfterTransferResult = get_test_result(&mut builder, cep85_test_contract_package);
+    assert_eq!(result, (owner, NULL_KEY, (vec![id], vec![U256::from(2)])));
+
+    let remove_after_transfer_hook_call =
+        cep85_set_after_transfer_hook(&mut builder, &cep85_token, &admin, None, None);
+    remove_after_transfer_hook_call.expect_success().commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &id,
+        &U256::one(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    // The removed hook is no longer called
+    let result: AfterTransferResult = get_test_result(&mut builder, cep85_test_contract_package);
+    assert_eq!(result, (owner, NULL_KEY, (vec![id], vec![U256::from(2)])));
+}
+
+#[test]
+fn should_not_set_invalid_after_transfer_hook() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    let failing_set_after_transfer_hook_call = cep85_set_after_transfer_hook(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        Some(Key::from(cep85_test_contract)),
+        Some(ENTRY_POINT_AFTER_TRANSFER_HOOK_METHOD),
+    );
+    failing_set_after_transfer_hook_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only admins can set the after transfer hook",
+    );
+
+    let failing_set_after_transfer_hook_call = cep85_set_after_transfer_hook(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        Some(Key::from(cep85_test_contract)),
+        Some(""),
+    );
+    failing_set_after_transfer_hook_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidAfterTransferHookMethod as u16,
+        "should not set a hook contract with an empty method",
+    );
+}
@@ -10,8 +10,9 @@ use casper_engine_test_support::{
 use casper_types::{runtime_args, ContractHash, RuntimeArgs};
 use cep85::{
     constants::{
-        ARG_ENABLE_BURN, ARG_EVENTS_MODE, ARG_NAME, ARG_PACKAGE_HASH, ARG_TRANSFER_FILTER_CONTRACT,
-        ARG_TRANSFER_FILTER_METHOD, ARG_URI, DICT_BALANCES, DICT_OPERATORS, ENTRY_POINT_INIT,
+        ARG_ENABLE_BURN, ARG_ENABLE_RECEIVER_CHECK, ARG_EVENTS_MODE, ARG_NAME, ARG_PACKAGE_HASH,
+        ARG_TRANSFER_FILTER_CONTRACT, ARG_TRANSFER_FILTER_METHOD, ARG_URI, DICT_BALANCES,
+        DICT_OPERATORS, ENTRY_POINT_INIT,
     },
     error::Cep85Error,
     modalities::EventsMode,
@@ -110,3 +111,32 @@ fn should_reject_invalid_collection_name() {
         "should not allow calls to init() after installation",
     );
 }
+
+#[test]
+fn should_enable_receiver_check_by_default() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP85_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_URI => TOKEN_URI,
+        },
+    )
+    .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let cep85_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP85_TEST_TOKEN_CONTRACT_NAME)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    let enable_receiver_check: bool = builder.get_value(cep85_token, ARG_ENABLE_RECEIVER_CHECK);
+    assert!(enable_receiver_check);
+}
@@ -16,6 +16,15 @@ mod uri;
 #[cfg(test)]
 mod supply;
 
+#[cfg(test)]
+mod token_kinds;
+
+#[cfg(test)]
+mod split_ids;
+
+#[cfg(test)]
+mod metadata;
+
 #[cfg(test)]
 mod balances;
 
@@ -34,11 +43,38 @@ mod fungible;
 #[cfg(test)]
 mod filter;
 
+#[cfg(test)]
+mod hooks;
+
 #[cfg(test)]
 mod allowance;
 
 #[cfg(test)]
 mod modalities;
 
+#[cfg(test)]
+mod pause;
+
+#[cfg(test)]
+mod lock;
+
+#[cfg(test)]
+mod freeze;
+
+#[cfg(test)]
+mod force_transfer;
+
+#[cfg(test)]
+mod scoped_roles;
+
+#[cfg(test)]
+mod quotas;
+
+#[cfg(test)]
+mod proposals;
+
+#[cfg(test)]
+mod timelock;
+
 #[cfg(test)]
 mod upgrade;
@@ -0,0 +1,286 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2},
+    installer_request_builders::{
+        cep85_batch_lock_ids, cep85_batch_mint, cep85_batch_unlock_ids, cep85_burn,
+        cep85_change_security, cep85_check_balance_of, cep85_check_is_id_locked, cep85_lock_id,
+        cep85_transfer_from, cep85_unlock_id, setup, setup_with_args, SecurityLists, TestContext,
+        TransferData,
+    },
+    support::assert_expected_error,
+};
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cep85::{constants::ARG_ENABLE_BURN, error::Cep85Error};
+
+#[test]
+fn should_lock_id_and_prevent_transfer_and_burn() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_ENABLE_BURN => true,
+        },
+        None,
+    );
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let owner = Key::from(account_user_1);
+    let to = Key::from(account_user_2);
+    let locked_id = U256::one();
+    let other_id = U256::from(2);
+
+    let change_security_call = cep85_change_security(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        SecurityLists {
+            minter_list: None,
+            burner_list: Some(vec![owner]),
+            meta_list: None,
+            admin_list: None,
+            pauser_list: None,
+            controller_list: None,
+            none_list: None,
+        },
+    );
+    change_security_call.expect_success().commit();
+
+    let mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        vec![locked_id, other_id],
+        vec![U256::from(2), U256::from(2)],
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let lock_call = cep85_lock_id(&mut builder, &cep85_token, &admin, &locked_id);
+    lock_call.expect_success().commit();
+
+    assert!(cep85_check_is_id_locked(
+        &mut builder,
+        &cep85_test_contract_package,
+        &locked_id
+    ));
+    assert!(!cep85_check_is_id_locked(
+        &mut builder,
+        &cep85_test_contract_package,
+        &other_id
+    ));
+
+    let failing_transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &to,
+            ids: vec![locked_id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TokenIdLocked as u16,
+        "should not transfer a locked id",
+    );
+
+    let failing_burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &owner,
+        &locked_id,
+        &U256::one(),
+    );
+    failing_burn_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TokenIdLocked as u16,
+        "should not burn a locked id",
+    );
+
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &to,
+            ids: vec![other_id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    let balance =
+        cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &to, &other_id);
+    assert_eq!(balance, Some(U256::one()));
+
+    let unlock_call = cep85_unlock_id(&mut builder, &cep85_token, &admin, &locked_id);
+    unlock_call.expect_success().commit();
+
+    assert!(!cep85_check_is_id_locked(
+        &mut builder,
+        &cep85_test_contract_package,
+        &locked_id
+    ));
+
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &to,
+            ids: vec![locked_id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    let balance =
+        cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &to, &locked_id);
+    assert_eq!(balance, Some(U256::one()));
+}
+
+#[test]
+fn should_batch_lock_and_unlock_ids() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let owner = Key::from(account_user_1);
+    let to = Key::from(account_user_2);
+    let ids = vec![U256::one(), U256::from(2)];
+    let amounts = vec![U256::one(), U256::one()];
+
+    let mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        ids.clone(),
+        amounts.clone(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let batch_lock_call = cep85_batch_lock_ids(&mut builder, &cep85_token, &admin, ids.clone());
+    batch_lock_call.expect_success().commit();
+
+    for id in &ids {
+        assert!(cep85_check_is_id_locked(
+            &mut builder,
+            &cep85_test_contract_package,
+            id
+        ));
+    }
+
+    let failing_transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &to,
+            ids: ids.clone(),
+            amounts: amounts.clone(),
+            data: None,
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TokenIdLocked as u16,
+        "should not batch transfer locked ids",
+    );
+
+    let batch_unlock_call = cep85_batch_unlock_ids(&mut builder, &cep85_token, &admin, ids.clone());
+    batch_unlock_call.expect_success().commit();
+
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &to,
+            ids: ids.clone(),
+            amounts,
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    for id in &ids {
+        let balance = cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &to, id);
+        assert_eq!(balance, Some(U256::one()));
+    }
+}
+
+#[test]
+fn should_not_lock_id_without_admin_badge() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let id = U256::one();
+
+    let failing_lock_call = cep85_lock_id(&mut builder, &cep85_token, &account_user_1, &id);
+    failing_lock_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only admins can lock ids",
+    );
+
+    assert!(!cep85_check_is_id_locked(
+        &mut builder,
+        &cep85_test_contract_package,
+        &id
+    ));
+}
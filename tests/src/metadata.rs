@@ -0,0 +1,327 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2},
+    installer_request_builders::{
+        cep85_check_metadata_of, cep85_check_metadata_of_batch, cep85_mint, cep85_set_metadata_of,
+        setup, setup_with_args, TestContext,
+    },
+    support::{assert_expected_error, create_dummy_key_pair, fund_account, get_event},
+};
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cep85::{
+    constants::{
+        ARG_AMOUNT, ARG_AMOUNTS, ARG_COUNT, ARG_DECIMALS, ARG_DECIMALS_LIST, ARG_EVENTS_MODE,
+        ARG_ID, ARG_IDS, ARG_NAME, ARG_NAMES, ARG_RECIPIENT, ARG_SYMBOL, ARG_SYMBOLS,
+        ARG_TOKEN_KIND, ARG_TOTAL_SUPPLY, ENTRY_POINT_BATCH_MINT, ENTRY_POINT_BATCH_MINT_NEXT,
+        ENTRY_POINT_CREATE_TOKEN, ENTRY_POINT_MINT, ENTRY_POINT_MINT_NEXT, META_LIST,
+    },
+    error::Cep85Error,
+    events::MetadataUpdate,
+    modalities::{EventsMode, TokenKind},
+};
+use std::collections::HashMap;
+
+#[test]
+fn should_set_and_query_metadata_of_ids() {
+    let (_, public_key_account_user_1) = create_dummy_key_pair(ACCOUNT_USER_1);
+    let account_user_1 = public_key_account_user_1.to_account_hash();
+    let (_, public_key_account_user_2) = create_dummy_key_pair(ACCOUNT_USER_2);
+    let account_user_2 = public_key_account_user_2.to_account_hash();
+    let mut test_accounts = HashMap::new();
+    test_accounts.insert(ACCOUNT_USER_1, account_user_1);
+    test_accounts.insert(ACCOUNT_USER_2, account_user_2);
+
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_EVENTS_MODE => EventsMode::CES as u8,
+            META_LIST => vec![Key::from(account_user_1)]
+        },
+        Some(test_accounts),
+    );
+
+    fund_account(&mut builder, account_user_1);
+    fund_account(&mut builder, account_user_2);
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let id = U256::one();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &Key::from(admin),
+        &id,
+        &U256::from(1_000_000),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let metadata = cep85_check_metadata_of(&mut builder, &cep85_test_contract_package, &id);
+    assert_eq!(metadata, None);
+
+    let set_metadata_of_call = cep85_set_metadata_of(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &id,
+        "Gold",
+        "GLD",
+        6,
+    );
+    set_metadata_of_call.expect_success().commit();
+
+    let expected_event = MetadataUpdate::new(id, "Gold".to_string(), "GLD".to_string(), 6);
+    let event_index = 1; // (Mint + MetadataUpdate)
+    let actual_event: MetadataUpdate = get_event(&builder, &cep85_token.into(), event_index);
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected MetadataUpdate event."
+    );
+
+    let metadata = cep85_check_metadata_of(&mut builder, &cep85_test_contract_package, &id);
+    assert_eq!(metadata, Some(("Gold".to_string(), "GLD".to_string(), 6)));
+
+    let batch_metadata = cep85_check_metadata_of_batch(
+        &mut builder,
+        &cep85_test_contract_package,
+        vec![id, U256::from(2)],
+    );
+    assert_eq!(
+        batch_metadata,
+        vec![Some(("Gold".to_string(), "GLD".to_string(), 6)), None]
+    );
+
+    let failing_set_metadata_of_call = cep85_set_metadata_of(
+        &mut builder,
+        &cep85_token,
+        &account_user_2,
+        &id,
+        "Silver",
+        "SLV",
+        6,
+    );
+    failing_set_metadata_of_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only admins and meta accounts can set metadata",
+    );
+
+    let failing_set_metadata_of_call = cep85_set_metadata_of(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &U256::from(2),
+        "Silver",
+        "SLV",
+        6,
+    );
+    failing_set_metadata_of_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::NonSuppliedTokenId as u16,
+        "should not set metadata of a non supplied id",
+    );
+}
+
+#[test]
+fn should_set_metadata_when_minting_and_creating_tokens() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let minted_id = U256::one();
+    let created_id = U256::from(2);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_RECIPIENT => Key::from(admin),
+            ARG_ID => minted_id,
+            ARG_AMOUNT => U256::from(100),
+            ARG_NAME => "Gold",
+            ARG_SYMBOL => "GLD",
+            ARG_DECIMALS => 2u8,
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    let create_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_CREATE_TOKEN,
+        runtime_args! {
+            ARG_ID => created_id,
+            ARG_TOKEN_KIND => TokenKind::NonFungible as u8,
+            ARG_TOTAL_SUPPLY => U256::one(),
+            ARG_NAME => "Crown",
+            ARG_SYMBOL => "CRW",
+            ARG_DECIMALS => 0u8,
+        },
+    )
+    .build();
+    builder.exec(create_token_request).expect_success().commit();
+
+    let batch_metadata = cep85_check_metadata_of_batch(
+        &mut builder,
+        &cep85_test_contract_package,
+        vec![minted_id, created_id],
+    );
+    assert_eq!(
+        batch_metadata,
+        vec![
+            Some(("Gold".to_string(), "GLD".to_string(), 2)),
+            Some(("Crown".to_string(), "CRW".to_string(), 0)),
+        ]
+    );
+
+    let failing_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_RECIPIENT => Key::from(admin),
+            ARG_ID => U256::from(3),
+            ARG_AMOUNT => U256::from(100),
+            ARG_NAME => "Silver",
+        },
+    )
+    .build();
+    builder.exec(failing_mint_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::MissingTokenSymbol as u16,
+        "a token name requires a symbol and decimals",
+    );
+}
+
+#[test]
+fn should_set_metadata_when_batch_minting_and_minting_next_ids() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let batch_ids = vec![U256::from(10), U256::from(11)];
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_BATCH_MINT,
+        runtime_args! {
+            ARG_RECIPIENT => Key::from(admin),
+            ARG_IDS => batch_ids.clone(),
+            ARG_AMOUNTS => vec![U256::from(100), U256::from(50)],
+            ARG_NAMES => vec!["Gold".to_string(), "Silver".to_string()],
+            ARG_SYMBOLS => vec!["GLD".to_string(), "SLV".to_string()],
+            ARG_DECIMALS_LIST => vec![2u8, 4u8],
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).expect_success().commit();
+
+    // The collection counter allocates id 1, then ids 2 and 3
+    let mint_next_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_MINT_NEXT,
+        runtime_args! {
+            ARG_RECIPIENT => Key::from(admin),
+            ARG_NAME => "Crown",
+            ARG_SYMBOL => "CRW",
+            ARG_DECIMALS => 0u8,
+        },
+    )
+    .build();
+    builder.exec(mint_next_request).expect_success().commit();
+
+    let batch_mint_next_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_BATCH_MINT_NEXT,
+        runtime_args! {
+            ARG_RECIPIENT => Key::from(admin),
+            ARG_COUNT => 2u32,
+            ARG_NAMES => vec!["Sword".to_string(), "Shield".to_string()],
+            ARG_SYMBOLS => vec!["SWD".to_string(), "SHD".to_string()],
+            ARG_DECIMALS_LIST => vec![0u8, 0u8],
+        },
+    )
+    .build();
+    builder
+        .exec(batch_mint_next_request)
+        .expect_success()
+        .commit();
+
+    let batch_metadata = cep85_check_metadata_of_batch(
+        &mut builder,
+        &cep85_test_contract_package,
+        vec![
+            batch_ids[0],
+            batch_ids[1],
+            U256::one(),
+            U256::from(2),
+            U256::from(3),
+        ],
+    );
+    assert_eq!(
+        batch_metadata,
+        vec![
+            Some(("Gold".to_string(), "GLD".to_string(), 2)),
+            Some(("Silver".to_string(), "SLV".to_string(), 4)),
+            Some(("Crown".to_string(), "CRW".to_string(), 0)),
+            Some(("Sword".to_string(), "SWD".to_string(), 0)),
+            Some(("Shield".to_string(), "SHD".to_string(), 0)),
+        ]
+    );
+
+    let failing_batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_BATCH_MINT,
+        runtime_args! {
+            ARG_RECIPIENT => Key::from(admin),
+            ARG_IDS => vec![U256::from(12), U256::from(13)],
+            ARG_AMOUNTS => vec![U256::from(100), U256::from(100)],
+            ARG_NAMES => vec!["Copper".to_string()],
+            ARG_SYMBOLS => vec!["CPR".to_string()],
+            ARG_DECIMALS_LIST => vec![2u8],
+        },
+    )
+    .build();
+    builder.exec(failing_batch_mint_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::MismatchParamsLength as u16,
+        "batch metadata lists must match the ids",
+    );
+}
@@ -1,8 +1,9 @@
 use crate::utility::{
     constants::ACCOUNT_USER_1,
     installer_request_builders::{
-        cep85_batch_mint, cep85_check_balance_of, cep85_check_balance_of_batch,
-        cep85_check_total_supply_of, cep85_check_total_supply_of_batch, cep85_mint,
+        cep85_batch_mint, cep85_batch_mint_next, cep85_check_balance_of,
+        cep85_check_balance_of_batch, cep85_check_is_non_fungible, cep85_check_total_supply_of,
+        cep85_check_total_supply_of_batch, cep85_mint, cep85_mint_next, cep85_set_id_namespace,
         cep85_set_total_supply_of, cep85_set_total_supply_of_batch, setup, TestContext,
     },
     support::assert_expected_error,
@@ -336,3 +337,195 @@ fn should_not_batch_mint_above_total_supply() {
 
     assert_eq!(actual_balances, expected_balances);
 }
+
+#[test]
+fn should_mint_next_free_ids() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let minting_account = *DEFAULT_ACCOUNT_ADDR;
+    let minting_recipient: Key = Key::from(*test_accounts.get(&ACCOUNT_USER_1).unwrap());
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &minting_recipient,
+        &U256::from(3),
+        &U256::from(5),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let mint_next_call = cep85_mint_next(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &minting_recipient,
+        None,
+    );
+    mint_next_call.expect_success().commit();
+
+    // Id 3 is already in use and is skipped
+    let batch_mint_next_call = cep85_batch_mint_next(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &minting_recipient,
+        2,
+        None,
+    );
+    batch_mint_next_call.expect_success().commit();
+
+    for id in [1, 2, 4] {
+        let balance = cep85_check_balance_of(
+            &mut builder,
+            &cep85_test_contract_package,
+            &minting_recipient,
+            &U256::from(id),
+        );
+        assert_eq!(balance, Some(U256::one()));
+    }
+
+    let balance = cep85_check_balance_of(
+        &mut builder,
+        &cep85_test_contract_package,
+        &minting_recipient,
+        &U256::from(3),
+    );
+    assert_eq!(balance, Some(U256::from(5)));
+
+    let is_non_fungible =
+        cep85_check_is_non_fungible(&mut builder, &cep85_test_contract_package, &U256::from(4));
+    assert_eq!(is_non_fungible, Some(true));
+
+    let set_id_namespace_call = cep85_set_id_namespace(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        "tickets",
+        &U256::from(100),
+        Some(U256::from(101)),
+    );
+    set_id_namespace_call.expect_success().commit();
+
+    let batch_mint_next_call = cep85_batch_mint_next(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &minting_recipient,
+        2,
+        Some("tickets"),
+    );
+    batch_mint_next_call.expect_success().commit();
+
+    let balances = cep85_check_balance_of_batch(
+        &mut builder,
+        &cep85_test_contract_package,
+        vec![minting_recipient, minting_recipient],
+        vec![U256::from(100), U256::from(101)],
+    );
+    assert_eq!(balances, vec![Some(U256::one()), Some(U256::one())]);
+
+    let failing_mint_next_call = cep85_mint_next(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &minting_recipient,
+        Some("tickets"),
+    );
+    failing_mint_next_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::IdNamespaceExhausted as u16,
+        "should not allocate ids outside of the namespace",
+    );
+
+    let failing_mint_next_call = cep85_mint_next(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &minting_recipient,
+        Some("badges"),
+    );
+    failing_mint_next_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::IdNamespaceNotFound as u16,
+        "should not allocate ids in an unknown namespace",
+    );
+
+    // The last id of an unbounded namespace can be allocated
+    let set_id_namespace_call = cep85_set_id_namespace(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        "last",
+        &(U256::MAX - 1),
+        None,
+    );
+    set_id_namespace_call.expect_success().commit();
+
+    let batch_mint_next_call = cep85_batch_mint_next(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &minting_recipient,
+        2,
+        Some("last"),
+    );
+    batch_mint_next_call.expect_success().commit();
+
+    let balances = cep85_check_balance_of_batch(
+        &mut builder,
+        &cep85_test_contract_package,
+        vec![minting_recipient, minting_recipient],
+        vec![U256::MAX - 1, U256::MAX],
+    );
+    assert_eq!(balances, vec![Some(U256::one()), Some(U256::one())]);
+
+    let failing_mint_next_call = cep85_mint_next(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &minting_recipient,
+        Some("last"),
+    );
+    failing_mint_next_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::IdNamespaceExhausted as u16,
+        "should not allocate ids past the last id",
+    );
+
+    // The collection counter is independent of the namespaces
+    let mint_next_call = cep85_mint_next(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &minting_recipient,
+        None,
+    );
+    mint_next_call.expect_success().commit();
+
+    let balance = cep85_check_balance_of(
+        &mut builder,
+        &cep85_test_contract_package,
+        &minting_recipient,
+        &U256::from(5),
+    );
+    assert_eq!(balance, Some(U256::one()));
+}
@@ -0,0 +1,277 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2, TOKEN_URI_TEST},
+    installer_request_builders::{
+        cep85_burn, cep85_change_security, cep85_check_balance_of, cep85_check_is_paused,
+        cep85_check_uri, cep85_freeze_accounts, cep85_mint, cep85_pause, cep85_set_total_supply_of,
+        cep85_set_uri, cep85_transfer_from, cep85_unpause, setup, setup_with_args, SecurityLists,
+        TestContext, TransferData,
+    },
+    support::assert_expected_error,
+};
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cep85::{constants::ARG_ENABLE_BURN, error::Cep85Error};
+
+#[test]
+fn should_pause_and_unpause_with_pauser_badge() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_ENABLE_BURN => true,
+        },
+        None,
+    );
+
+    let minting_account = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let owner = Key::from(account_user_1);
+    let id = U256::one();
+    let amount = U256::from(2);
+
+    let change_security_call = cep85_change_security(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        SecurityLists {
+            minter_list: None,
+            burner_list: None,
+            meta_list: None,
+            admin_list: None,
+            pauser_list: Some(vec![Key::from(account_user_2)]),
+            controller_list: None,
+            none_list: None,
+        },
+    );
+    change_security_call.expect_success().commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &owner,
+        &id,
+        &amount,
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let pause_call = cep85_pause(&mut builder, &cep85_token, &account_user_2);
+    pause_call.expect_success().commit();
+
+    assert!(cep85_check_is_paused(
+        &mut builder,
+        &cep85_test_contract_package
+    ));
+
+    let to = Key::from(account_user_2);
+    let failing_transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &to,
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ContractPaused as u16,
+        "should not transfer while paused",
+    );
+
+    let failing_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &owner,
+        &id,
+        &amount,
+        None,
+    );
+    failing_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ContractPaused as u16,
+        "should not mint while paused",
+    );
+
+    let failing_burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &owner,
+        &id,
+        &U256::one(),
+    );
+    failing_burn_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ContractPaused as u16,
+        "should not burn while paused",
+    );
+
+    let unpause_call = cep85_unpause(&mut builder, &cep85_token, &account_user_2);
+    unpause_call.expect_success().commit();
+
+    assert!(!cep85_check_is_paused(
+        &mut builder,
+        &cep85_test_contract_package
+    ));
+
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TransferData {
+            from: &owner,
+            to: &to,
+            ids: vec![id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    let balance = cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &to, &id);
+    assert_eq!(balance, Some(U256::one()));
+}
+
+#[test]
+fn should_not_pause_without_pauser_badge() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    let failing_pause_call = cep85_pause(&mut builder, &cep85_token, &account_user_1);
+    failing_pause_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only pausers and admins can pause",
+    );
+
+    assert!(!cep85_check_is_paused(
+        &mut builder,
+        &cep85_test_contract_package
+    ));
+}
+
+#[test]
+fn should_not_pause_twice_nor_unpause_when_not_paused() {
+    let (mut builder, TestContext { cep85_token, .. }) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+
+    let failing_unpause_call = cep85_unpause(&mut builder, &cep85_token, &admin);
+    failing_unpause_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ContractNotPaused as u16,
+        "should not unpause when not paused",
+    );
+
+    let pause_call = cep85_pause(&mut builder, &cep85_token, &admin);
+    pause_call.expect_success().commit();
+
+    let failing_pause_call = cep85_pause(&mut builder, &cep85_token, &admin);
+    failing_pause_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ContractPaused as u16,
+        "should not pause when already paused",
+    );
+}
+
+#[test]
+fn should_not_run_admin_mutators_while_paused() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let id = U256::one();
+
+    let pause_call = cep85_pause(&mut builder, &cep85_token, &admin);
+    pause_call.expect_success().commit();
+
+    let failing_set_uri_call =
+        cep85_set_uri(&mut builder, &cep85_token, &admin, TOKEN_URI_TEST, None);
+    failing_set_uri_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ContractPaused as u16,
+        "should not set the uri while paused",
+    );
+
+    let failing_set_total_supply_of_call =
+        cep85_set_total_supply_of(&mut builder, &cep85_token, &admin, &id, &U256::from(10));
+    failing_set_total_supply_of_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ContractPaused as u16,
+        "should not set a total supply while paused",
+    );
+
+    // Incident response entry points stay available
+    let freeze_accounts_call = cep85_freeze_accounts(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        vec![Key::from(account_user_1)],
+    );
+    freeze_accounts_call.expect_success().commit();
+
+    let unpause_call = cep85_unpause(&mut builder, &cep85_token, &admin);
+    unpause_call.expect_success().commit();
+
+    let set_uri_call = cep85_set_uri(&mut builder, &cep85_token, &admin, TOKEN_URI_TEST, None);
+    set_uri_call.expect_success().commit();
+
+    let uri = cep85_check_uri(&mut builder, &cep85_test_contract_package, None);
+    assert_eq!(uri, Some(TOKEN_URI_TEST.to_string()));
+}
@@ -0,0 +1,397 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2, TOKEN_URI, TOKEN_URI_TEST},
+    installer_request_builders::{
+        cep85_approve_proposal, cep85_cancel_proposal, cep85_check_is_id_locked, cep85_check_uri,
+        cep85_create_proposal, cep85_lock_id, cep85_set_uri, setup_with_args, TestContext,
+    },
+    support::{assert_expected_error, create_dummy_key_pair, fund_account},
+};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use cep85::{
+    constants::{
+        ADMIN_LIST, ARG_ADMIN_THRESHOLD, ARG_ID, ARG_PROPOSAL_ID, ARG_URI,
+        ENTRY_POINT_APPROVE_PROPOSAL, ENTRY_POINT_CHANGE_SECURITY, ENTRY_POINT_LOCK_ID,
+        ENTRY_POINT_SET_URI, NONE_LIST,
+    },
+    error::Cep85Error,
+};
+use std::collections::HashMap;
+
+fn setup_with_two_admins() -> (InMemoryWasmTestBuilder, TestContext, AccountHash) {
+    let (_, public_key_account_user_1) = create_dummy_key_pair(ACCOUNT_USER_1);
+    let account_user_1 = public_key_account_user_1.to_account_hash();
+    let mut test_accounts = HashMap::new();
+    test_accounts.insert(ACCOUNT_USER_1, account_user_1);
+
+    let (mut builder, test_context) = setup_with_args(
+        runtime_args! {
+            ADMIN_LIST => vec![Key::from(*DEFAULT_ACCOUNT_ADDR), Key::from(account_user_1)],
+            ARG_ADMIN_THRESHOLD => 2u32,
+        },
+        Some(test_accounts),
+    );
+
+    // account_user_1 was created before genesis and is not yet funded so fund it
+    fund_account(&mut builder, account_user_1);
+
+    (builder, test_context, account_user_1)
+}
+
+#[test]
+fn should_execute_proposal_once_threshold_is_reached() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ..
+        },
+        account_user_1,
+    ) = setup_with_two_admins();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+
+    let failing_set_uri_call =
+        cep85_set_uri(&mut builder, &cep85_token, &admin, TOKEN_URI_TEST, None);
+    failing_set_uri_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "a single admin should not set the uri above the threshold",
+    );
+
+    let create_proposal_call = cep85_create_proposal(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_SET_URI,
+        runtime_args! {
+            ARG_URI => TOKEN_URI_TEST,
+        },
+        None,
+    );
+    create_proposal_call.expect_success().commit();
+
+    let proposal_id = 0u32;
+
+    let uri = cep85_check_uri(&mut builder, &cep85_test_contract_package, None);
+    assert_eq!(uri, Some(TOKEN_URI.to_string()));
+
+    let failing_approve_call =
+        cep85_approve_proposal(&mut builder, &cep85_token, &admin, proposal_id);
+    failing_approve_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ProposalAlreadyApproved as u16,
+        "the proposer already approved the proposal",
+    );
+
+    let approve_call =
+        cep85_approve_proposal(&mut builder, &cep85_token, &account_user_1, proposal_id);
+    approve_call.expect_success().commit();
+
+    let uri = cep85_check_uri(&mut builder, &cep85_test_contract_package, None);
+    assert_eq!(uri, Some(TOKEN_URI_TEST.to_string()));
+
+    let failing_approve_call =
+        cep85_approve_proposal(&mut builder, &cep85_token, &account_user_1, proposal_id);
+    failing_approve_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ProposalNotPending as u16,
+        "an executed proposal cannot be approved again",
+    );
+}
+
+#[test]
+fn should_not_approve_cancelled_or_expired_proposals() {
+    let (mut builder, TestContext { cep85_token, .. }, account_user_1) = setup_with_two_admins();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let expires_at = 1000u64;
+
+    let create_proposal_call = cep85_create_proposal(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_SET_URI,
+        runtime_args! {
+            ARG_URI => TOKEN_URI_TEST,
+        },
+        None,
+    );
+    create_proposal_call.expect_success().commit();
+
+    let cancel_call = cep85_cancel_proposal(&mut builder, &cep85_token, &account_user_1, 0);
+    cancel_call.expect_success().commit();
+
+    let failing_approve_call =
+        cep85_approve_proposal(&mut builder, &cep85_token, &account_user_1, 0);
+    failing_approve_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ProposalNotPending as u16,
+        "a cancelled proposal cannot be approved",
+    );
+
+    let create_proposal_call = cep85_create_proposal(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_SET_URI,
+        runtime_args! {
+            ARG_URI => TOKEN_URI_TEST,
+        },
+        Some(expires_at),
+    );
+    create_proposal_call.expect_success().commit();
+
+    let failing_approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        cep85_token,
+        ENTRY_POINT_APPROVE_PROPOSAL,
+        runtime_args! {
+            ARG_PROPOSAL_ID => 1u32,
+        },
+    )
+    .with_block_time(expires_at)
+    .build();
+    builder.exec(failing_approve_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ProposalExpired as u16,
+        "an expired proposal cannot be approved",
+    );
+
+    let failing_approve_call =
+        cep85_approve_proposal(&mut builder, &cep85_token, &account_user_1, 2);
+    failing_approve_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ProposalNotFound as u16,
+        "an unknown proposal cannot be approved",
+    );
+}
+
+#[test]
+fn should_not_create_invalid_proposals() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+        account_user_1,
+    ) = setup_with_two_admins();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+
+    let failing_create_proposal_call = cep85_create_proposal(
+        &mut builder,
+        &cep85_token,
+        &account_user_2,
+        ENTRY_POINT_SET_URI,
+        runtime_args! {
+            ARG_URI => TOKEN_URI_TEST,
+        },
+        None,
+    );
+    failing_create_proposal_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only admins can create proposals",
+    );
+
+    let failing_create_proposal_call = cep85_create_proposal(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        "mint",
+        RuntimeArgs::new(),
+        None,
+    );
+    failing_create_proposal_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidAction as u16,
+        "only admin actions can be proposed",
+    );
+
+    // Removing an admin would leave fewer admins than the threshold
+    let create_proposal_call = cep85_create_proposal(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_CHANGE_SECURITY,
+        runtime_args! {
+            NONE_LIST => vec![Key::from(account_user_1)],
+        },
+        None,
+    );
+    create_proposal_call.expect_success().commit();
+
+    let failing_approve_call =
+        cep85_approve_proposal(&mut builder, &cep85_token, &account_user_1, 0);
+    failing_approve_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::AdminCountBelowThreshold as u16,
+        "admins cannot fall below the threshold",
+    );
+}
+
+#[test]
+fn should_lock_id_through_a_proposal_above_the_threshold() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ..
+        },
+        account_user_1,
+    ) = setup_with_two_admins();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let id = U256::one();
+
+    let failing_lock_id_call = cep85_lock_id(&mut builder, &cep85_token, &admin, &id);
+    failing_lock_id_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "a single admin should not lock an id above the threshold",
+    );
+
+    let create_proposal_call = cep85_create_proposal(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_LOCK_ID,
+        runtime_args! {
+            ARG_ID => id,
+        },
+        None,
+    );
+    create_proposal_call.expect_success().commit();
+
+    assert!(!cep85_check_is_id_locked(
+        &mut builder,
+        &cep85_test_contract_package,
+        &id
+    ));
+
+    let approve_call = cep85_approve_proposal(&mut builder, &cep85_token, &account_user_1, 0);
+    approve_call.expect_success().commit();
+
+    assert!(cep85_check_is_id_locked(
+        &mut builder,
+        &cep85_test_contract_package,
+        &id
+    ));
+}
+
+#[test]
+fn should_not_count_approvals_of_removed_admins() {
+    let (_, public_key_account_user_1) = create_dummy_key_pair(ACCOUNT_USER_1);
+    let account_user_1 = public_key_account_user_1.to_account_hash();
+    let (_, public_key_account_user_2) = create_dummy_key_pair(ACCOUNT_USER_2);
+    let account_user_2 = public_key_account_user_2.to_account_hash();
+    let mut test_accounts = HashMap::new();
+    test_accounts.insert(ACCOUNT_USER_1, account_user_1);
+    test_accounts.insert(ACCOUNT_USER_2, account_user_2);
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ADMIN_LIST => vec![
+                Key::from(admin),
+                Key::from(account_user_1),
+                Key::from(account_user_2),
+            ],
+            ARG_ADMIN_THRESHOLD => 2u32,
+        },
+        Some(test_accounts),
+    );
+
+    // account_user_1 and account_user_2 were created before genesis and are not yet funded so
+    // fund them
+    fund_account(&mut builder, account_user_1);
+    fund_account(&mut builder, account_user_2);
+
+    // The admin approves a uri change by creating its proposal.
+    let create_proposal_call = cep85_create_proposal(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_SET_URI,
+        runtime_args! {
+            ARG_URI => TOKEN_URI_TEST,
+        },
+        None,
+    );
+    create_proposal_call.expect_success().commit();
+
+    // The two other admins then remove the admin.
+    let create_proposal_call = cep85_create_proposal(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        ENTRY_POINT_CHANGE_SECURITY,
+        runtime_args! {
+            NONE_LIST => vec![Key::from(admin)],
+        },
+        None,
+    );
+    create_proposal_call.expect_success().commit();
+
+    let approve_call = cep85_approve_proposal(&mut builder, &cep85_token, &account_user_2, 1);
+    approve_call.expect_success().commit();
+
+    // The approval of the removed admin no longer counts towards the threshold.
+    let approve_call = cep85_approve_proposal(&mut builder, &cep85_token, &account_user_2, 0);
+    approve_call.expect_success().commit();
+
+    let uri = cep85_check_uri(&mut builder, &cep85_test_contract_package, None);
+    assert_eq!(uri, Some(TOKEN_URI.to_string()));
+
+    let approve_call = cep85_approve_proposal(&mut builder, &cep85_token, &account_user_1, 0);
+    approve_call.expect_success().commit();
+
+    let uri = cep85_check_uri(&mut builder, &cep85_test_contract_package, None);
+    assert_eq!(uri, Some(TOKEN_URI_TEST.to_string()));
+}
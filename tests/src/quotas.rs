@@ -0,0 +1,280 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2},
+    installer_request_builders::{
+        cep85_batch_mint, cep85_change_security, cep85_check_remaining_mint_quota, cep85_mint,
+        cep85_set_mint_quota, setup, MintQuotaLimits, SecurityLists, TestContext,
+    },
+    support::assert_expected_error,
+};
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cep85::{
+    constants::{ARG_AMOUNT, ARG_ID, ARG_RECIPIENT, ENTRY_POINT_MINT},
+    error::Cep85Error,
+};
+
+#[test]
+fn should_limit_minter_to_lifetime_quota() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let minter = Key::from(account_user_1);
+    let recipient = Key::from(account_user_2);
+
+    let change_security_call = cep85_change_security(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        SecurityLists {
+            minter_list: Some(vec![minter]),
+            burner_list: None,
+            meta_list: None,
+            admin_list: None,
+            pauser_list: None,
+            controller_list: None,
+            none_list: None,
+        },
+    );
+    change_security_call.expect_success().commit();
+
+    let set_mint_quota_call = cep85_set_mint_quota(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &minter,
+        None,
+        MintQuotaLimits {
+            lifetime_limit: Some(U256::from(5)),
+            window_limit: None,
+            window: 0,
+        },
+    );
+    set_mint_quota_call.expect_success().commit();
+
+    let remaining_mint_quota =
+        cep85_check_remaining_mint_quota(&mut builder, &cep85_test_contract_package, &minter, None);
+    assert_eq!(remaining_mint_quota, Some(U256::from(5)));
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &recipient,
+        &U256::one(),
+        &U256::from(3),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let remaining_mint_quota =
+        cep85_check_remaining_mint_quota(&mut builder, &cep85_test_contract_package, &minter, None);
+    assert_eq!(remaining_mint_quota, Some(U256::from(2)));
+
+    let failing_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &recipient,
+        &U256::one(),
+        &U256::from(3),
+        None,
+    );
+    failing_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::MintQuotaExceeded as u16,
+        "should not mint more than the lifetime quota",
+    );
+
+    let batch_mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &recipient,
+        vec![U256::one(), U256::from(2)],
+        vec![U256::one(), U256::one()],
+        None,
+    );
+    batch_mint_call.expect_success().commit();
+
+    let remaining_mint_quota =
+        cep85_check_remaining_mint_quota(&mut builder, &cep85_test_contract_package, &minter, None);
+    assert_eq!(remaining_mint_quota, Some(U256::zero()));
+
+    let unlimited_mint_quota = cep85_check_remaining_mint_quota(
+        &mut builder,
+        &cep85_test_contract_package,
+        &Key::from(admin),
+        None,
+    );
+    assert_eq!(unlimited_mint_quota, None);
+}
+
+#[test]
+fn should_limit_minter_per_id_and_window() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let recipient = Key::from(account_user_1);
+    let minter = Key::from(admin);
+    let id = U256::one();
+    let window = 1000u64;
+
+    let set_mint_quota_call = cep85_set_mint_quota(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &minter,
+        Some(id),
+        MintQuotaLimits {
+            lifetime_limit: None,
+            window_limit: Some(U256::from(2)),
+            window,
+        },
+    );
+    set_mint_quota_call.expect_success().commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &recipient,
+        &id,
+        &U256::from(2),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let remaining_mint_quota = cep85_check_remaining_mint_quota(
+        &mut builder,
+        &cep85_test_contract_package,
+        &minter,
+        Some(id),
+    );
+    assert_eq!(remaining_mint_quota, Some(U256::zero()));
+
+    // Other ids are not limited by the quota of id 1
+    let other_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &recipient,
+        &U256::from(2),
+        &U256::from(10),
+        None,
+    );
+    other_mint_call.expect_success().commit();
+
+    let mint_args = runtime_args! {
+        ARG_RECIPIENT => recipient,
+        ARG_ID => id,
+        ARG_AMOUNT => U256::one(),
+    };
+
+    // The window started with the first mint at block time 0
+    let failing_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_MINT,
+        mint_args.clone(),
+    )
+    .with_block_time(window - 1)
+    .build();
+    builder.exec(failing_mint_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::MintQuotaExceeded as u16,
+        "should not mint more than the window quota",
+    );
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_MINT,
+        mint_args,
+    )
+    .with_block_time(window)
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+}
+
+#[test]
+fn should_not_set_invalid_mint_quota() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let minter = Key::from(account_user_1);
+
+    let failing_set_mint_quota_call = cep85_set_mint_quota(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &minter,
+        None,
+        MintQuotaLimits {
+            lifetime_limit: Some(U256::one()),
+            window_limit: None,
+            window: 0,
+        },
+    );
+    failing_set_mint_quota_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only admins can set mint quotas",
+    );
+
+    let failing_set_mint_quota_call = cep85_set_mint_quota(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &minter,
+        None,
+        MintQuotaLimits {
+            lifetime_limit: None,
+            window_limit: Some(U256::one()),
+            window: 0,
+        },
+    );
+    failing_set_mint_quota_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidWindow as u16,
+        "a window limit requires a window",
+    );
+}
@@ -0,0 +1,340 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2, TOKEN_URI_TEST},
+    installer_request_builders::{
+        cep85_batch_burn, cep85_batch_mint, cep85_burn, cep85_grant_scoped_role, cep85_mint,
+        cep85_revoke_scoped_role, cep85_set_uri, setup, setup_with_args, TestContext,
+    },
+    support::assert_expected_error,
+};
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cep85::{constants::ARG_ENABLE_BURN, error::Cep85Error, security::SecurityBadge};
+
+#[test]
+fn should_mint_and_set_uri_only_within_scoped_ids() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let partner = Key::from(account_user_1);
+    let start_id = U256::from(10);
+    let end_id = U256::from(20);
+
+    let grant_call = cep85_grant_scoped_role(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &partner,
+        SecurityBadge::Minter,
+        &start_id,
+        &end_id,
+    );
+    grant_call.expect_success().commit();
+
+    let grant_call = cep85_grant_scoped_role(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &partner,
+        SecurityBadge::Meta,
+        &start_id,
+        &start_id,
+    );
+    grant_call.expect_success().commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &partner,
+        &start_id,
+        &U256::one(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let batch_mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &partner,
+        vec![U256::from(11), end_id],
+        vec![U256::one(), U256::one()],
+        None,
+    );
+    batch_mint_call.expect_success().commit();
+
+    let failing_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &partner,
+        &U256::from(21),
+        &U256::one(),
+        None,
+    );
+    failing_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "should not mint outside of the scoped ids",
+    );
+
+    let failing_batch_mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &partner,
+        vec![end_id, U256::from(21)],
+        vec![U256::one(), U256::one()],
+        None,
+    );
+    failing_batch_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "should not batch mint when one id is outside of the scoped ids",
+    );
+
+    let set_uri_call = cep85_set_uri(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TOKEN_URI_TEST,
+        Some(start_id),
+    );
+    set_uri_call.expect_success().commit();
+
+    let failing_set_uri_call = cep85_set_uri(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TOKEN_URI_TEST,
+        Some(end_id),
+    );
+    failing_set_uri_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "should not set the uri of an id outside of the scoped ids",
+    );
+
+    let failing_set_uri_call = cep85_set_uri(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TOKEN_URI_TEST,
+        None,
+    );
+    failing_set_uri_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "scoped meta badge should not set the global uri",
+    );
+
+    let revoke_call = cep85_revoke_scoped_role(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &partner,
+        SecurityBadge::Minter,
+        &start_id,
+        &end_id,
+    );
+    revoke_call.expect_success().commit();
+
+    let failing_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &partner,
+        &start_id,
+        &U256::one(),
+        None,
+    );
+    failing_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "should not mint once the scoped badge is revoked",
+    );
+
+    let failing_revoke_call = cep85_revoke_scoped_role(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &partner,
+        SecurityBadge::Minter,
+        &start_id,
+        &end_id,
+    );
+    failing_revoke_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::MissingScopedRole as u16,
+        "should not revoke a scoped badge twice",
+    );
+}
+
+#[test]
+fn should_burn_only_within_scoped_ids() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_ENABLE_BURN => true,
+        },
+        None,
+    );
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let owner = Key::from(account_user_1);
+    let scoped_id = U256::one();
+    let other_id = U256::from(2);
+
+    let mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        vec![scoped_id, other_id],
+        vec![U256::from(2), U256::from(2)],
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let grant_call = cep85_grant_scoped_role(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        SecurityBadge::Burner,
+        &scoped_id,
+        &scoped_id,
+    );
+    grant_call.expect_success().commit();
+
+    let burn_call = cep85_burn(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &owner,
+        &scoped_id,
+        &U256::one(),
+    );
+    burn_call.expect_success().commit();
+
+    let failing_batch_burn_call = cep85_batch_burn(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &owner,
+        vec![scoped_id, other_id],
+        vec![U256::one(), U256::one()],
+    );
+    failing_batch_burn_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "should not burn an id outside of the scoped ids",
+    );
+}
+
+#[test]
+fn should_not_grant_invalid_scoped_roles() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let partner = Key::from(account_user_2);
+
+    let failing_grant_call = cep85_grant_scoped_role(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &partner,
+        SecurityBadge::Minter,
+        &U256::one(),
+        &U256::one(),
+    );
+    failing_grant_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only admins can grant scoped badges",
+    );
+
+    let failing_grant_call = cep85_grant_scoped_role(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &partner,
+        SecurityBadge::Admin,
+        &U256::one(),
+        &U256::one(),
+    );
+    failing_grant_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidScopedBadge as u16,
+        "the admin badge cannot be scoped",
+    );
+
+    let failing_grant_call = cep85_grant_scoped_role(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &partner,
+        SecurityBadge::Minter,
+        &U256::from(2),
+        &U256::one(),
+    );
+    failing_grant_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidIdRange as u16,
+        "the end id should not precede the start id",
+    );
+}
@@ -1,8 +1,10 @@
 use crate::utility::{
     constants::{ACCOUNT_USER_1, ACCOUNT_USER_2, TOKEN_URI, TOKEN_URI_TEST},
     installer_request_builders::{
-        cep85_burn, cep85_change_security, cep85_check_uri, cep85_mint, cep85_set_total_supply_of,
-        cep85_set_uri, setup_with_args, SecurityLists, TestContext,
+        cep85_accept_admin, cep85_burn, cep85_change_security, cep85_check_get_security_badge,
+        cep85_check_role_member_count, cep85_check_role_members, cep85_check_uri, cep85_mint,
+        cep85_propose_admin, cep85_renounce_role, cep85_revoke_security, cep85_set_total_supply_of,
+        cep85_set_uri, setup, setup_with_args, SecurityLists, TestContext,
     },
     support::{assert_expected_error, create_dummy_key_pair, fund_account},
 };
@@ -11,6 +13,7 @@ use casper_types::{runtime_args, Key, RuntimeArgs, U256};
 use cep85::{
     constants::{ADMIN_LIST, ARG_ENABLE_BURN, BURNER_LIST, META_LIST, MINTER_LIST},
     error::Cep85Error,
+    security::SecurityBadge,
     utils::replace_token_id_in_uri,
 };
 use std::collections::HashMap;
@@ -433,6 +436,8 @@ fn should_test_change_security() {
         burner_list: None,
         meta_list: None,
         admin_list: None,
+        pauser_list: None,
+        controller_list: None,
         none_list: None,
     };
 
@@ -459,6 +464,8 @@ fn should_test_change_security() {
         burner_list: None,
         meta_list: None,
         admin_list: None,
+        pauser_list: None,
+        controller_list: None,
         none_list: Some(vec![Key::Account(account_user_2)]),
     };
 
@@ -488,3 +495,536 @@ fn should_test_change_security() {
         "should not allow to mint for non default admin account",
     );
 }
+
+#[test]
+fn should_not_remove_last_admin() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let installer = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    let failing_renounce_call = cep85_renounce_role(&mut builder, &cep85_token, &installer);
+    failing_renounce_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::LastAdmin as u16,
+        "the only admin should not renounce",
+    );
+
+    let security_lists = SecurityLists {
+        minter_list: None,
+        burner_list: None,
+        meta_list: None,
+        admin_list: Some(vec![Key::from(account_user_1)]),
+        pauser_list: None,
+        controller_list: None,
+        none_list: None,
+    };
+    let change_security_call =
+        cep85_change_security(&mut builder, &cep85_token, &installer, security_lists);
+    change_security_call.expect_success().commit();
+
+    let security_lists = SecurityLists {
+        minter_list: None,
+        burner_list: None,
+        meta_list: None,
+        admin_list: None,
+        pauser_list: None,
+        controller_list: None,
+        none_list: Some(vec![Key::from(installer)]),
+    };
+    let change_security_call =
+        cep85_change_security(&mut builder, &cep85_token, &account_user_1, security_lists);
+    change_security_call.expect_success().commit();
+
+    let failing_renounce_call = cep85_renounce_role(&mut builder, &cep85_token, &account_user_1);
+    failing_renounce_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::LastAdmin as u16,
+        "the last remaining admin should not renounce",
+    );
+}
+
+#[test]
+fn should_hand_over_admin_in_two_steps() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let installer = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let id = U256::one();
+
+    let propose_admin_call = cep85_propose_admin(
+        &mut builder,
+        &cep85_token,
+        &installer,
+        &Key::from(account_user_1),
+    );
+    propose_admin_call.expect_success().commit();
+
+    let failing_accept_call = cep85_accept_admin(&mut builder, &cep85_token, &account_user_2);
+    failing_accept_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::NoPendingAdminProposal as u16,
+        "only the proposed account should accept",
+    );
+
+    let accept_call = cep85_accept_admin(&mut builder, &cep85_token, &account_user_1);
+    accept_call.expect_success().commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &Key::from(account_user_1),
+        &id,
+        &U256::one(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let failing_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &installer,
+        &Key::from(installer),
+        &id,
+        &U256::one(),
+        None,
+    );
+    failing_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "previous admin should have lost its badge",
+    );
+
+    let failing_accept_call = cep85_accept_admin(&mut builder, &cep85_token, &account_user_1);
+    failing_accept_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::NoPendingAdminProposal as u16,
+        "a proposal should only be accepted once",
+    );
+}
+
+#[test]
+fn should_renounce_own_role() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let installer = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    let security_lists = SecurityLists {
+        minter_list: None,
+        burner_list: None,
+        meta_list: None,
+        admin_list: Some(vec![Key::from(account_user_1)]),
+        pauser_list: None,
+        controller_list: None,
+        none_list: None,
+    };
+    let change_security_call =
+        cep85_change_security(&mut builder, &cep85_token, &installer, security_lists);
+    change_security_call.expect_success().commit();
+
+    let renounce_call = cep85_renounce_role(&mut builder, &cep85_token, &installer);
+    renounce_call.expect_success().commit();
+
+    let security_lists = SecurityLists {
+        minter_list: Some(vec![Key::from(installer)]),
+        burner_list: None,
+        meta_list: None,
+        admin_list: None,
+        pauser_list: None,
+        controller_list: None,
+        none_list: None,
+    };
+    let failing_change_security_call =
+        cep85_change_security(&mut builder, &cep85_token, &installer, security_lists);
+    failing_change_security_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "renounced admin should not change security",
+    );
+}
+
+#[test]
+fn should_query_security_badge() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let installer = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+
+    assert_eq!(
+        cep85_check_get_security_badge(
+            &mut builder,
+            &cep85_test_contract_package,
+            &Key::from(installer)
+        ),
+        vec![SecurityBadge::Admin]
+    );
+    assert_eq!(
+        cep85_check_get_security_badge(
+            &mut builder,
+            &cep85_test_contract_package,
+            &Key::from(account_user_1)
+        ),
+        vec![]
+    );
+
+    let security_lists = SecurityLists {
+        minter_list: Some(vec![Key::from(account_user_1)]),
+        burner_list: None,
+        meta_list: None,
+        admin_list: None,
+        pauser_list: None,
+        controller_list: None,
+        none_list: Some(vec![Key::from(account_user_2)]),
+    };
+    let change_security_call =
+        cep85_change_security(&mut builder, &cep85_token, &installer, security_lists);
+    change_security_call.expect_success().commit();
+
+    assert_eq!(
+        cep85_check_get_security_badge(
+            &mut builder,
+            &cep85_test_contract_package,
+            &Key::from(account_user_1)
+        ),
+        vec![SecurityBadge::Minter]
+    );
+    assert_eq!(
+        cep85_check_get_security_badge(
+            &mut builder,
+            &cep85_test_contract_package,
+            &Key::from(account_user_2)
+        ),
+        vec![]
+    );
+}
+
+#[test]
+fn should_enumerate_role_members() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let installer = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let minters = vec![Key::from(account_user_1), Key::from(account_user_2)];
+
+    assert_eq!(
+        cep85_check_role_members(
+            &mut builder,
+            &cep85_test_contract_package,
+            SecurityBadge::Admin,
+            0,
+            10
+        ),
+        vec![Key::from(installer)]
+    );
+
+    let security_lists = SecurityLists {
+        minter_list: Some(minters.clone()),
+        burner_list: None,
+        meta_list: None,
+        admin_list: None,
+        pauser_list: None,
+        controller_list: None,
+        none_list: None,
+    };
+    let change_security_call =
+        cep85_change_security(&mut builder, &cep85_token, &installer, security_lists);
+    change_security_call.expect_success().commit();
+
+    assert_eq!(
+        cep85_check_role_member_count(
+            &mut builder,
+            &cep85_test_contract_package,
+            SecurityBadge::Minter
+        ),
+        2
+    );
+
+    let mut listed_minters = cep85_check_role_members(
+        &mut builder,
+        &cep85_test_contract_package,
+        SecurityBadge::Minter,
+        0,
+        1,
+    );
+    assert_eq!(listed_minters.len(), 1);
+    listed_minters.extend(cep85_check_role_members(
+        &mut builder,
+        &cep85_test_contract_package,
+        SecurityBadge::Minter,
+        1,
+        1,
+    ));
+    listed_minters.sort();
+    let mut expected_minters = minters;
+    expected_minters.sort();
+    assert_eq!(listed_minters, expected_minters);
+
+    assert!(cep85_check_role_members(
+        &mut builder,
+        &cep85_test_contract_package,
+        SecurityBadge::Minter,
+        2,
+        10
+    )
+    .is_empty());
+
+    let security_lists = SecurityLists {
+        minter_list: None,
+        burner_list: None,
+        meta_list: None,
+        admin_list: None,
+        pauser_list: None,
+        controller_list: None,
+        none_list: Some(vec![Key::from(account_user_1)]),
+    };
+    let change_security_call =
+        cep85_change_security(&mut builder, &cep85_token, &installer, security_lists);
+    change_security_call.expect_success().commit();
+
+    assert_eq!(
+        cep85_check_role_members(
+            &mut builder,
+            &cep85_test_contract_package,
+            SecurityBadge::Minter,
+            0,
+            10
+        ),
+        vec![Key::from(account_user_2)]
+    );
+    assert_eq!(
+        cep85_check_role_member_count(
+            &mut builder,
+            &cep85_test_contract_package,
+            SecurityBadge::Minter
+        ),
+        1
+    );
+}
+
+#[test]
+fn should_grant_and_revoke_roles_independently() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let installer = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let minting_recipient = Key::from(account_user_1);
+    let id = U256::one();
+
+    let security_lists = SecurityLists {
+        minter_list: Some(vec![Key::from(account_user_1)]),
+        burner_list: None,
+        meta_list: None,
+        admin_list: None,
+        pauser_list: None,
+        controller_list: None,
+        none_list: None,
+    };
+    let change_security_call =
+        cep85_change_security(&mut builder, &cep85_token, &installer, security_lists);
+    change_security_call.expect_success().commit();
+
+    let security_lists = SecurityLists {
+        minter_list: None,
+        burner_list: None,
+        meta_list: Some(vec![Key::from(account_user_1)]),
+        admin_list: None,
+        pauser_list: None,
+        controller_list: None,
+        none_list: None,
+    };
+    let change_security_call =
+        cep85_change_security(&mut builder, &cep85_token, &installer, security_lists);
+    change_security_call.expect_success().commit();
+
+    assert_eq!(
+        cep85_check_get_security_badge(
+            &mut builder,
+            &cep85_test_contract_package,
+            &Key::from(account_user_1)
+        ),
+        vec![SecurityBadge::Minter, SecurityBadge::Meta]
+    );
+
+    // account_user_1 kept its minter badge when granted the meta badge
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &minting_recipient,
+        &id,
+        &U256::one(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let security_lists = SecurityLists {
+        minter_list: Some(vec![Key::from(account_user_1)]),
+        burner_list: None,
+        meta_list: None,
+        admin_list: None,
+        pauser_list: None,
+        controller_list: None,
+        none_list: None,
+    };
+    let revoke_security_call =
+        cep85_revoke_security(&mut builder, &cep85_token, &installer, security_lists);
+    revoke_security_call.expect_success().commit();
+
+    let failing_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &minting_recipient,
+        &id,
+        &U256::one(),
+        None,
+    );
+    failing_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "revoked minter should not mint",
+    );
+
+    let meta_call = cep85_set_uri(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        TOKEN_URI_TEST,
+        Some(id),
+    );
+    meta_call.expect_success().commit();
+
+    assert_eq!(
+        cep85_check_role_members(
+            &mut builder,
+            &cep85_test_contract_package,
+            SecurityBadge::Meta,
+            0,
+            10
+        ),
+        vec![Key::from(account_user_1)]
+    );
+    assert_eq!(
+        cep85_check_role_member_count(
+            &mut builder,
+            &cep85_test_contract_package,
+            SecurityBadge::Minter
+        ),
+        0
+    );
+}
+
+#[test]
+fn should_let_an_admin_call_admin_entry_points_directly() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    let set_uri_call = cep85_set_uri(&mut builder, &cep85_token, &admin, TOKEN_URI_TEST, None);
+    set_uri_call.expect_success().commit();
+
+    let uri = cep85_check_uri(&mut builder, &cep85_test_contract_package, None);
+    assert_eq!(uri, Some(TOKEN_URI_TEST.to_string()));
+
+    let security_lists = SecurityLists {
+        minter_list: Some(vec![Key::from(account_user_1)]),
+        burner_list: None,
+        meta_list: None,
+        admin_list: None,
+        pauser_list: None,
+        controller_list: None,
+        none_list: None,
+    };
+    let change_security_call =
+        cep85_change_security(&mut builder, &cep85_token, &admin, security_lists);
+    change_security_call.expect_success().commit();
+
+    assert_eq!(
+        cep85_check_get_security_badge(
+            &mut builder,
+            &cep85_test_contract_package,
+            &Key::from(account_user_1)
+        ),
+        vec![SecurityBadge::Minter]
+    );
+}
@@ -0,0 +1,192 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, TOKEN_URI},
+    installer_request_builders::{
+        cep85_check_balance_of_type, cep85_check_is_non_fungible, cep85_check_uri,
+        cep85_create_type, cep85_mint, cep85_transfer_from, setup, TestContext, TransferData,
+    },
+    support::assert_expected_error,
+};
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{Key, U256};
+use cep85::{error::Cep85Error, utils::replace_token_id_in_uri};
+
+const TYPE_URI: &str = "https://type-cdn-domain/{id}.json";
+
+#[test]
+fn should_mint_and_count_items_of_split_id_types() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let owner = Key::from(admin);
+    let recipient = Key::from(*test_accounts.get(&ACCOUNT_USER_1).unwrap());
+    let type_id = U256::one() << 128;
+    let first_item_id = type_id + 1;
+    let second_item_id = type_id + 2;
+    let item_uri = "https://item-cdn-domain/2.json";
+
+    let create_type_call =
+        cep85_create_type(&mut builder, &cep85_token, &admin, &type_id, Some(TYPE_URI));
+    create_type_call.expect_success().commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &first_item_id,
+        &U256::one(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &second_item_id,
+        &U256::one(),
+        Some(item_uri),
+    );
+    mint_call.expect_success().commit();
+
+    let is_non_fungible =
+        cep85_check_is_non_fungible(&mut builder, &cep85_test_contract_package, &first_item_id);
+    assert_eq!(is_non_fungible, Some(true));
+
+    let type_balance =
+        cep85_check_balance_of_type(&mut builder, &cep85_test_contract_package, &owner, &type_id);
+    assert_eq!(type_balance, Some(U256::from(2)));
+
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        TransferData {
+            from: &owner,
+            to: &recipient,
+            ids: vec![first_item_id],
+            amounts: vec![U256::one()],
+            data: None,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    let type_balance =
+        cep85_check_balance_of_type(&mut builder, &cep85_test_contract_package, &owner, &type_id);
+    assert_eq!(type_balance, Some(U256::one()));
+
+    // Any item id resolves to its type
+    let type_balance = cep85_check_balance_of_type(
+        &mut builder,
+        &cep85_test_contract_package,
+        &recipient,
+        &second_item_id,
+    );
+    assert_eq!(type_balance, Some(U256::one()));
+
+    let type_balance = cep85_check_balance_of_type(
+        &mut builder,
+        &cep85_test_contract_package,
+        &owner,
+        &(U256::from(2) << 128),
+    );
+    assert_eq!(type_balance, None);
+
+    // The uri of an item falls back from the item to its type, then to the global uri
+    let actual_uri = cep85_check_uri(
+        &mut builder,
+        &cep85_test_contract_package,
+        Some(second_item_id),
+    );
+    assert_eq!(actual_uri, Some(item_uri.to_string()));
+
+    let actual_uri = cep85_check_uri(
+        &mut builder,
+        &cep85_test_contract_package,
+        Some(first_item_id),
+    );
+    assert_eq!(
+        actual_uri,
+        Some(replace_token_id_in_uri(TYPE_URI, &first_item_id))
+    );
+
+    let untyped_id = (U256::from(2) << 128) + 1;
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &untyped_id,
+        &U256::one(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let actual_uri = cep85_check_uri(&mut builder, &cep85_test_contract_package, Some(untyped_id));
+    assert_eq!(
+        actual_uri,
+        Some(replace_token_id_in_uri(TOKEN_URI, &untyped_id))
+    );
+}
+
+#[test]
+fn should_not_create_invalid_types_or_mint_fungible_items() {
+    let (mut builder, TestContext { cep85_token, .. }) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let owner = Key::from(admin);
+    let type_id = U256::one() << 128;
+
+    let failing_create_type_call =
+        cep85_create_type(&mut builder, &cep85_token, &admin, &(type_id + 1), None);
+    failing_create_type_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidTypeId as u16,
+        "a type id has no item index",
+    );
+
+    let create_type_call = cep85_create_type(&mut builder, &cep85_token, &admin, &type_id, None);
+    create_type_call.expect_success().commit();
+
+    let failing_create_type_call =
+        cep85_create_type(&mut builder, &cep85_token, &admin, &type_id, None);
+    failing_create_type_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TypeAlreadyCreated as u16,
+        "should not create the same type twice",
+    );
+
+    let failing_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &owner,
+        &(type_id + 1),
+        &U256::from(2),
+        None,
+    );
+    failing_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ExceededMaxTotalSupply as u16,
+        "an item of a type is non-fungible",
+    );
+}
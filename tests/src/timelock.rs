@@ -0,0 +1,345 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2},
+    installer_request_builders::{
+        cep85_approve_proposal, cep85_cancel_operation, cep85_check_total_supply_of,
+        cep85_create_proposal, cep85_execute_operation, cep85_grant_scoped_role,
+        cep85_queue_operation, cep85_set_total_supply_of, setup_with_args, TestContext,
+    },
+    support::{assert_expected_error, create_dummy_key_pair, fund_account},
+};
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cep85::{
+    constants::{
+        ADMIN_LIST, ARG_ACCOUNT, ARG_ADMIN_THRESHOLD, ARG_BADGE, ARG_END_ID, ARG_ID,
+        ARG_TIMELOCK_DELAY, ARG_TOTAL_SUPPLY, ENTRY_POINT_GRANT_SCOPED_ROLE, ENTRY_POINT_MINT,
+        ENTRY_POINT_SET_MODALITIES, ENTRY_POINT_SET_TOTAL_SUPPLY_OF,
+    },
+    error::Cep85Error,
+    security::SecurityBadge,
+};
+use std::collections::HashMap;
+
+const TIMELOCK_DELAY: u64 = 1000;
+
+#[test]
+fn should_execute_queued_operation_after_delay() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_TIMELOCK_DELAY => TIMELOCK_DELAY,
+        },
+        None,
+    );
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let id = U256::one();
+    let total_supply = U256::from(10);
+
+    let failing_set_total_supply_call =
+        cep85_set_total_supply_of(&mut builder, &cep85_token, &admin, &id, &total_supply);
+    failing_set_total_supply_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TimelockedAction as u16,
+        "timelocked actions should not be called directly",
+    );
+
+    let queue_operation_call = cep85_queue_operation(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_SET_TOTAL_SUPPLY_OF,
+        runtime_args! {
+            ARG_ID => id,
+            ARG_TOTAL_SUPPLY => total_supply,
+        },
+    );
+    queue_operation_call.expect_success().commit();
+
+    let operation_id = 0u32;
+
+    let failing_execute_operation_call = cep85_execute_operation(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        operation_id,
+        TIMELOCK_DELAY - 1,
+    );
+    failing_execute_operation_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::OperationNotReady as u16,
+        "should not execute an operation before its delay elapsed",
+    );
+
+    let total_supply_of =
+        cep85_check_total_supply_of(&mut builder, &cep85_test_contract_package, &id);
+    assert_eq!(total_supply_of, None);
+
+    let execute_operation_call = cep85_execute_operation(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        operation_id,
+        TIMELOCK_DELAY,
+    );
+    execute_operation_call.expect_success().commit();
+
+    let total_supply_of =
+        cep85_check_total_supply_of(&mut builder, &cep85_test_contract_package, &id);
+    assert_eq!(total_supply_of, Some(total_supply));
+
+    let failing_execute_operation_call = cep85_execute_operation(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        operation_id,
+        TIMELOCK_DELAY,
+    );
+    failing_execute_operation_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::OperationNotQueued as u16,
+        "should not execute an operation twice",
+    );
+
+    // Lifting the timelock is itself timelocked
+    let queue_operation_call = cep85_queue_operation(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_SET_MODALITIES,
+        runtime_args! {
+            ARG_TIMELOCK_DELAY => 0u64,
+        },
+    );
+    queue_operation_call.expect_success().commit();
+
+    let execute_operation_call =
+        cep85_execute_operation(&mut builder, &cep85_token, &admin, 1, TIMELOCK_DELAY);
+    execute_operation_call.expect_success().commit();
+
+    let set_total_supply_call =
+        cep85_set_total_supply_of(&mut builder, &cep85_token, &admin, &id, &U256::from(20));
+    set_total_supply_call.expect_success().commit();
+}
+
+#[test]
+fn should_not_execute_cancelled_or_invalid_operations() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_TIMELOCK_DELAY => TIMELOCK_DELAY,
+        },
+        None,
+    );
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let total_supply_args = runtime_args! {
+        ARG_ID => U256::one(),
+        ARG_TOTAL_SUPPLY => U256::from(10),
+    };
+
+    let failing_queue_operation_call = cep85_queue_operation(
+        &mut builder,
+        &cep85_token,
+        &account_user_2,
+        ENTRY_POINT_SET_TOTAL_SUPPLY_OF,
+        total_supply_args.clone(),
+    );
+    failing_queue_operation_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only admins can queue operations",
+    );
+
+    let failing_queue_operation_call = cep85_queue_operation(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_MINT,
+        RuntimeArgs::new(),
+    );
+    failing_queue_operation_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidAction as u16,
+        "only timelocked actions can be queued",
+    );
+
+    let queue_operation_call = cep85_queue_operation(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_SET_TOTAL_SUPPLY_OF,
+        total_supply_args,
+    );
+    queue_operation_call.expect_success().commit();
+
+    let cancel_operation_call = cep85_cancel_operation(&mut builder, &cep85_token, &admin, 0);
+    cancel_operation_call.expect_success().commit();
+
+    let failing_execute_operation_call =
+        cep85_execute_operation(&mut builder, &cep85_token, &admin, 0, TIMELOCK_DELAY);
+    failing_execute_operation_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::OperationNotQueued as u16,
+        "a cancelled operation cannot be executed",
+    );
+
+    let failing_cancel_operation_call =
+        cep85_cancel_operation(&mut builder, &cep85_token, &admin, 1);
+    failing_cancel_operation_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::OperationNotFound as u16,
+        "an unknown operation cannot be cancelled",
+    );
+}
+
+#[test]
+fn should_queue_approved_proposals_of_timelocked_actions() {
+    let (_, public_key_account_user_1) = create_dummy_key_pair(ACCOUNT_USER_1);
+    let account_user_1 = public_key_account_user_1.to_account_hash();
+    let mut test_accounts = HashMap::new();
+    test_accounts.insert(ACCOUNT_USER_1, account_user_1);
+
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ADMIN_LIST => vec![Key::from(*DEFAULT_ACCOUNT_ADDR), Key::from(account_user_1)],
+            ARG_ADMIN_THRESHOLD => 2u32,
+            ARG_TIMELOCK_DELAY => TIMELOCK_DELAY,
+        },
+        Some(test_accounts),
+    );
+
+    // account_user_1 was created before genesis and is not yet funded so fund it
+    fund_account(&mut builder, account_user_1);
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let id = U256::one();
+    let total_supply = U256::from(10);
+
+    let create_proposal_call = cep85_create_proposal(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_SET_TOTAL_SUPPLY_OF,
+        runtime_args! {
+            ARG_ID => id,
+            ARG_TOTAL_SUPPLY => total_supply,
+        },
+        None,
+    );
+    create_proposal_call.expect_success().commit();
+
+    let approve_proposal_call =
+        cep85_approve_proposal(&mut builder, &cep85_token, &account_user_1, 0);
+    approve_proposal_call.expect_success().commit();
+
+    let total_supply_of =
+        cep85_check_total_supply_of(&mut builder, &cep85_test_contract_package, &id);
+    assert_eq!(total_supply_of, None);
+
+    let execute_operation_call =
+        cep85_execute_operation(&mut builder, &cep85_token, &admin, 0, TIMELOCK_DELAY);
+    execute_operation_call.expect_success().commit();
+
+    let total_supply_of =
+        cep85_check_total_supply_of(&mut builder, &cep85_test_contract_package, &id);
+    assert_eq!(total_supply_of, Some(total_supply));
+}
+
+#[test]
+fn should_timelock_granting_scoped_roles() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_TIMELOCK_DELAY => TIMELOCK_DELAY,
+        },
+        None,
+    );
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = Key::from(*test_accounts.get(&ACCOUNT_USER_1).unwrap());
+    let id = U256::one();
+
+    let failing_grant_scoped_role_call = cep85_grant_scoped_role(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &account_user_1,
+        SecurityBadge::Minter,
+        &id,
+        &id,
+    );
+    failing_grant_scoped_role_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TimelockedAction as u16,
+        "granting scoped roles should be timelocked",
+    );
+
+    let queue_operation_call = cep85_queue_operation(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        ENTRY_POINT_GRANT_SCOPED_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => account_user_1,
+            ARG_BADGE => SecurityBadge::Minter,
+            ARG_ID => id,
+            ARG_END_ID => id,
+        },
+    );
+    queue_operation_call.expect_success().commit();
+
+    let execute_operation_call =
+        cep85_execute_operation(&mut builder, &cep85_token, &admin, 0, TIMELOCK_DELAY);
+    execute_operation_call.expect_success().commit();
+}
@@ -0,0 +1,202 @@
+use crate::utility::{
+    constants::ACCOUNT_USER_1,
+    installer_request_builders::{
+        cep85_check_is_non_fungible, cep85_check_total_supply_of, cep85_check_uri,
+        cep85_create_token, cep85_create_token_batch, cep85_mint, setup, setup_with_args,
+        TestContext,
+    },
+    support::assert_expected_error,
+};
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cep85::{constants::ARG_REGISTERED_IDS_ONLY, error::Cep85Error, modalities::TokenKind};
+
+#[test]
+fn should_create_tokens_with_explicit_kind() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let nft_id = U256::one();
+    let uri = "https://example.com/1.json";
+
+    let create_token_call = cep85_create_token(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &nft_id,
+        TokenKind::NonFungible,
+        &U256::one(),
+        Some(uri),
+    );
+    create_token_call.expect_success().commit();
+
+    // A fungible id with a total supply of one is not mistaken for an NFT
+    let create_token_batch_call = cep85_create_token_batch(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        vec![U256::from(2), U256::from(3)],
+        vec![TokenKind::Fungible, TokenKind::SemiFungible],
+        vec![U256::one(), U256::from(100)],
+        None,
+    );
+    create_token_batch_call.expect_success().commit();
+
+    let is_non_fungible =
+        cep85_check_is_non_fungible(&mut builder, &cep85_test_contract_package, &nft_id);
+    assert_eq!(is_non_fungible, Some(true));
+
+    let is_non_fungible =
+        cep85_check_is_non_fungible(&mut builder, &cep85_test_contract_package, &U256::from(2));
+    assert_eq!(is_non_fungible, Some(false));
+
+    let total_supply =
+        cep85_check_total_supply_of(&mut builder, &cep85_test_contract_package, &U256::from(3));
+    assert_eq!(total_supply, Some(U256::from(100)));
+
+    let actual_uri = cep85_check_uri(&mut builder, &cep85_test_contract_package, Some(nft_id));
+    assert_eq!(actual_uri, Some(uri.to_string()));
+
+    let failing_create_token_call = cep85_create_token(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &nft_id,
+        TokenKind::Fungible,
+        &U256::from(10),
+        None,
+    );
+    failing_create_token_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TokenAlreadyCreated as u16,
+        "should not create the same id twice",
+    );
+
+    let failing_create_token_call = cep85_create_token(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &U256::from(4),
+        TokenKind::NonFungible,
+        &U256::from(2),
+        None,
+    );
+    failing_create_token_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidTotalSupply as u16,
+        "a non-fungible id has a total supply of one",
+    );
+
+    let failing_create_token_call = cep85_create_token(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        &U256::from(4),
+        TokenKind::Fungible,
+        &U256::from(2),
+        None,
+    );
+    failing_create_token_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "only admins can create tokens",
+    );
+}
+
+#[test]
+fn should_only_mint_created_ids_in_registered_ids_only_mode() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_REGISTERED_IDS_ONLY => true,
+        },
+        None,
+    );
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let recipient = Key::from(account_user_1);
+    let id = U256::one();
+
+    let failing_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &recipient,
+        &id,
+        &U256::one(),
+        None,
+    );
+    failing_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::TokenNotCreated as u16,
+        "should not mint an id that was not created",
+    );
+
+    let create_token_call = cep85_create_token(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &id,
+        TokenKind::NonFungible,
+        &U256::one(),
+        None,
+    );
+    create_token_call.expect_success().commit();
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &recipient,
+        &id,
+        &U256::one(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let failing_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &admin,
+        &recipient,
+        &id,
+        &U256::one(),
+        None,
+    );
+    failing_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ExceededMaxTotalSupply as u16,
+        "should not mint more than the created total supply",
+    );
+}
@@ -2,18 +2,19 @@ use crate::utility::{
     constants::{ACCOUNT_USER_1, ACCOUNT_USER_2},
     installer_request_builders::{
         cep85_batch_mint, cep85_batch_transfer_from, cep85_check_balance_of,
-        cep85_check_balance_of_batch, cep85_mint, cep85_set_total_supply_of_batch,
-        cep85_transfer_from, setup, setup_with_args, TestContext, TransferData,
+        cep85_check_balance_of_batch, cep85_mint, cep85_set_receiver_return_value,
+        cep85_set_total_supply_of_batch, cep85_transfer_from, setup, setup_with_args, TestContext,
+        TransferData,
     },
     support::{assert_expected_error, get_event},
 };
 use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
 use casper_types::{bytesrepr::Bytes, runtime_args, Key, RuntimeArgs, U256};
 use cep85::{
-    constants::ARG_EVENTS_MODE,
+    constants::{ARG_ENABLE_RECEIVER_CHECK, ARG_EVENTS_MODE},
     error::Cep85Error,
     events::{Transfer, TransferBatch},
-    modalities::EventsMode,
+    modalities::{EventsMode, TokenReceiverResult},
 };
 
 #[test]
@@ -1775,3 +1776,300 @@ fn should_revert_on_transfer_batch_of_non_existent_token() {
         "should return error when trying to burn a non_existing token",
     );
 }
+
+#[test]
+fn should_only_transfer_to_contract_accepting_tokens_with_receiver_check() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract,
+            cep85_test_contract_package,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_ENABLE_RECEIVER_CHECK => true,
+        },
+        None,
+    );
+
+    let minting_account = *DEFAULT_ACCOUNT_ADDR;
+    let from = Key::from(minting_account);
+    let to = Key::from(cep85_test_contract);
+    let id = U256::one();
+    let amount = U256::one();
+    let data = Some(Bytes::from("Casper Labs free bytes".as_bytes()));
+
+    let mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &from,
+        &id,
+        &amount,
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    // Test contract rejects tokens by default
+    let failing_transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: vec![id],
+            amounts: vec![amount],
+            data: data.clone(),
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        Cep85Error::TransferRejectedByReceiver as u16,
+        "should not transfer to a contract that does not accept tokens",
+    );
+
+    let actual_balance_to =
+        cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &to, &id).unwrap();
+    assert_eq!(actual_balance_to, U256::zero());
+
+    cep85_set_receiver_return_value(
+        &mut builder,
+        &cep85_test_contract,
+        TokenReceiverResult::AcceptTransfer,
+    )
+    .expect_success()
+    .commit();
+
+    let transfer_call = cep85_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: vec![id],
+            amounts: vec![amount],
+            data,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    let actual_balance_to =
+        cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &to, &id).unwrap();
+    assert_eq!(actual_balance_to, amount);
+}
+
+#[test]
+fn should_only_batch_transfer_to_contract_accepting_tokens_with_receiver_check() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract,
+            cep85_test_contract_package,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_ENABLE_RECEIVER_CHECK => true,
+        },
+        None,
+    );
+
+    let minting_account = *DEFAULT_ACCOUNT_ADDR;
+    let from = Key::from(minting_account);
+    let to = Key::from(cep85_test_contract);
+    let ids: Vec<U256> = vec![U256::one(), U256::from(2)];
+    let amounts: Vec<U256> = vec![U256::one(), U256::from(2)];
+    let data = Some(Bytes::from("Casper Labs free bytes".as_bytes()));
+
+    let mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &from,
+        ids.clone(),
+        amounts.clone(),
+        None,
+    );
+    mint_call.expect_success().commit();
+
+    let failing_transfer_call = cep85_batch_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: ids.clone(),
+            amounts: amounts.clone(),
+            data: data.clone(),
+        },
+        None,
+    );
+    failing_transfer_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        Cep85Error::TransferRejectedByReceiver as u16,
+        "should not batch transfer to a contract that does not accept tokens",
+    );
+
+    cep85_set_receiver_return_value(
+        &mut builder,
+        &cep85_test_contract,
+        TokenReceiverResult::AcceptTransfer,
+    )
+    .expect_success()
+    .commit();
+
+    let transfer_call = cep85_batch_transfer_from(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        TransferData {
+            from: &from,
+            to: &to,
+            ids: ids.clone(),
+            amounts: amounts.clone(),
+            data,
+        },
+        None,
+    );
+    transfer_call.expect_success().commit();
+
+    let actual_balances_to = cep85_check_balance_of_batch(
+        &mut builder,
+        &cep85_test_contract_package,
+        vec![to, to],
+        ids,
+    );
+
+    assert_eq!(
+        actual_balances_to,
+        amounts
+            .iter()
+            .map(|&amount| Some(amount))
+            .collect::<Vec<Option<U256>>>()
+    );
+}
+
+#[test]
+fn should_only_mint_to_contract_accepting_tokens_with_receiver_check() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract,
+            cep85_test_contract_package,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_ENABLE_RECEIVER_CHECK => true,
+        },
+        None,
+    );
+
+    let minting_account = *DEFAULT_ACCOUNT_ADDR;
+    let recipient = Key::from(cep85_test_contract);
+    let id = U256::one();
+    let ids: Vec<U256> = vec![U256::from(2), U256::from(3)];
+    let amount = U256::one();
+    let amounts: Vec<U256> = vec![U256::one(), U256::one()];
+
+    let failing_mint_call = cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &recipient,
+        &id,
+        &amount,
+        None,
+    );
+    failing_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        Cep85Error::TransferRejectedByReceiver as u16,
+        "should not mint to a contract that does not accept tokens",
+    );
+
+    let failing_batch_mint_call = cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &recipient,
+        ids.clone(),
+        amounts.clone(),
+        None,
+    );
+    failing_batch_mint_call.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        Cep85Error::TransferRejectedByReceiver as u16,
+        "should not batch mint to a contract that does not accept tokens",
+    );
+
+    cep85_set_receiver_return_value(
+        &mut builder,
+        &cep85_test_contract,
+        TokenReceiverResult::AcceptTransfer,
+    )
+    .expect_success()
+    .commit();
+
+    cep85_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &recipient,
+        &id,
+        &amount,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    cep85_batch_mint(
+        &mut builder,
+        &cep85_token,
+        &minting_account,
+        &recipient,
+        ids.clone(),
+        amounts,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    let actual_balance =
+        cep85_check_balance_of(&mut builder, &cep85_test_contract_package, &recipient, &id)
+            .unwrap();
+    assert_eq!(actual_balance, amount);
+
+    let actual_balance = cep85_check_balance_of(
+        &mut builder,
+        &cep85_test_contract_package,
+        &recipient,
+        &ids[1],
+    )
+    .unwrap();
+    assert_eq!(actual_balance, U256::one());
+}
@@ -1,17 +1,25 @@
 use crate::utility::{
     constants::{
-        CEP85_CONTRACT_WASM, CEP85_TEST_TOKEN_CONTRACT_NAME, CEP85_TEST_TOKEN_CONTRACT_VERSION,
-        TOKEN_NAME,
+        ACCOUNT_USER_1, CEP85_CONTRACT_WASM, CEP85_TEST_TOKEN_CONTRACT_NAME,
+        CEP85_TEST_TOKEN_CONTRACT_VERSION, TOKEN_NAME,
     },
-    installer_request_builders::{setup, setup_with_args, TestContext},
-    support::get_event,
+    installer_request_builders::{
+        cep85_change_security, cep85_check_role_member_count, cep85_check_role_members, setup,
+        setup_with_args, SecurityLists, TestContext,
+    },
+    support::{assert_expected_error, get_event},
 };
 use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
 use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
 use cep85::{
-    constants::{ARG_CONTRACT_HASH, ARG_EVENTS_MODE, ARG_NAME, ARG_UPGRADE_FLAG},
+    constants::{
+        ADMIN_LIST, ARG_CONTRACT_HASH, ARG_EVENTS_MODE, ARG_NAME, ARG_UPGRADE_FLAG,
+        ENTRY_POINT_UPGRADE, MINTER_LIST,
+    },
+    error::Cep85Error,
     events::Upgrade,
     modalities::EventsMode,
+    security::SecurityBadge,
 };
 
 #[test]
@@ -133,3 +141,243 @@ fn should_emit_event_on_upgrade_with_events_mode_ces() {
     let actual_event: Upgrade = get_event(&builder, &cep85_token.into(), 0);
     assert_eq!(actual_event, expected_event, "Expected Upgrade event.");
 }
+
+#[test]
+fn should_not_allow_outsider_to_call_upgrade_entry_point() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    let contract_hash_before: Key = *builder
+        .get_contract(cep85_token)
+        .expect("should have contract")
+        .named_keys()
+        .get(ARG_CONTRACT_HASH)
+        .expect("should have contract hash");
+
+    let failing_upgrade_call = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        cep85_token,
+        ENTRY_POINT_UPGRADE,
+        runtime_args! {
+            ARG_CONTRACT_HASH => Key::from(account_user_1),
+        },
+    )
+    .build();
+    builder.exec(failing_upgrade_call).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InsufficientRights as u16,
+        "outsiders should not call upgrade",
+    );
+
+    let contract_hash_after: Key = *builder
+        .get_contract(cep85_token)
+        .expect("should have contract")
+        .named_keys()
+        .get(ARG_CONTRACT_HASH)
+        .expect("should have contract hash");
+
+    assert_eq!(contract_hash_before, contract_hash_after);
+}
+
+#[test]
+fn should_allow_installer_without_admin_badge_to_upgrade() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let installer = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    let change_security_call = cep85_change_security(
+        &mut builder,
+        &cep85_token,
+        &installer,
+        SecurityLists {
+            minter_list: None,
+            burner_list: None,
+            meta_list: None,
+            admin_list: Some(vec![Key::from(account_user_1)]),
+            pauser_list: None,
+            controller_list: None,
+            none_list: None,
+        },
+    );
+    change_security_call.expect_success().commit();
+
+    let change_security_call = cep85_change_security(
+        &mut builder,
+        &cep85_token,
+        &account_user_1,
+        SecurityLists {
+            minter_list: None,
+            burner_list: None,
+            meta_list: None,
+            admin_list: None,
+            pauser_list: None,
+            controller_list: None,
+            none_list: Some(vec![Key::from(installer)]),
+        },
+    );
+    change_security_call.expect_success().commit();
+
+    let upgrade_request_contract = ExecuteRequestBuilder::standard(
+        installer,
+        CEP85_CONTRACT_WASM,
+        runtime_args! {
+            ARG_UPGRADE_FLAG => true,
+            ARG_NAME => TOKEN_NAME,
+        },
+    )
+    .build();
+
+    builder
+        .exec(upgrade_request_contract)
+        .expect_success()
+        .commit();
+
+    let cep85_token_contract_version = builder
+        .query(
+            None,
+            Key::from(installer),
+            &[CEP85_TEST_TOKEN_CONTRACT_VERSION.to_string()],
+        )
+        .unwrap()
+        .as_cl_value()
+        .unwrap()
+        .to_owned()
+        .into_t::<u32>()
+        .unwrap();
+
+    assert_eq!(cep85_token_contract_version, 2_u32);
+}
+
+#[test]
+fn should_not_repoint_the_contract_hash_to_another_key() {
+    let (mut builder, TestContext { cep85_token, .. }) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+
+    let contract_hash_before: Key = *builder
+        .get_contract(cep85_token)
+        .expect("should have contract")
+        .named_keys()
+        .get(ARG_CONTRACT_HASH)
+        .expect("should have contract hash");
+
+    let failing_upgrade_call = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_UPGRADE,
+        runtime_args! {
+            ARG_CONTRACT_HASH => Key::from(admin),
+        },
+    )
+    .build();
+    builder.exec(failing_upgrade_call).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::InvalidContractHash as u16,
+        "the contract hash should only point to the upgraded contract",
+    );
+
+    let contract_hash_after: Key = *builder
+        .get_contract(cep85_token)
+        .expect("should have contract")
+        .named_keys()
+        .get(ARG_CONTRACT_HASH)
+        .expect("should have contract hash");
+
+    assert_eq!(contract_hash_before, contract_hash_after);
+}
+
+#[test]
+fn should_only_list_badge_holders_on_upgrade() {
+    let (
+        mut builder,
+        TestContext {
+            cep85_token,
+            cep85_test_contract_package,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let admin = *DEFAULT_ACCOUNT_ADDR;
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    let contract_hash: Key = *builder
+        .get_contract(cep85_token)
+        .expect("should have contract")
+        .named_keys()
+        .get(ARG_CONTRACT_HASH)
+        .expect("should have contract hash");
+
+    let failing_upgrade_call = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_UPGRADE,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+            MINTER_LIST => vec![Key::from(account_user_1)],
+        },
+    )
+    .build();
+    builder.exec(failing_upgrade_call).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        Cep85Error::ListedKeyWithoutBadge as u16,
+        "only badge holders should be listed as role members",
+    );
+
+    // Admins already listed as role members are not listed twice
+    let upgrade_call = ExecuteRequestBuilder::contract_call_by_hash(
+        admin,
+        cep85_token,
+        ENTRY_POINT_UPGRADE,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+            ADMIN_LIST => vec![Key::from(admin), Key::from(admin)],
+        },
+    )
+    .build();
+    builder.exec(upgrade_call).expect_success().commit();
+
+    assert_eq!(
+        cep85_check_role_member_count(
+            &mut builder,
+            &cep85_test_contract_package,
+            SecurityBadge::Admin
+        ),
+        1
+    );
+    assert_eq!(
+        cep85_check_role_members(
+            &mut builder,
+            &cep85_test_contract_package,
+            SecurityBadge::Admin,
+            0,
+            10
+        ),
+        vec![Key::from(admin)]
+    );
+}
@@ -11,33 +11,58 @@ use casper_engine_test_support::{
 };
 use casper_types::{
     account::AccountHash,
-    bytesrepr::{Bytes, FromBytes},
+    bytesrepr::{Bytes, FromBytes, ToBytes},
     runtime_args,
     system::mint::{ARG_ID, ARG_TO},
     CLTyped, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
 };
 use cep85::{
     constants::{
-        ADMIN_LIST, ARG_ACCOUNT, ARG_ACCOUNTS, ARG_AMOUNTS, ARG_APPROVED, ARG_DATA,
-        ARG_ENABLE_BURN, ARG_EVENTS_MODE, ARG_FROM, ARG_IDS, ARG_NAME, ARG_OPERATOR, ARG_OWNER,
-        ARG_RECIPIENT, ARG_SESSION_NAMED_KEY_NAME, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLIES,
-        ARG_TOTAL_SUPPLY, ARG_URI, BURNER_LIST, ENTRY_POINT_BATCH_BURN, ENTRY_POINT_BATCH_MINT,
-        ENTRY_POINT_BATCH_TRANSFER_FROM, ENTRY_POINT_BURN, ENTRY_POINT_CHANGE_SECURITY,
-        ENTRY_POINT_MAKE_DICTIONARY_ITEM_KEY, ENTRY_POINT_MINT, ENTRY_POINT_SET_APPROVAL_FOR_ALL,
-        ENTRY_POINT_SET_MODALITIES, ENTRY_POINT_SET_TOTAL_SUPPLY_OF,
-        ENTRY_POINT_SET_TOTAL_SUPPLY_OF_BATCH, ENTRY_POINT_SET_URI, ENTRY_POINT_TRANSFER_FROM,
-        META_LIST, MINTER_LIST, NONE_LIST,
+        ADMIN_LIST, ARG_ACCOUNT, ARG_ACCOUNTS, ARG_ACTION, ARG_ACTION_ARGS,
+        ARG_AFTER_TRANSFER_HOOK_CONTRACT, ARG_AFTER_TRANSFER_HOOK_METHOD, ARG_AMOUNTS,
+        ARG_APPROVED, ARG_BADGE, ARG_COUNT, ARG_DATA, ARG_DECIMALS, ARG_ENABLE_BURN,
+        ARG_ENABLE_RECEIVER_CHECK, ARG_END_ID, ARG_EVENTS_MODE, ARG_EXPIRES_AT, ARG_FROM, ARG_IDS,
+        ARG_INDEX, ARG_LIFETIME_LIMIT, ARG_LIMIT, ARG_NAME, ARG_NAMESPACE, ARG_OPERATION_ID,
+        ARG_OPERATOR, ARG_OWNER, ARG_PROPOSAL_ID, ARG_REASON, ARG_RECIPIENT, ARG_REVOKE,
+        ARG_SESSION_NAMED_KEY_NAME, ARG_SPENDER, ARG_START, ARG_SYMBOL, ARG_TOKEN_CONTRACT,
+        ARG_TOKEN_KIND, ARG_TOKEN_KINDS, ARG_TOTAL_SUPPLIES, ARG_TOTAL_SUPPLY,
+        ARG_TRANSFER_FILTER_CONTRACT, ARG_TRANSFER_FILTER_METHOD, ARG_URI, ARG_WINDOW,
+        ARG_WINDOW_LIMIT, BURNER_LIST, CONTROLLER_LIST, ENTRY_POINT_ACCEPT_ADMIN,
+        ENTRY_POINT_ADD_TRANSFER_FILTER, ENTRY_POINT_APPROVE, ENTRY_POINT_APPROVE_PROPOSAL,
+        ENTRY_POINT_BATCH_BURN, ENTRY_POINT_BATCH_LOCK_IDS, ENTRY_POINT_BATCH_MINT,
+        ENTRY_POINT_BATCH_MINT_NEXT, ENTRY_POINT_BATCH_TRANSFER_FROM, ENTRY_POINT_BATCH_UNLOCK_IDS,
+        ENTRY_POINT_BURN, ENTRY_POINT_CANCEL_OPERATION, ENTRY_POINT_CANCEL_PROPOSAL,
+        ENTRY_POINT_CHANGE_SECURITY, ENTRY_POINT_CREATE_PROPOSAL, ENTRY_POINT_CREATE_TOKEN,
+        ENTRY_POINT_CREATE_TOKEN_BATCH, ENTRY_POINT_CREATE_TYPE, ENTRY_POINT_DECREASE_ALLOWANCE,
+        ENTRY_POINT_EXECUTE_OPERATION, ENTRY_POINT_FORCE_BATCH_TRANSFER,
+        ENTRY_POINT_FORCE_TRANSFER, ENTRY_POINT_FREEZE_ACCOUNTS, ENTRY_POINT_GRANT_SCOPED_ROLE,
+        ENTRY_POINT_INCREASE_ALLOWANCE, ENTRY_POINT_LOCK_ID, ENTRY_POINT_MAKE_DICTIONARY_ITEM_KEY,
+        ENTRY_POINT_MINT, ENTRY_POINT_MINT_NEXT, ENTRY_POINT_PAUSE, ENTRY_POINT_PROPOSE_ADMIN,
+        ENTRY_POINT_QUEUE_OPERATION, ENTRY_POINT_REMOVE_TRANSFER_FILTER, ENTRY_POINT_RENOUNCE_ROLE,
+        ENTRY_POINT_REVOKE_SCOPED_ROLE, ENTRY_POINT_SET_AFTER_TRANSFER_HOOK,
+        ENTRY_POINT_SET_APPROVAL_FOR_ALL, ENTRY_POINT_SET_ID_NAMESPACE,
+        ENTRY_POINT_SET_METADATA_OF, ENTRY_POINT_SET_MINT_QUOTA, ENTRY_POINT_SET_MODALITIES,
+        ENTRY_POINT_SET_TOTAL_SUPPLY_OF, ENTRY_POINT_SET_TOTAL_SUPPLY_OF_BATCH,
+        ENTRY_POINT_SET_TRANSFER_FILTER, ENTRY_POINT_SET_URI, ENTRY_POINT_TRANSFER_FROM,
+        ENTRY_POINT_UNFREEZE_ACCOUNTS, ENTRY_POINT_UNLOCK_ID, ENTRY_POINT_UNPAUSE, META_LIST,
+        MINTER_LIST, NONE_LIST, PAUSER_LIST,
     },
-    modalities::EventsMode,
+    modalities::{EventsMode, TokenKind, TokenReceiverResult},
+    security::SecurityBadge,
 };
 use cep85_test_contract::constants::{
-    CEP85_TEST_CONTRACT_NAME, CEP85_TEST_PACKAGE_NAME, ENTRY_POINT_CHECK_BALANCE_OF,
-    ENTRY_POINT_CHECK_BALANCE_OF_BATCH, ENTRY_POINT_CHECK_BATCH_TRANSFER_FROM,
-    ENTRY_POINT_CHECK_IS_APPROVED_FOR_ALL, ENTRY_POINT_CHECK_IS_NON_FUNGIBLE,
+    ARG_RECEIVER_RETURN_VALUE, CEP85_TEST_CONTRACT_NAME, CEP85_TEST_PACKAGE_NAME,
+    ENTRY_POINT_CHECK_ALLOWANCE, ENTRY_POINT_CHECK_BALANCE_OF, ENTRY_POINT_CHECK_BALANCE_OF_BATCH,
+    ENTRY_POINT_CHECK_BALANCE_OF_TYPE, ENTRY_POINT_CHECK_BATCH_TRANSFER_FROM,
+    ENTRY_POINT_CHECK_GET_SECURITY_BADGE, ENTRY_POINT_CHECK_IS_APPROVED_FOR_ALL,
+    ENTRY_POINT_CHECK_IS_FROZEN, ENTRY_POINT_CHECK_IS_ID_LOCKED, ENTRY_POINT_CHECK_IS_NON_FUNGIBLE,
+    ENTRY_POINT_CHECK_IS_PAUSED, ENTRY_POINT_CHECK_METADATA_OF,
+    ENTRY_POINT_CHECK_METADATA_OF_BATCH, ENTRY_POINT_CHECK_REMAINING_MINT_QUOTA,
+    ENTRY_POINT_CHECK_ROLE_MEMBERS, ENTRY_POINT_CHECK_ROLE_MEMBER_COUNT,
     ENTRY_POINT_CHECK_SUPPLY_OF, ENTRY_POINT_CHECK_SUPPLY_OF_BATCH,
     ENTRY_POINT_CHECK_TOTAL_FUNGIBLE_SUPPLY, ENTRY_POINT_CHECK_TOTAL_SUPPLY_OF,
     ENTRY_POINT_CHECK_TOTAL_SUPPLY_OF_BATCH, ENTRY_POINT_CHECK_TRANSFER_FROM,
-    ENTRY_POINT_CHECK_URI, RESULT_KEY,
+    ENTRY_POINT_CHECK_URI, ENTRY_POINT_SET_RECEIVER_RETURN_VALUE, RESULT_KEY,
 };
 use std::collections::HashMap;
 
@@ -188,6 +213,31 @@ pub fn cep85_set_uri<'a>(
     builder.exec(set_uri_request)
 }
 
+pub fn cep85_set_metadata_of<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    updating_account: &'a AccountHash,
+    id: &U256,
+    name: &str,
+    symbol: &str,
+    decimals: u8,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let set_metadata_of_args = runtime_args! {
+        ARG_ID => *id,
+        ARG_NAME => name,
+        ARG_SYMBOL => symbol,
+        ARG_DECIMALS => decimals,
+    };
+    let set_metadata_of_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *updating_account,
+        *cep85_token,
+        ENTRY_POINT_SET_METADATA_OF,
+        set_metadata_of_args,
+    )
+    .build();
+    builder.exec(set_metadata_of_request)
+}
+
 pub fn cep85_check_uri(
     builder: &mut InMemoryWasmTestBuilder,
     contract_package_hash: &ContractPackageHash,
@@ -263,6 +313,79 @@ pub fn cep85_batch_mint<'a>(
     builder.exec(mint_request)
 }
 
+pub fn cep85_set_id_namespace<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    namespace: &str,
+    start_id: &U256,
+    end_id: Option<U256>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut set_id_namespace_args = runtime_args! {
+        ARG_NAMESPACE => namespace,
+        ARG_ID => *start_id,
+    };
+    if let Some(end_id) = end_id {
+        let _ = set_id_namespace_args.insert(ARG_END_ID, end_id);
+    }
+    let set_id_namespace_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_SET_ID_NAMESPACE,
+        set_id_namespace_args,
+    )
+    .build();
+    builder.exec(set_id_namespace_request)
+}
+
+pub fn cep85_mint_next<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    minting_account: &'a AccountHash,
+    recipient: &Key,
+    namespace: Option<&str>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut mint_next_args = runtime_args! {
+        ARG_RECIPIENT => *recipient,
+    };
+    if let Some(namespace) = namespace {
+        let _ = mint_next_args.insert(ARG_NAMESPACE, namespace);
+    }
+    let mint_next_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *minting_account,
+        *cep85_token,
+        ENTRY_POINT_MINT_NEXT,
+        mint_next_args,
+    )
+    .build();
+    builder.exec(mint_next_request)
+}
+
+pub fn cep85_batch_mint_next<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    minting_account: &'a AccountHash,
+    recipient: &Key,
+    count: u32,
+    namespace: Option<&str>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut batch_mint_next_args = runtime_args! {
+        ARG_RECIPIENT => *recipient,
+        ARG_COUNT => count,
+    };
+    if let Some(namespace) = namespace {
+        let _ = batch_mint_next_args.insert(ARG_NAMESPACE, namespace);
+    }
+    let batch_mint_next_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *minting_account,
+        *cep85_token,
+        ENTRY_POINT_BATCH_MINT_NEXT,
+        batch_mint_next_args,
+    )
+    .build();
+    builder.exec(batch_mint_next_request)
+}
+
 pub fn cep85_burn<'a>(
     builder: &'a mut InMemoryWasmTestBuilder,
     contract_hash: &'a ContractHash,
@@ -331,6 +454,28 @@ pub fn cep85_check_balance_of(
     get_test_result(builder, *contract_package_hash)
 }
 
+pub fn cep85_check_balance_of_type(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+    account: &Key,
+    id: &U256,
+) -> Option<U256> {
+    let check_balance_args = runtime_args! {
+        ARG_ACCOUNT => *account,
+        ARG_ID => *id,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_BALANCE_OF_TYPE,
+        check_balance_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
 pub fn cep85_check_balance_of_batch(
     builder: &mut InMemoryWasmTestBuilder,
     contract_package_hash: &ContractPackageHash,
@@ -415,6 +560,87 @@ pub fn cep85_set_total_supply_of_batch<'a>(
     builder.exec(set_total_supply_of_batch_request)
 }
 
+pub fn cep85_create_token<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    id: &U256,
+    token_kind: TokenKind,
+    total_supply: &U256,
+    uri: Option<&str>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut create_token_args = runtime_args! {
+        ARG_ID => *id,
+        ARG_TOKEN_KIND => token_kind as u8,
+        ARG_TOTAL_SUPPLY => *total_supply,
+    };
+    if let Some(uri) = uri {
+        let _ = create_token_args.insert(ARG_URI, uri);
+    }
+    let create_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_CREATE_TOKEN,
+        create_token_args,
+    )
+    .build();
+    builder.exec(create_token_request)
+}
+
+pub fn cep85_create_token_batch<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    ids: Vec<U256>,
+    token_kinds: Vec<TokenKind>,
+    total_supplies: Vec<U256>,
+    uri: Option<&str>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let token_kinds: Vec<u8> = token_kinds
+        .into_iter()
+        .map(|token_kind| token_kind as u8)
+        .collect();
+    let mut create_token_batch_args = runtime_args! {
+        ARG_IDS => ids,
+        ARG_TOKEN_KINDS => token_kinds,
+        ARG_TOTAL_SUPPLIES => total_supplies,
+    };
+    if let Some(uri) = uri {
+        let _ = create_token_batch_args.insert(ARG_URI, uri);
+    }
+    let create_token_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_CREATE_TOKEN_BATCH,
+        create_token_batch_args,
+    )
+    .build();
+    builder.exec(create_token_batch_request)
+}
+
+pub fn cep85_create_type<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    id: &U256,
+    uri: Option<&str>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut create_type_args = runtime_args! {
+        ARG_ID => *id,
+    };
+    if let Some(uri) = uri {
+        let _ = create_type_args.insert(ARG_URI, uri);
+    }
+    let create_type_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_CREATE_TYPE,
+        create_type_args,
+    )
+    .build();
+    builder.exec(create_type_request)
+}
+
 pub fn cep85_check_total_supply_of_batch(
     builder: &mut InMemoryWasmTestBuilder,
     contract_package_hash: &ContractPackageHash,
@@ -475,6 +701,46 @@ pub fn cep85_check_supply_of_batch(
     get_test_result(builder, *contract_package_hash)
 }
 
+pub fn cep85_check_metadata_of(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+    id: &U256,
+) -> Option<(String, String, u8)> {
+    let check_metadata_of_args = runtime_args! {
+        ARG_ID => *id,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_METADATA_OF,
+        check_metadata_of_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
+pub fn cep85_check_metadata_of_batch(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+    ids: Vec<U256>,
+) -> Vec<Option<(String, String, u8)>> {
+    let check_metadata_of_batch_args = runtime_args! {
+        ARG_IDS => ids,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_METADATA_OF_BATCH,
+        check_metadata_of_batch_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
 pub fn cep85_set_approval_for_all<'a>(
     builder: &'a mut InMemoryWasmTestBuilder,
     cep85_token: &'a ContractHash,
@@ -495,6 +761,51 @@ pub fn cep85_set_approval_for_all<'a>(
     builder.exec(set_approval_for_all_request)
 }
 
+pub fn cep85_set_approval_for_all_with_expiry<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    owner: &'a AccountHash,
+    operator: &'a Key,
+    expires_at: u64,
+    block_time: u64,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let set_approval_for_all_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *owner,
+        *cep85_token,
+        ENTRY_POINT_SET_APPROVAL_FOR_ALL,
+        runtime_args! {
+            ARG_OPERATOR => *operator,
+            ARG_APPROVED => true,
+            ARG_EXPIRES_AT => expires_at,
+        },
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(set_approval_for_all_request)
+}
+
+pub fn cep85_check_approval_expires_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+    account: &Key,
+    operator: &Key,
+) -> Option<u64> {
+    let check_approval_expires_at_args = runtime_args! {
+        ARG_ACCOUNT => *account,
+        ARG_OPERATOR => *operator,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_APPROVAL_EXPIRES_AT,
+        check_approval_expires_at_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
 pub fn cep85_check_is_approved(
     builder: &mut InMemoryWasmTestBuilder,
     contract_package_hash: &ContractPackageHash,
@@ -517,6 +828,111 @@ pub fn cep85_check_is_approved(
     get_test_result(builder, *contract_package_hash)
 }
 
+fn cep85_allowance_call<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    entry_point: &str,
+    owner: &'a AccountHash,
+    spender: &'a Key,
+    id: &'a U256,
+    amount: &'a U256,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *owner,
+        *cep85_token,
+        entry_point,
+        runtime_args! {
+            ARG_SPENDER => *spender,
+            ARG_ID => *id,
+            ARG_AMOUNT => *amount,
+        },
+    )
+    .build();
+    builder.exec(allowance_request)
+}
+
+pub fn cep85_approve<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    owner: &'a AccountHash,
+    spender: &'a Key,
+    id: &'a U256,
+    amount: &'a U256,
+) -> &'a mut InMemoryWasmTestBuilder {
+    cep85_allowance_call(
+        builder,
+        cep85_token,
+        ENTRY_POINT_APPROVE,
+        owner,
+        spender,
+        id,
+        amount,
+    )
+}
+
+pub fn cep85_increase_allowance<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    owner: &'a AccountHash,
+    spender: &'a Key,
+    id: &'a U256,
+    amount: &'a U256,
+) -> &'a mut InMemoryWasmTestBuilder {
+    cep85_allowance_call(
+        builder,
+        cep85_token,
+        ENTRY_POINT_INCREASE_ALLOWANCE,
+        owner,
+        spender,
+        id,
+        amount,
+    )
+}
+
+pub fn cep85_decrease_allowance<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    owner: &'a AccountHash,
+    spender: &'a Key,
+    id: &'a U256,
+    amount: &'a U256,
+) -> &'a mut InMemoryWasmTestBuilder {
+    cep85_allowance_call(
+        builder,
+        cep85_token,
+        ENTRY_POINT_DECREASE_ALLOWANCE,
+        owner,
+        spender,
+        id,
+        amount,
+    )
+}
+
+pub fn cep85_check_allowance(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+    owner: &Key,
+    spender: &Key,
+    id: &U256,
+) -> U256 {
+    let check_allowance_args = runtime_args! {
+        ARG_OWNER => *owner,
+        ARG_SPENDER => *spender,
+        ARG_ID => *id,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_ALLOWANCE,
+        ENTRY_POINT_CHECK_APPROVAL_EXPIRES_AT,
+        check_allowance_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
 pub fn cep85_make_dictionary_item_key(
     builder: &mut InMemoryWasmTestBuilder,
     cep85_token: &ContractHash,
@@ -646,11 +1062,12 @@ pub fn cep85_transfer_from<'a>(
     builder.exec(transfer_request)
 }
 
-pub fn cep85_transfer_from_as_contract<'a>(
+pub fn cep85_force_transfer<'a>(
     builder: &'a mut InMemoryWasmTestBuilder,
-    contract_package_hash: &'a ContractPackageHash,
+    cep85_token: &'a ContractHash,
     sender: &'a AccountHash,
     transfer_data: TransferData<'a>,
+    reason: &str,
 ) -> &'a mut InMemoryWasmTestBuilder {
     let TransferData {
         from,
@@ -660,17 +1077,90 @@ pub fn cep85_transfer_from_as_contract<'a>(
         data,
     } = transfer_data;
 
-    let transfer_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+    let mut args = runtime_args! {
+        ARG_FROM => *from,
+        ARG_TO => *to,
+        ARG_ID => ids[0],
+        ARG_AMOUNT => amounts[0],
+        ARG_REASON => reason.to_string(),
+    };
+
+    if let Some(data) = data {
+        let _ = args.insert(ARG_DATA, data);
+    }
+
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
         *sender,
-        *contract_package_hash,
-        None,
-        ENTRY_POINT_CHECK_TRANSFER_FROM,
-        runtime_args! {
-            ARG_FROM => *from,
-            ARG_TO => *to,
-            ARG_ID => ids[0],
-            ARG_AMOUNT => amounts[0],
-            ARG_DATA => data,
+        *cep85_token,
+        ENTRY_POINT_FORCE_TRANSFER,
+        args,
+    )
+    .build();
+    builder.exec(force_transfer_request)
+}
+
+pub fn cep85_force_batch_transfer<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    transfer_data: TransferData<'a>,
+    reason: &str,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let TransferData {
+        from,
+        to,
+        ids,
+        amounts,
+        data,
+    } = transfer_data;
+
+    let mut args = runtime_args! {
+        ARG_FROM => *from,
+        ARG_TO => *to,
+        ARG_IDS => ids,
+        ARG_AMOUNTS => amounts,
+        ARG_REASON => reason.to_string(),
+    };
+
+    if let Some(data) = data {
+        let _ = args.insert(ARG_DATA, data);
+    }
+
+    let force_batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_FORCE_BATCH_TRANSFER,
+        args,
+    )
+    .build();
+    builder.exec(force_batch_transfer_request)
+}
+
+pub fn cep85_transfer_from_as_contract<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    contract_package_hash: &'a ContractPackageHash,
+    sender: &'a AccountHash,
+    transfer_data: TransferData<'a>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let TransferData {
+        from,
+        to,
+        ids,
+        amounts,
+        data,
+    } = transfer_data;
+
+    let transfer_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *sender,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_TRANSFER_FROM,
+        runtime_args! {
+            ARG_FROM => *from,
+            ARG_TO => *to,
+            ARG_ID => ids[0],
+            ARG_AMOUNT => amounts[0],
+            ARG_DATA => data,
         },
     )
     .build();
@@ -853,11 +1343,654 @@ pub fn cep85_set_modalities<'a>(
     builder.exec(set_modalities_request)
 }
 
+pub fn cep85_set_transfer_filter<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    transfer_filter_contract: Option<Key>,
+    transfer_filter_method: Option<&str>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut args = runtime_args! {};
+    if let Some(transfer_filter_contract) = transfer_filter_contract {
+        let _ = args.insert(ARG_TRANSFER_FILTER_CONTRACT, transfer_filter_contract);
+    };
+    if let Some(transfer_filter_method) = transfer_filter_method {
+        let _ = args.insert(ARG_TRANSFER_FILTER_METHOD, transfer_filter_method);
+    };
+    let set_transfer_filter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_SET_TRANSFER_FILTER,
+        args,
+    )
+    .build();
+    builder.exec(set_transfer_filter_request)
+}
+
+pub fn cep85_set_after_transfer_hook<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    after_transfer_hook_contract: Option<Key>,
+    after_transfer_hook_method: Option<&str>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut args = runtime_args! {};
+    if let Some(after_transfer_hook_contract) = after_transfer_hook_contract {
+        let _ = args.insert(
+            ARG_AFTER_TRANSFER_HOOK_CONTRACT,
+            after_transfer_hook_contract,
+        );
+    };
+    if let Some(after_transfer_hook_method) = after_transfer_hook_method {
+        let _ = args.insert(ARG_AFTER_TRANSFER_HOOK_METHOD, after_transfer_hook_method);
+    };
+    let set_after_transfer_hook_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_SET_AFTER_TRANSFER_HOOK,
+        args,
+    )
+    .build();
+    builder.exec(set_after_transfer_hook_request)
+}
+
+pub fn cep85_add_transfer_filter<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    transfer_filter_contract: Key,
+    transfer_filter_method: &str,
+    start_id: Option<U256>,
+    end_id: Option<U256>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut args = runtime_args! {
+        ARG_TRANSFER_FILTER_CONTRACT => transfer_filter_contract,
+        ARG_TRANSFER_FILTER_METHOD => transfer_filter_method,
+    };
+    if let Some(start_id) = start_id {
+        let _ = args.insert(ARG_ID, start_id);
+    };
+    if let Some(end_id) = end_id {
+        let _ = args.insert(ARG_END_ID, end_id);
+    };
+    let add_transfer_filter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_ADD_TRANSFER_FILTER,
+        args,
+    )
+    .build();
+    builder.exec(add_transfer_filter_request)
+}
+
+pub fn cep85_remove_transfer_filter<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    index: u32,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let remove_transfer_filter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_REMOVE_TRANSFER_FILTER,
+        runtime_args! {
+            ARG_INDEX => index,
+        },
+    )
+    .build();
+    builder.exec(remove_transfer_filter_request)
+}
+
+pub fn cep85_set_receiver_return_value<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_test_contract: &'a ContractHash,
+    value: TokenReceiverResult,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let set_receiver_return_value_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *cep85_test_contract,
+        ENTRY_POINT_SET_RECEIVER_RETURN_VALUE,
+        runtime_args! {
+            ARG_RECEIVER_RETURN_VALUE => value,
+        },
+    )
+    .build();
+    builder.exec(set_receiver_return_value_request)
+}
+
+pub fn cep85_pause<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request)
+}
+
+pub fn cep85_unpause<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let unpause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_UNPAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(unpause_request)
+}
+
+pub fn cep85_check_is_paused(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+) -> bool {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_IS_PAUSED,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
+pub fn cep85_grant_scoped_role<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    account: &'a Key,
+    badge: SecurityBadge,
+    start_id: &'a U256,
+    end_id: &'a U256,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let grant_scoped_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_GRANT_SCOPED_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => *account,
+            ARG_BADGE => badge,
+            ARG_ID => *start_id,
+            ARG_END_ID => *end_id,
+        },
+    )
+    .build();
+    builder.exec(grant_scoped_role_request)
+}
+
+pub fn cep85_revoke_scoped_role<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    account: &'a Key,
+    badge: SecurityBadge,
+    start_id: &'a U256,
+    end_id: &'a U256,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let revoke_scoped_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_REVOKE_SCOPED_ROLE,
+        runtime_args! {
+            ARG_ACCOUNT => *account,
+            ARG_BADGE => badge,
+            ARG_ID => *start_id,
+            ARG_END_ID => *end_id,
+        },
+    )
+    .build();
+    builder.exec(revoke_scoped_role_request)
+}
+
+pub struct MintQuotaLimits {
+    pub lifetime_limit: Option<U256>,
+    pub window_limit: Option<U256>,
+    pub window: u64,
+}
+
+pub fn cep85_set_mint_quota<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    account: &'a Key,
+    id: Option<U256>,
+    limits: MintQuotaLimits,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut set_mint_quota_args = runtime_args! {
+        ARG_ACCOUNT => *account,
+        ARG_WINDOW => limits.window,
+    };
+    if let Some(id) = id {
+        let _ = set_mint_quota_args.insert(ARG_ID, id);
+    }
+    if let Some(lifetime_limit) = limits.lifetime_limit {
+        let _ = set_mint_quota_args.insert(ARG_LIFETIME_LIMIT, lifetime_limit);
+    }
+    if let Some(window_limit) = limits.window_limit {
+        let _ = set_mint_quota_args.insert(ARG_WINDOW_LIMIT, window_limit);
+    }
+    let set_mint_quota_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_SET_MINT_QUOTA,
+        set_mint_quota_args,
+    )
+    .build();
+    builder.exec(set_mint_quota_request)
+}
+
+pub fn cep85_create_proposal<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    action: &str,
+    action_args: RuntimeArgs,
+    expires_at: Option<u64>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut create_proposal_args = runtime_args! {
+        ARG_ACTION => action,
+        ARG_ACTION_ARGS => Bytes::from(action_args.to_bytes().unwrap()),
+    };
+    if let Some(expires_at) = expires_at {
+        let _ = create_proposal_args.insert(ARG_EXPIRES_AT, expires_at);
+    }
+    let create_proposal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_CREATE_PROPOSAL,
+        create_proposal_args,
+    )
+    .build();
+    builder.exec(create_proposal_request)
+}
+
+pub fn cep85_approve_proposal<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    proposal_id: u32,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let approve_proposal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_APPROVE_PROPOSAL,
+        runtime_args! {
+            ARG_PROPOSAL_ID => proposal_id,
+        },
+    )
+    .build();
+    builder.exec(approve_proposal_request)
+}
+
+pub fn cep85_cancel_proposal<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    proposal_id: u32,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let cancel_proposal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_CANCEL_PROPOSAL,
+        runtime_args! {
+            ARG_PROPOSAL_ID => proposal_id,
+        },
+    )
+    .build();
+    builder.exec(cancel_proposal_request)
+}
+
+pub fn cep85_queue_operation<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    action: &str,
+    action_args: RuntimeArgs,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let queue_operation_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_QUEUE_OPERATION,
+        runtime_args! {
+            ARG_ACTION => action,
+            ARG_ACTION_ARGS => Bytes::from(action_args.to_bytes().unwrap()),
+        },
+    )
+    .build();
+    builder.exec(queue_operation_request)
+}
+
+pub fn cep85_execute_operation<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    operation_id: u32,
+    block_time: u64,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let execute_operation_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_EXECUTE_OPERATION,
+        runtime_args! {
+            ARG_OPERATION_ID => operation_id,
+        },
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(execute_operation_request)
+}
+
+pub fn cep85_cancel_operation<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    operation_id: u32,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let cancel_operation_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_CANCEL_OPERATION,
+        runtime_args! {
+            ARG_OPERATION_ID => operation_id,
+        },
+    )
+    .build();
+    builder.exec(cancel_operation_request)
+}
+
+pub fn cep85_lock_id<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    id: &'a U256,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let lock_id_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_LOCK_ID,
+        runtime_args! {
+            ARG_ID => *id,
+        },
+    )
+    .build();
+    builder.exec(lock_id_request)
+}
+
+pub fn cep85_unlock_id<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    id: &'a U256,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let unlock_id_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_UNLOCK_ID,
+        runtime_args! {
+            ARG_ID => *id,
+        },
+    )
+    .build();
+    builder.exec(unlock_id_request)
+}
+
+pub fn cep85_batch_lock_ids<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    ids: Vec<U256>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let batch_lock_ids_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_BATCH_LOCK_IDS,
+        runtime_args! {
+            ARG_IDS => ids,
+        },
+    )
+    .build();
+    builder.exec(batch_lock_ids_request)
+}
+
+pub fn cep85_batch_unlock_ids<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    ids: Vec<U256>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let batch_unlock_ids_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_BATCH_UNLOCK_IDS,
+        runtime_args! {
+            ARG_IDS => ids,
+        },
+    )
+    .build();
+    builder.exec(batch_unlock_ids_request)
+}
+
+pub fn cep85_check_is_id_locked(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+    id: &U256,
+) -> bool {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_IS_ID_LOCKED,
+        runtime_args! {
+            ARG_ID => *id,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
+pub fn cep85_freeze_accounts<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    accounts: Vec<Key>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let freeze_accounts_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_FREEZE_ACCOUNTS,
+        runtime_args! {
+            ARG_ACCOUNTS => accounts,
+        },
+    )
+    .build();
+    builder.exec(freeze_accounts_request)
+}
+
+pub fn cep85_unfreeze_accounts<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    accounts: Vec<Key>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let unfreeze_accounts_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_UNFREEZE_ACCOUNTS,
+        runtime_args! {
+            ARG_ACCOUNTS => accounts,
+        },
+    )
+    .build();
+    builder.exec(unfreeze_accounts_request)
+}
+
+pub fn cep85_check_is_frozen(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+    account: &Key,
+) -> bool {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_IS_FROZEN,
+        runtime_args! {
+            ARG_ACCOUNT => *account,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
+pub fn cep85_check_get_security_badge(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+    account: &Key,
+) -> Vec<SecurityBadge> {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_GET_SECURITY_BADGE,
+        runtime_args! {
+            ARG_ACCOUNT => *account,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
+pub fn cep85_check_role_member_count(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+    badge: SecurityBadge,
+) -> u32 {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_ROLE_MEMBER_COUNT,
+        runtime_args! {
+            ARG_BADGE => badge,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
+pub fn cep85_check_role_members(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+    badge: SecurityBadge,
+    start: u32,
+    limit: u32,
+) -> Vec<Key> {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_ROLE_MEMBERS,
+        runtime_args! {
+            ARG_BADGE => badge,
+            ARG_START => start,
+            ARG_LIMIT => limit,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
+pub fn cep85_check_remaining_mint_quota(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: &ContractPackageHash,
+    account: &Key,
+    id: Option<U256>,
+) -> Option<U256> {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *contract_package_hash,
+        None,
+        ENTRY_POINT_CHECK_REMAINING_MINT_QUOTA,
+        runtime_args! {
+            ARG_ACCOUNT => *account,
+            ARG_ID => id,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    get_test_result(builder, *contract_package_hash)
+}
+
+pub fn cep85_propose_admin<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+    candidate: &'a Key,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let propose_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_PROPOSE_ADMIN,
+        runtime_args! {
+            ARG_ACCOUNT => *candidate,
+        },
+    )
+    .build();
+    builder.exec(propose_admin_request)
+}
+
+pub fn cep85_accept_admin<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_admin_request)
+}
+
+pub fn cep85_renounce_role<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    sender: &'a AccountHash,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let renounce_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cep85_token,
+        ENTRY_POINT_RENOUNCE_ROLE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(renounce_role_request)
+}
+
 pub struct SecurityLists {
     pub minter_list: Option<Vec<Key>>,
     pub burner_list: Option<Vec<Key>>,
     pub meta_list: Option<Vec<Key>>,
     pub admin_list: Option<Vec<Key>>,
+    pub pauser_list: Option<Vec<Key>>,
+    pub controller_list: Option<Vec<Key>>,
     pub none_list: Option<Vec<Key>>,
 }
 
@@ -867,28 +2000,54 @@ pub fn cep85_change_security<'a>(
     admin_account: &'a AccountHash,
     security_lists: SecurityLists,
 ) -> &'a mut InMemoryWasmTestBuilder {
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *admin_account,
+        *cep85_token,
+        ENTRY_POINT_CHANGE_SECURITY,
+        security_lists_args(security_lists),
+    )
+    .build();
+    builder.exec(change_security_request)
+}
+
+pub fn cep85_revoke_security<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cep85_token: &'a ContractHash,
+    admin_account: &'a AccountHash,
+    security_lists: SecurityLists,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut args = security_lists_args(security_lists);
+    let _ = args.insert(ARG_REVOKE, true);
+    let revoke_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *admin_account,
+        *cep85_token,
+        ENTRY_POINT_CHANGE_SECURITY,
+        args,
+    )
+    .build();
+    builder.exec(revoke_security_request)
+}
+
+fn security_lists_args(security_lists: SecurityLists) -> RuntimeArgs {
     let SecurityLists {
         minter_list,
         burner_list,
         meta_list,
         admin_list,
+        pauser_list,
+        controller_list,
         none_list,
     } = security_lists;
 
-    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
-        *admin_account,
-        *cep85_token,
-        ENTRY_POINT_CHANGE_SECURITY,
-        runtime_args! {
-            MINTER_LIST => minter_list.unwrap_or_default(),
-            BURNER_LIST => burner_list.unwrap_or_default(),
-            META_LIST => meta_list.unwrap_or_default(),
-            ADMIN_LIST => admin_list.unwrap_or_default(),
-            NONE_LIST => none_list.unwrap_or_default(),
-        },
-    )
-    .build();
-    builder.exec(change_security_request)
+    runtime_args! {
+        MINTER_LIST => minter_list.unwrap_or_default(),
+        BURNER_LIST => burner_list.unwrap_or_default(),
+        META_LIST => meta_list.unwrap_or_default(),
+        ADMIN_LIST => admin_list.unwrap_or_default(),
+        PAUSER_LIST => pauser_list.unwrap_or_default(),
+        CONTROLLER_LIST => controller_list.unwrap_or_default(),
+        NONE_LIST => none_list.unwrap_or_default(),
+    }
 }
 
 fn merge_args(install_args: RuntimeArgs) -> RuntimeArgs {
@@ -904,5 +2063,9 @@ fn merge_args(install_args: RuntimeArgs) -> RuntimeArgs {
             merged_args.insert_cl_value(ARG_URI, default_uri_value.clone());
         }
     }
+    // Most tests send tokens to contracts that do not implement the receiver entrypoints
+    if merged_args.get(ARG_ENABLE_RECEIVER_CHECK).is_none() {
+        let _ = merged_args.insert(ARG_ENABLE_RECEIVER_CHECK, false);
+    }
     merged_args
 }